@@ -31,6 +31,8 @@ pub enum AssignmentKind {
     LogAnd,
     /// The logical or (||=) augmented assignment expression
     LogOr,
+    /// The null-coalescing (??=) augmented assignment expression
+    NullCoalescing,
 }
 
 impl Display for AssignmentKind {
@@ -53,6 +55,7 @@ impl Display for AssignmentKind {
                 Self::BitOr => "|=",
                 Self::LogAnd => "&&=",
                 Self::LogOr => "||=",
+                Self::NullCoalescing => "??=",
             }
         )
     }
@@ -0,0 +1,91 @@
+use std::fmt::Display;
+
+use crate::{function::FunctionParameters, source_range::SourceRange};
+
+use super::*;
+use ast_node_format::format_as_node;
+
+/// An anonymous function AST node
+#[derive(Debug, Clone)]
+pub struct AstNodeAnonymousFunction<'source> {
+    parameters: FunctionParameters<'source>,
+    body: Vec<AstNode<'source>>,
+    pos: SourceRange<'source>,
+}
+
+impl<'source> AstNodeAnonymousFunction<'source> {
+    /// Constructs a new AstNodeAnonymousFunction
+    pub fn new(
+        parameters: FunctionParameters<'source>,
+        body: Vec<AstNode<'source>>,
+        pos: SourceRange<'source>,
+    ) -> Self {
+        Self {
+            parameters,
+            body,
+            pos,
+        }
+    }
+
+    /// Returns the parameters of the function
+    pub fn parameters(&self) -> &FunctionParameters<'source> {
+        &self.parameters
+    }
+
+    /// Returns the body of the function
+    pub fn body(&self) -> &Vec<AstNode<'source>> {
+        &self.body
+    }
+
+    /// Returns the position in the source code of this anonymous function
+    pub fn pos(&self) -> &SourceRange<'source> {
+        &self.pos
+    }
+
+    /// Returns a mutable reference to the position in the source code of this
+    /// anonymous function
+    pub fn pos_mut(&mut self) -> &mut SourceRange<'source> {
+        &mut self.pos
+    }
+}
+
+impl PartialEq for AstNodeAnonymousFunction<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.parameters == other.parameters && self.body == other.body
+    }
+}
+impl Eq for AstNodeAnonymousFunction<'_> {}
+
+impl Display for AstNodeAnonymousFunction<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            format_as_node(
+                &format!("[Anonymous Function] fn({})", self.parameters),
+                self.body.iter().map(AstNode::to_string).collect()
+            )
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ast_node_display_anonymous_function_nullary() {
+        let nowhere = SourceRange::new(" ", "", 0, 0);
+        let parameters = FunctionParameters::Polyadic {
+            parameters: vec![],
+            rest_parameter: None,
+        };
+        let node: AstNode = AstNodeAnonymousFunction::new(parameters, vec![], nowhere).into();
+
+        assert_eq!(
+            node.to_string(),
+            "\
+● [Anonymous Function] fn()"
+        );
+    }
+}
@@ -9,18 +9,25 @@ use super::{ast_node_format::format_as_node, AstNode};
 pub struct AstNodeComputedMemberAccess<'source> {
     root: Box<AstNode<'source>>,
     member_node: Box<AstNode<'source>>,
+    optional: bool,
     pos: SourceRange<'source>,
 }
 impl<'source> AstNodeComputedMemberAccess<'source> {
     /// Constructs a new AstNodeComputedMemberAccess
+    ///
+    /// An optional member access (`root?[member]`) evaluates to null instead of
+    /// failing when the root is null, or the member is a missing key or an
+    /// out-of-bounds index
     pub fn new(
         root: AstNode<'source>,
         member_node: AstNode<'source>,
+        optional: bool,
         pos: SourceRange<'source>,
     ) -> Self {
         Self {
             root: Box::new(root),
             member_node: Box::new(member_node),
+            optional,
             pos,
         }
     }
@@ -35,6 +42,11 @@ impl<'source> AstNodeComputedMemberAccess<'source> {
         &self.member_node
     }
 
+    /// Returns whether or not this is an optional member access (`?[...]`)
+    pub fn is_optional(&self) -> bool {
+        self.optional
+    }
+
     /// Returns the position in the source code of this
     /// AstNodeComputedMemberAccess
     pub fn pos(&self) -> &SourceRange<'source> {
@@ -54,7 +66,10 @@ impl Display for AstNodeComputedMemberAccess<'_> {
             f,
             "{}",
             format_as_node(
-                "[Computed Member Access]",
+                match self.optional {
+                    true => "[Optional Computed Member Access]",
+                    false => "[Computed Member Access]",
+                },
                 vec![
                     format_as_node("Root", vec![self.root.to_string()]),
                     format_as_node("Computed Member", vec![self.member_node.to_string()])
@@ -66,7 +81,9 @@ impl Display for AstNodeComputedMemberAccess<'_> {
 
 impl PartialEq for AstNodeComputedMemberAccess<'_> {
     fn eq(&self, other: &Self) -> bool {
-        self.root == other.root && self.member_node == other.member_node
+        self.root == other.root
+            && self.member_node == other.member_node
+            && self.optional == other.optional
     }
 }
 impl Eq for AstNodeComputedMemberAccess<'_> {}
@@ -0,0 +1,70 @@
+use std::fmt::Display;
+
+use crate::source_range::SourceRange;
+
+use super::{ast_node_format::format_as_node, *};
+
+/// A destructuring assignment AST node, which assigns the parts of a value to
+/// the targets of a list or dict pattern
+#[derive(Debug, Clone)]
+pub struct AstNodeDestructuringAssignment<'source> {
+    pattern: DestructuringPattern<'source>,
+    rhs: Box<AstNode<'source>>,
+    pos: SourceRange<'source>,
+}
+
+impl<'source> AstNodeDestructuringAssignment<'source> {
+    /// Constructs a new AstNodeDestructuringAssignment with the given pattern
+    /// and right-hand side
+    pub fn new(pattern: DestructuringPattern<'source>, rhs: AstNode<'source>) -> Self {
+        let pos = pattern.pos().extended_to(rhs.pos());
+        Self {
+            pattern,
+            rhs: Box::new(rhs),
+            pos,
+        }
+    }
+
+    /// Returns the pattern (left-hand side) of the destructuring assignment
+    /// node
+    pub fn pattern(&self) -> &DestructuringPattern<'source> {
+        &self.pattern
+    }
+
+    /// Returns the right-hand side of the destructuring assignment node
+    pub fn rhs(&self) -> &AstNode<'source> {
+        &self.rhs
+    }
+
+    /// Returns the position in the source code of this destructuring
+    /// assignment node
+    pub fn pos(&self) -> &SourceRange<'source> {
+        &self.pos
+    }
+
+    /// Returns a mutable reference to the position in the source code of this
+    /// destructuring assignment node
+    pub fn pos_mut(&mut self) -> &mut SourceRange<'source> {
+        &mut self.pos
+    }
+}
+
+impl Display for AstNodeDestructuringAssignment<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            format_as_node(
+                "[Destructuring Assignment] pattern = rhs",
+                vec![self.pattern.to_string(), self.rhs.to_string()]
+            )
+        )
+    }
+}
+
+impl PartialEq for AstNodeDestructuringAssignment<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.pattern == other.pattern && self.rhs == other.rhs
+    }
+}
+impl Eq for AstNodeDestructuringAssignment<'_> {}
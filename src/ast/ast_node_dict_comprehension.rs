@@ -0,0 +1,77 @@
+use std::fmt::Display;
+
+use crate::source_range::SourceRange;
+
+use super::{ast_node_format::format_as_node, *};
+
+/// A dict comprehension AST node (like `{k: v * 2 for k, v in d}`)
+#[derive(Debug, Clone)]
+pub struct AstNodeDictComprehension<'source> {
+    key: Box<AstNode<'source>>,
+    value: Box<AstNode<'source>>,
+    clauses: Vec<ComprehensionClause<'source>>,
+    pos: SourceRange<'source>,
+}
+
+impl<'source> AstNodeDictComprehension<'source> {
+    /// Constructs a new AstNodeDictComprehension with the given key and value
+    /// expressions, clauses, and pos
+    pub fn new(
+        key: AstNode<'source>,
+        value: AstNode<'source>,
+        clauses: Vec<ComprehensionClause<'source>>,
+        pos: SourceRange<'source>,
+    ) -> Self {
+        Self {
+            key: Box::new(key),
+            value: Box::new(value),
+            clauses,
+            pos,
+        }
+    }
+
+    /// Returns the key expression of the dict comprehension node
+    pub fn key(&self) -> &AstNode<'source> {
+        &self.key
+    }
+
+    /// Returns the value expression of the dict comprehension node
+    pub fn value(&self) -> &AstNode<'source> {
+        &self.value
+    }
+
+    /// Returns the clauses of the dict comprehension node, in order
+    pub fn clauses(&self) -> &Vec<ComprehensionClause<'source>> {
+        &self.clauses
+    }
+
+    /// Returns the position in the source code of this dict comprehension node
+    pub fn pos(&self) -> &SourceRange<'source> {
+        &self.pos
+    }
+
+    /// Returns a mutable reference to the position in the source code of this
+    /// dict comprehension node
+    pub fn pos_mut(&mut self) -> &mut SourceRange<'source> {
+        &mut self.pos
+    }
+}
+
+impl Display for AstNodeDictComprehension<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut children = vec![
+            format_as_node("Key", vec![self.key.to_string()]),
+            format_as_node("Value", vec![self.value.to_string()]),
+        ];
+        children.extend(self.clauses.iter().map(ComprehensionClause::to_string));
+
+        write!(f, "{}", format_as_node("[Dict comprehension]", children))
+    }
+}
+
+impl PartialEq for AstNodeDictComprehension<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key && self.value == other.value && self.clauses == other.clauses
+    }
+}
+impl Eq for AstNodeDictComprehension<'_> {}
@@ -4,24 +4,31 @@ use crate::source_range::SourceRange;
 
 use super::{ast_node_format::format_as_node, *};
 
+/// An entry in a dict literal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DictLiteralEntry<'source> {
+    /// A key and a value (`key: value`)
+    KeyValue(Box<AstNode<'source>>, Box<AstNode<'source>>),
+
+    /// A spread of another dict, whose entries are all added (`...dict`)
+    Spread(AstNodeSpread<'source>),
+}
+
 /// A dict literal AST node
 #[derive(Debug, Clone)]
 pub struct AstNodeDictLiteral<'source> {
-    entries: Vec<(AstNode<'source>, AstNode<'source>)>,
+    entries: Vec<DictLiteralEntry<'source>>,
     pos: SourceRange<'source>,
 }
 
 impl<'source> AstNodeDictLiteral<'source> {
     /// Constructs a new AstNodeDictLiteral with the given entries and pos
-    pub fn new(
-        entries: Vec<(AstNode<'source>, AstNode<'source>)>,
-        pos: SourceRange<'source>,
-    ) -> Self {
+    pub fn new(entries: Vec<DictLiteralEntry<'source>>, pos: SourceRange<'source>) -> Self {
         Self { entries, pos }
     }
 
     /// Returns the entries of the dict literal node
-    pub fn entries(&self) -> &Vec<(AstNode<'source>, AstNode<'source>)> {
+    pub fn entries(&self) -> &Vec<DictLiteralEntry<'source>> {
         &self.entries
     }
 
@@ -46,13 +53,16 @@ impl Display for AstNodeDictLiteral<'_> {
                 "[Literal] (dict)",
                 self.entries
                     .iter()
-                    .map(|entry| format_as_node(
-                        "Entry",
-                        vec![
-                            format_as_node("Key", vec![entry.0.to_string()]),
-                            format_as_node("Value", vec![entry.1.to_string()])
-                        ]
-                    ))
+                    .map(|entry| match entry {
+                        DictLiteralEntry::KeyValue(key, value) => format_as_node(
+                            "Entry",
+                            vec![
+                                format_as_node("Key", vec![key.to_string()]),
+                                format_as_node("Value", vec![value.to_string()])
+                            ]
+                        ),
+                        DictLiteralEntry::Spread(spread) => spread.to_string(),
+                    })
                     .collect()
             )
         )
@@ -9,14 +9,24 @@ use super::{ast_node_format::format_as_node, AstNode};
 pub struct AstNodeDotMemberAccess<'source> {
     root: Box<AstNode<'source>>,
     member: String,
+    optional: bool,
     pos: SourceRange<'source>,
 }
 impl<'source> AstNodeDotMemberAccess<'source> {
     /// Constructs AstNodeDotMemberAccess
-    pub fn new(root: AstNode<'source>, member: String, pos: SourceRange<'source>) -> Self {
+    ///
+    /// An optional member access (`root?.member`) evaluates to null instead of
+    /// failing when the root is null or doesn't have the member
+    pub fn new(
+        root: AstNode<'source>,
+        member: String,
+        optional: bool,
+        pos: SourceRange<'source>,
+    ) -> Self {
         Self {
             root: Box::new(root),
             member,
+            optional,
             pos,
         }
     }
@@ -31,6 +41,11 @@ impl<'source> AstNodeDotMemberAccess<'source> {
         &self.member
     }
 
+    /// Returns whether or not this is an optional member access (`?.`)
+    pub fn is_optional(&self) -> bool {
+        self.optional
+    }
+
     /// Returns the position in the source code of this AstNodeDotMemberAccess
     pub fn pos(&self) -> &SourceRange<'source> {
         &self.pos
@@ -49,7 +64,10 @@ impl Display for AstNodeDotMemberAccess<'_> {
             f,
             "{}",
             format_as_node(
-                "[Dot Member Access]",
+                match self.optional {
+                    true => "[Optional Dot Member Access]",
+                    false => "[Dot Member Access]",
+                },
                 vec![
                     format_as_node("Root", vec![self.root.to_string()]),
                     format_as_node("Property", vec![self.member.to_string()])
@@ -61,7 +79,7 @@ impl Display for AstNodeDotMemberAccess<'_> {
 
 impl PartialEq for AstNodeDotMemberAccess<'_> {
     fn eq(&self, other: &Self) -> bool {
-        self.root == other.root && self.member == other.member
+        self.root == other.root && self.member == other.member && self.optional == other.optional
     }
 }
 impl Eq for AstNodeDotMemberAccess<'_> {}
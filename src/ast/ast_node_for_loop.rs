@@ -7,32 +7,48 @@ use super::{ast_node_format::format_as_node, *};
 /// A for loop AST node
 #[derive(Debug, Clone)]
 pub struct AstNodeForLoop<'source> {
-    ident: String,
+    pattern: DestructuringPattern<'source>,
+    multiple_variables: bool,
     iterable: Box<AstNode<'source>>,
     body: Vec<AstNode<'source>>,
+    label: Option<String>,
     pos: SourceRange<'source>,
 }
 
 impl<'source> AstNodeForLoop<'source> {
     /// Constructs a new AstNodeForLoop with the given loop-control variable
-    /// identifier, iterable expression, body, and pos
+    /// pattern, iterable expression, body, label, and pos
+    ///
+    /// `multiple_variables` is whether the loop has several comma-separated
+    /// loop-control variables (like `for k, v in ...`), in which case
+    /// `pattern` is a list pattern containing each of them
     pub fn new(
-        ident: String,
+        pattern: DestructuringPattern<'source>,
+        multiple_variables: bool,
         iterable: AstNode<'source>,
         body: Vec<AstNode<'source>>,
+        label: Option<String>,
         pos: SourceRange<'source>,
     ) -> Self {
         Self {
-            ident,
+            pattern,
+            multiple_variables,
             iterable: Box::new(iterable),
             body,
+            label,
             pos,
         }
     }
 
-    /// Returns the loop-control variable identifier of the for loop node
-    pub fn ident(&self) -> &str {
-        &self.ident
+    /// Returns the loop-control variable pattern of the for loop node
+    pub fn pattern(&self) -> &DestructuringPattern<'source> {
+        &self.pattern
+    }
+
+    /// Returns whether or not the for loop node has several comma-separated
+    /// loop-control variables (like `for k, v in ...`)
+    pub fn has_multiple_variables(&self) -> bool {
+        self.multiple_variables
     }
 
     /// Returns the iterable expression of the for loop node
@@ -45,6 +61,12 @@ impl<'source> AstNodeForLoop<'source> {
         &self.body
     }
 
+    /// Returns the label of the for loop node (without the leading `'`), if
+    /// it has one
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
     /// Returns the position in the source code of this for loop node
     pub fn pos(&self) -> &SourceRange<'source> {
         &self.pos
@@ -59,26 +81,40 @@ impl<'source> AstNodeForLoop<'source> {
 
 impl Display for AstNodeForLoop<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
+        let mut children = vec![
+            format_as_node("Iterable", vec![self.iterable.to_string()]),
             format_as_node(
-                &format!("[For loop] for {} in ...", self.ident),
-                vec![
-                    format_as_node("Iterable", vec![self.iterable.to_string()]),
-                    format_as_node(
-                        "Body",
-                        self.body.iter().map(|node| node.to_string()).collect()
-                    )
-                ]
-            )
-        )
+                "Body",
+                self.body.iter().map(|node| node.to_string()).collect(),
+            ),
+        ];
+        let label = match &self.label {
+            Some(label) => format!("'{label}: "),
+            None => String::new(),
+        };
+        let head = match self.pattern.ident() {
+            _ if self.multiple_variables => {
+                children.insert(0, format_as_node("Pattern", vec![self.pattern.to_string()]));
+                format!("[For loop] {label}for <patterns> in ...")
+            }
+            Some(ident) => format!("[For loop] {label}for {ident} in ..."),
+            None => {
+                children.insert(0, format_as_node("Pattern", vec![self.pattern.to_string()]));
+                format!("[For loop] {label}for <pattern> in ...")
+            }
+        };
+
+        write!(f, "{}", format_as_node(&head, children))
     }
 }
 
 impl PartialEq for AstNodeForLoop<'_> {
     fn eq(&self, other: &Self) -> bool {
-        self.ident == other.ident && self.iterable == other.iterable && self.body == other.body
+        self.pattern == other.pattern
+            && self.multiple_variables == other.multiple_variables
+            && self.iterable == other.iterable
+            && self.body == other.body
+            && self.label == other.label
     }
 }
 impl Eq for AstNodeForLoop<'_> {}
@@ -1,14 +1,14 @@
 use std::fmt::{Display, Write};
 
-use crate::source_range::SourceRange;
+use crate::{format_spec::FormatSpec, source_range::SourceRange};
 
 use super::{ast_node_format::format_as_node, *};
 
 /// A while loop AST node
 #[derive(Debug, Clone)]
 pub struct AstNodeFormattedStringLiteral<'source> {
-    start: (String, Box<AstNode<'source>>),
-    continuations: Vec<(String, Box<AstNode<'source>>)>,
+    start: (String, Box<AstNode<'source>>, Option<FormatSpec>),
+    continuations: Vec<(String, Box<AstNode<'source>>, Option<FormatSpec>)>,
     end: String,
     pos: SourceRange<'source>,
 }
@@ -16,17 +16,21 @@ pub struct AstNodeFormattedStringLiteral<'source> {
 impl<'source> AstNodeFormattedStringLiteral<'source> {
     /// Constructs a new AstNodeFormattedStringLiteral with the given start,
     /// continuations, end, and pos
+    ///
+    /// The start and each continuation are made up of a string part, the
+    /// replacement field following it, and that replacement field's format
+    /// spec (if it has one)
     pub fn new(
-        start: (String, AstNode<'source>),
-        continuations: Vec<(String, AstNode<'source>)>,
+        start: (String, AstNode<'source>, Option<FormatSpec>),
+        continuations: Vec<(String, AstNode<'source>, Option<FormatSpec>)>,
         end: String,
         pos: SourceRange<'source>,
     ) -> Self {
         Self {
-            start: (start.0, Box::new(start.1)),
+            start: (start.0, Box::new(start.1), start.2),
             continuations: continuations
                 .into_iter()
-                .map(|(s, r)| (s, Box::new(r)))
+                .map(|(s, r, f)| (s, Box::new(r), f))
                 .collect(),
             end,
             pos,
@@ -34,15 +38,15 @@ impl<'source> AstNodeFormattedStringLiteral<'source> {
     }
 
     /// Returns the start of the formatted string literal node
-    pub fn start(&self) -> (&String, &AstNode<'source>) {
-        (&self.start.0, &self.start.1)
+    pub fn start(&self) -> (&String, &AstNode<'source>, Option<&FormatSpec>) {
+        (&self.start.0, &self.start.1, self.start.2.as_ref())
     }
 
     /// Returns the continuations of the formatted string literal node
-    pub fn continuations(&self) -> Vec<(&String, &AstNode<'source>)> {
+    pub fn continuations(&self) -> Vec<(&String, &AstNode<'source>, Option<&FormatSpec>)> {
         self.continuations
             .iter()
-            .map(|(s, r)| (s, r.as_ref()))
+            .map(|(s, r, f)| (s, r.as_ref(), f.as_ref()))
             .collect()
     }
 
@@ -71,20 +75,30 @@ impl Display for AstNodeFormattedStringLiteral<'_> {
             "{}",
             format_as_node(
                 &format!(
-                    "[Formatted String Literal] {}{}{}",
+                    "[Formatted String Literal] {}{}",
                     self.start.0,
-                    self.continuations
-                        .iter()
-                        .fold("...".to_string(), |mut accumulator, (s, _)| {
-                            write!(accumulator, "{s}...").unwrap();
+                    // Each replacement field is shown as "...", followed by
+                    // its format spec (if it has one)
+                    std::iter::once(&self.start.2)
+                        .chain(self.continuations.iter().map(|(_, _, f)| f))
+                        .zip(
+                            self.continuations
+                                .iter()
+                                .map(|(s, _, _)| s)
+                                .chain(std::iter::once(&self.end))
+                        )
+                        .fold(String::new(), |mut accumulator, (f, s)| {
+                            match f {
+                                Some(f) => write!(accumulator, "...:{f}{s}").unwrap(),
+                                None => write!(accumulator, "...{s}").unwrap(),
+                            }
 
                             accumulator
                         }),
-                    self.end
                 ),
                 vec![self.start.1.to_string()]
                     .into_iter()
-                    .chain(self.continuations.iter().map(|(_, r)| r.to_string()))
+                    .chain(self.continuations.iter().map(|(_, r, _)| r.to_string()))
                     .collect()
             )
         )
@@ -9,6 +9,7 @@ use super::{ast_node_format::format_as_node, AstNode};
 pub struct AstNodeFunctionCall<'source> {
     root: Box<AstNode<'source>>,
     arguments: Vec<AstNode<'source>>,
+    named_arguments: Vec<(String, AstNode<'source>, SourceRange<'source>)>,
     pos: SourceRange<'source>,
 }
 impl<'source> AstNodeFunctionCall<'source> {
@@ -16,11 +17,13 @@ impl<'source> AstNodeFunctionCall<'source> {
     pub fn new(
         root: AstNode<'source>,
         arguments: Vec<AstNode<'source>>,
+        named_arguments: Vec<(String, AstNode<'source>, SourceRange<'source>)>,
         pos: SourceRange<'source>,
     ) -> Self {
         Self {
             root: Box::new(root),
             arguments,
+            named_arguments,
             pos,
         }
     }
@@ -30,11 +33,17 @@ impl<'source> AstNodeFunctionCall<'source> {
         &self.root
     }
 
-    /// Returns the arguments to the function call
+    /// Returns the (positional) arguments to the function call
     pub fn arguments(&self) -> &Vec<AstNode<'source>> {
         &self.arguments
     }
 
+    /// Returns the named arguments to the function call, as (name, value,
+    /// name position) triples in source order
+    pub fn named_arguments(&self) -> &Vec<(String, AstNode<'source>, SourceRange<'source>)> {
+        &self.named_arguments
+    }
+
     /// Returns the position in the source code of this AstNodeFunctionCall
     pub fn pos(&self) -> &SourceRange<'source> {
         &self.pos
@@ -49,26 +58,39 @@ impl<'source> AstNodeFunctionCall<'source> {
 
 impl Display for AstNodeFunctionCall<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
+        let mut children = vec![
+            format_as_node("Root", vec![self.root.to_string()]),
             format_as_node(
-                "[Function Call]",
-                vec![
-                    format_as_node("Root", vec![self.root.to_string()]),
-                    format_as_node(
-                        "Arguments",
-                        self.arguments.iter().map(AstNode::to_string).collect()
-                    )
-                ]
-            )
-        )
+                "Arguments",
+                self.arguments.iter().map(AstNode::to_string).collect(),
+            ),
+        ];
+        if !self.named_arguments.is_empty() {
+            children.push(format_as_node(
+                "Named arguments",
+                self.named_arguments
+                    .iter()
+                    .map(|(name, value, _)| format_as_node(name, vec![value.to_string()]))
+                    .collect(),
+            ));
+        }
+
+        write!(f, "{}", format_as_node("[Function Call]", children))
     }
 }
 
 impl PartialEq for AstNodeFunctionCall<'_> {
     fn eq(&self, other: &Self) -> bool {
-        self.root == other.root && self.arguments == other.arguments
+        self.root == other.root
+            && self.arguments == other.arguments
+            && self.named_arguments.len() == other.named_arguments.len()
+            && self
+                .named_arguments
+                .iter()
+                .zip(other.named_arguments.iter())
+                .all(|((l_name, l_value, _), (r_name, r_value, _))| {
+                    l_name == r_name && l_value == r_value
+                })
     }
 }
 impl Eq for AstNodeFunctionCall<'_> {}
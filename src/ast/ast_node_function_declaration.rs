@@ -82,6 +82,8 @@ impl Display for AstNodeFunctionDeclaration<'_> {
 
 #[cfg(test)]
 mod tests {
+    use crate::{icelang_type::IcelangType, value::Value};
+
     use super::*;
 
     // TODO move function parameters tests
@@ -89,11 +91,41 @@ mod tests {
     #[test]
     fn test_function_parameters_display_empty() {
         assert_eq!(
-            FunctionParameters::Polyadic { parameters: vec![] }.to_string(),
+            FunctionParameters::Polyadic {
+                parameters: vec![],
+                rest_parameter: None,
+            }
+            .to_string(),
             ""
         );
     }
 
+    #[test]
+    fn test_function_parameters_display_defaults_and_rest() {
+        let source = "fn f(a, b = 10, [rest]) {}";
+        let parameters = FunctionParameters::Polyadic {
+            parameters: vec![
+                ("a".to_string(), None, SourceRange::new(source, "", 5, 5)),
+                (
+                    "b".to_string(),
+                    Some(
+                        AstNodeLiteral::new(
+                            "10".to_string(),
+                            IcelangType::Int,
+                            Value::Int(10.into()),
+                            SourceRange::new(source, "", 12, 13),
+                        )
+                        .into(),
+                    ),
+                    SourceRange::new(source, "", 8, 8),
+                ),
+            ],
+            rest_parameter: Some(("rest".to_string(), SourceRange::new(source, "", 17, 20))),
+        };
+
+        assert_eq!(parameters.to_string(), "a, b = 10, [rest]");
+    }
+
     // TODO fix and reenable at some point
     // #[test]
     // fn test_function_parameters_display_unary() {
@@ -154,7 +186,10 @@ mod tests {
 
         let nowhere = SourceRange::new(" ", "", 0, 0);
         let body = vec![];
-        let parameters = FunctionParameters::Polyadic { parameters: vec![] };
+        let parameters = FunctionParameters::Polyadic {
+            parameters: vec![],
+            rest_parameter: None,
+        };
         let node: AstNode = AstNodeFunctionDeclaration::new(
             "get_funny_number".to_string(),
             parameters,
@@ -0,0 +1,81 @@
+use std::fmt::Display;
+
+use crate::source_range::SourceRange;
+
+use super::*;
+use ast_node_format::format_as_node;
+
+/// An import AST node
+#[derive(Debug, Clone)]
+pub struct AstNodeImport<'source> {
+    path: String,
+    namespace: String,
+    pos: SourceRange<'source>,
+}
+
+impl<'source> AstNodeImport<'source> {
+    /// Constructs a new AstNodeImport
+    pub fn new(path: String, namespace: String, pos: SourceRange<'source>) -> Self {
+        Self {
+            path,
+            namespace,
+            pos,
+        }
+    }
+
+    /// Returns the path of the imported module, relative to the importing
+    /// source file
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns the identifier the imported module will be bound to
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    /// Returns the position in the source code of this import
+    pub fn pos(&self) -> &SourceRange<'source> {
+        &self.pos
+    }
+
+    /// Returns a mutable reference to the position in the source code of this
+    /// import
+    pub fn pos_mut(&mut self) -> &mut SourceRange<'source> {
+        &mut self.pos
+    }
+}
+
+impl PartialEq for AstNodeImport<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.path == other.path && self.namespace == other.namespace
+    }
+}
+impl Eq for AstNodeImport<'_> {}
+
+impl Display for AstNodeImport<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            format_as_node(
+                &format!("[Import] {:?} as {}", self.path, self.namespace),
+                vec![]
+            )
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ast_node_display_import() {
+        let nowhere = SourceRange::new(" ", "", 0, 0);
+        let node: AstNode =
+            AstNodeImport::new("lib/math.ice".to_string(), "math".to_string(), nowhere).into();
+
+        assert_eq!(node.to_string(), "● [Import] \"lib/math.ice\" as math");
+    }
+}
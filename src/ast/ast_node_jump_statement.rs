@@ -9,20 +9,23 @@ use super::{ast_node_format::format_as_node, *};
 pub struct AstNodeJumpStatement<'source> {
     body: Option<Box<AstNode<'source>>>,
     jump_kind: JumpStatementKind,
+    label: Option<String>,
     pos: SourceRange<'source>,
 }
 
 impl<'source> AstNodeJumpStatement<'source> {
     /// Constructs a new AstNodeJumpStatement with the given body, jump kind,
-    /// and pos
+    /// label, and pos
     pub fn new(
         body: Option<AstNode<'source>>,
         jump_kind: JumpStatementKind,
+        label: Option<String>,
         pos: SourceRange<'source>,
     ) -> Self {
         Self {
             body: body.map(Box::new),
             jump_kind,
+            label,
             pos,
         }
     }
@@ -37,6 +40,12 @@ impl<'source> AstNodeJumpStatement<'source> {
         self.jump_kind
     }
 
+    /// Returns the label of the loop targeted by the jump statement node
+    /// (without the leading `'`), if it has one
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
     /// Returns the position in the source code of this jump statement node
     pub fn pos(&self) -> &SourceRange<'source> {
         &self.pos
@@ -55,7 +64,10 @@ impl Display for AstNodeJumpStatement<'_> {
             f,
             "{}",
             format_as_node(
-                &format!("[Jump Statement] {}", self.jump_kind),
+                &match &self.label {
+                    Some(label) => format!("[Jump Statement] {} '{label}", self.jump_kind),
+                    None => format!("[Jump Statement] {}", self.jump_kind),
+                },
                 self.body.iter().map(|node| node.to_string()).collect()
             )
         )
@@ -64,7 +76,7 @@ impl Display for AstNodeJumpStatement<'_> {
 
 impl PartialEq for AstNodeJumpStatement<'_> {
     fn eq(&self, other: &Self) -> bool {
-        self.body == other.body && self.jump_kind == other.jump_kind
+        self.body == other.body && self.jump_kind == other.jump_kind && self.label == other.label
     }
 }
 impl Eq for AstNodeJumpStatement<'_> {}
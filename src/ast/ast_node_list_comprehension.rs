@@ -0,0 +1,66 @@
+use std::fmt::Display;
+
+use crate::source_range::SourceRange;
+
+use super::{ast_node_format::format_as_node, *};
+
+/// A list comprehension AST node (like `[x * 2 for x in xs if x > 0]`)
+#[derive(Debug, Clone)]
+pub struct AstNodeListComprehension<'source> {
+    element: Box<AstNode<'source>>,
+    clauses: Vec<ComprehensionClause<'source>>,
+    pos: SourceRange<'source>,
+}
+
+impl<'source> AstNodeListComprehension<'source> {
+    /// Constructs a new AstNodeListComprehension with the given element
+    /// expression, clauses, and pos
+    pub fn new(
+        element: AstNode<'source>,
+        clauses: Vec<ComprehensionClause<'source>>,
+        pos: SourceRange<'source>,
+    ) -> Self {
+        Self {
+            element: Box::new(element),
+            clauses,
+            pos,
+        }
+    }
+
+    /// Returns the element expression of the list comprehension node
+    pub fn element(&self) -> &AstNode<'source> {
+        &self.element
+    }
+
+    /// Returns the clauses of the list comprehension node, in order
+    pub fn clauses(&self) -> &Vec<ComprehensionClause<'source>> {
+        &self.clauses
+    }
+
+    /// Returns the position in the source code of this list comprehension node
+    pub fn pos(&self) -> &SourceRange<'source> {
+        &self.pos
+    }
+
+    /// Returns a mutable reference to the position in the source code of this
+    /// list comprehension node
+    pub fn pos_mut(&mut self) -> &mut SourceRange<'source> {
+        &mut self.pos
+    }
+}
+
+impl Display for AstNodeListComprehension<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut children = vec![format_as_node("Element", vec![self.element.to_string()])];
+        children.extend(self.clauses.iter().map(ComprehensionClause::to_string));
+
+        write!(f, "{}", format_as_node("[List comprehension]", children))
+    }
+}
+
+impl PartialEq for AstNodeListComprehension<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.element == other.element && self.clauses == other.clauses
+    }
+}
+impl Eq for AstNodeListComprehension<'_> {}
@@ -10,7 +10,7 @@ use ast_node_format::format_as_node;
 pub struct AstNodeLiteral<'source> {
     raw: String,
     icelang_type: IcelangType,
-    value: Value,
+    value: Value<'source>,
     pos: SourceRange<'source>,
 }
 
@@ -19,7 +19,7 @@ impl<'source> AstNodeLiteral<'source> {
     pub fn new(
         raw: String,
         icelang_type: IcelangType,
-        value: Value,
+        value: Value<'source>,
         pos: SourceRange<'source>,
     ) -> Self {
         Self {
@@ -41,7 +41,7 @@ impl<'source> AstNodeLiteral<'source> {
     }
 
     /// Returns the value of the literal
-    pub fn value(&self) -> &Value {
+    pub fn value(&self) -> &Value<'source> {
         &self.value
     }
 
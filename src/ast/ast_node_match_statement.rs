@@ -66,19 +66,19 @@ impl Display for AstNodeMatchStatement<'_> {
                         self.arms
                             .iter()
                             .map(|arm| {
-                                format_as_node(
-                                    "Match arm",
-                                    vec![
-                                        format_as_node("Pattern", vec![arm.pattern().to_string()]),
-                                        format_as_node(
-                                            "Body",
-                                            arm.body()
-                                                .iter()
-                                                .map(|node| node.to_string())
-                                                .collect(),
-                                        ),
-                                    ],
-                                )
+                                let mut children = vec![format_as_node(
+                                    "Pattern",
+                                    vec![arm.pattern().to_string()],
+                                )];
+                                if let Some(guard) = arm.guard() {
+                                    children.push(format_as_node("Guard", vec![guard.to_string()]));
+                                }
+                                children.push(format_as_node(
+                                    "Body",
+                                    arm.body().iter().map(|node| node.to_string()).collect(),
+                                ));
+
+                                format_as_node("Match arm", children)
                             })
                             .collect()
                     )
@@ -0,0 +1,81 @@
+use std::fmt::Display;
+
+use crate::source_range::SourceRange;
+
+use super::{ast_node_format::format_as_node, *};
+
+/// A range literal AST node (`start..end` or `start..=end`)
+#[derive(Debug, Clone)]
+pub struct AstNodeRangeLiteral<'source> {
+    start: Box<AstNode<'source>>,
+    end: Box<AstNode<'source>>,
+    inclusive: bool,
+    pos: SourceRange<'source>,
+}
+
+impl<'source> AstNodeRangeLiteral<'source> {
+    /// Constructs a new AstNodeRangeLiteral with the given start, end, and
+    /// inclusivity
+    pub fn new(start: AstNode<'source>, end: AstNode<'source>, inclusive: bool) -> Self {
+        let pos = start.pos().extended_to(end.pos());
+        Self {
+            start: Box::new(start),
+            end: Box::new(end),
+            inclusive,
+            pos,
+        }
+    }
+
+    /// Returns the start of the range literal node
+    pub fn start(&self) -> &AstNode<'source> {
+        &self.start
+    }
+
+    /// Returns the end of the range literal node
+    pub fn end(&self) -> &AstNode<'source> {
+        &self.end
+    }
+
+    /// Returns whether or not the end of the range literal node is included in
+    /// the range
+    pub fn is_inclusive(&self) -> bool {
+        self.inclusive
+    }
+
+    /// Returns the position in the source code of this range literal node
+    pub fn pos(&self) -> &SourceRange<'source> {
+        &self.pos
+    }
+
+    /// Returns a mutable reference to the position in the source code of this
+    /// range literal node
+    pub fn pos_mut(&mut self) -> &mut SourceRange<'source> {
+        &mut self.pos
+    }
+}
+
+impl Display for AstNodeRangeLiteral<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            format_as_node(
+                match self.inclusive {
+                    true => "[Literal] (range, inclusive)",
+                    false => "[Literal] (range)",
+                },
+                vec![
+                    format_as_node("Start", vec![self.start.to_string()]),
+                    format_as_node("End", vec![self.end.to_string()]),
+                ]
+            )
+        )
+    }
+}
+
+impl PartialEq for AstNodeRangeLiteral<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.start == other.start && self.end == other.end && self.inclusive == other.inclusive
+    }
+}
+impl Eq for AstNodeRangeLiteral<'_> {}
@@ -0,0 +1,102 @@
+use std::fmt::Display;
+
+use crate::source_range::SourceRange;
+
+use super::*;
+use ast_node_format::format_as_node;
+
+/// A record declaration AST node
+#[derive(Debug, Clone)]
+pub struct AstNodeRecordDeclaration<'source> {
+    name: String,
+    fields: Vec<(String, SourceRange<'source>)>,
+    pos: SourceRange<'source>,
+}
+
+impl<'source> AstNodeRecordDeclaration<'source> {
+    /// Constructs a new AstNodeRecordDeclaration
+    pub fn new(
+        name: String,
+        fields: Vec<(String, SourceRange<'source>)>,
+        pos: SourceRange<'source>,
+    ) -> Self {
+        Self { name, fields, pos }
+    }
+
+    /// Returns the name of the declared record type
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the field names (and their positions in the source code) of
+    /// the declared record type, in declaration order
+    pub fn fields(&self) -> &Vec<(String, SourceRange<'source>)> {
+        &self.fields
+    }
+
+    /// Returns the position in the source code of this record declaration
+    pub fn pos(&self) -> &SourceRange<'source> {
+        &self.pos
+    }
+
+    /// Returns a mutable reference to the position in the source code of this
+    /// record declaration
+    pub fn pos_mut(&mut self) -> &mut SourceRange<'source> {
+        &mut self.pos
+    }
+}
+
+impl PartialEq for AstNodeRecordDeclaration<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.fields.len() == other.fields.len()
+            && self
+                .fields
+                .iter()
+                .zip(other.fields.iter())
+                .all(|((l_field, _), (r_field, _))| l_field == r_field)
+    }
+}
+impl Eq for AstNodeRecordDeclaration<'_> {}
+
+impl Display for AstNodeRecordDeclaration<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            format_as_node(
+                &format!(
+                    "[Record Declaration] {} {{ {} }}",
+                    self.name,
+                    self.fields
+                        .iter()
+                        .map(|(field, _)| field.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                vec![]
+            )
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ast_node_display_record_declaration() {
+        let nowhere = SourceRange::new(" ", "", 0, 0);
+        let node: AstNode = AstNodeRecordDeclaration::new(
+            "Point".to_string(),
+            vec![
+                ("x".to_string(), nowhere.clone()),
+                ("y".to_string(), nowhere.clone()),
+            ],
+            nowhere,
+        )
+        .into();
+
+        assert_eq!(node.to_string(), "● [Record Declaration] Point { x, y }");
+    }
+}
@@ -0,0 +1,80 @@
+use std::fmt::Display;
+
+use crate::source_range::SourceRange;
+
+use super::{ast_node_format::format_as_node, *};
+
+/// A record literal AST node
+#[derive(Debug, Clone)]
+pub struct AstNodeRecordLiteral<'source> {
+    record_type: Box<AstNode<'source>>,
+    fields: Vec<(String, AstNode<'source>, SourceRange<'source>)>,
+    pos: SourceRange<'source>,
+}
+
+impl<'source> AstNodeRecordLiteral<'source> {
+    /// Constructs a new AstNodeRecordLiteral with the given record type
+    /// expression, fields, and pos
+    pub fn new(
+        record_type: AstNode<'source>,
+        fields: Vec<(String, AstNode<'source>, SourceRange<'source>)>,
+        pos: SourceRange<'source>,
+    ) -> Self {
+        Self {
+            record_type: Box::new(record_type),
+            fields,
+            pos,
+        }
+    }
+
+    /// Returns the expression naming the record type being constructed
+    pub fn record_type(&self) -> &AstNode<'source> {
+        &self.record_type
+    }
+
+    /// Returns the fields of the record literal node, as (name, value,
+    /// name position) triples in source order
+    pub fn fields(&self) -> &Vec<(String, AstNode<'source>, SourceRange<'source>)> {
+        &self.fields
+    }
+
+    /// Returns the position in the source code of this record literal node
+    pub fn pos(&self) -> &SourceRange<'source> {
+        &self.pos
+    }
+
+    /// Returns a mutable reference to the position in the source code of this
+    /// record literal node
+    pub fn pos_mut(&mut self) -> &mut SourceRange<'source> {
+        &mut self.pos
+    }
+}
+
+impl Display for AstNodeRecordLiteral<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut children = vec![format_as_node(
+            "Record type",
+            vec![self.record_type.to_string()],
+        )];
+        children.extend(
+            self.fields
+                .iter()
+                .map(|(name, value, _)| format_as_node(name, vec![value.to_string()])),
+        );
+
+        write!(f, "{}", format_as_node("[Literal] (record)", children))
+    }
+}
+
+impl PartialEq for AstNodeRecordLiteral<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.record_type == other.record_type
+            && self.fields.len() == other.fields.len()
+            && self.fields.iter().zip(other.fields.iter()).all(
+                |((l_name, l_value, _), (r_name, r_value, _))| {
+                    l_name == r_name && l_value == r_value
+                },
+            )
+    }
+}
+impl Eq for AstNodeRecordLiteral<'_> {}
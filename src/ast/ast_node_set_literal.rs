@@ -0,0 +1,55 @@
+use std::fmt::Display;
+
+use crate::source_range::SourceRange;
+
+use super::{ast_node_format::format_as_node, *};
+
+/// A set literal AST node
+#[derive(Debug, Clone)]
+pub struct AstNodeSetLiteral<'source> {
+    elements: Vec<AstNode<'source>>,
+    pos: SourceRange<'source>,
+}
+
+impl<'source> AstNodeSetLiteral<'source> {
+    /// Constructs a new AstNodeSetLiteral with the given elements and pos
+    pub fn new(elements: Vec<AstNode<'source>>, pos: SourceRange<'source>) -> Self {
+        Self { elements, pos }
+    }
+
+    /// Returns the elements of the set literal node
+    pub fn elements(&self) -> &Vec<AstNode<'source>> {
+        &self.elements
+    }
+
+    /// Returns the position in the source code of this set literal node
+    pub fn pos(&self) -> &SourceRange<'source> {
+        &self.pos
+    }
+
+    /// Returns a mutable reference to the position in the source code of this
+    /// set literal node
+    pub fn pos_mut(&mut self) -> &mut SourceRange<'source> {
+        &mut self.pos
+    }
+}
+
+impl Display for AstNodeSetLiteral<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            format_as_node(
+                "[Literal] (set)",
+                self.elements.iter().map(AstNode::to_string).collect()
+            )
+        )
+    }
+}
+
+impl PartialEq for AstNodeSetLiteral<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.elements == other.elements
+    }
+}
+impl Eq for AstNodeSetLiteral<'_> {}
@@ -9,20 +9,23 @@ use super::{ast_node_format::format_as_node, *};
 pub struct AstNodeSimpleLoop<'source> {
     condition: Option<Box<AstNode<'source>>>,
     body: Vec<AstNode<'source>>,
+    label: Option<String>,
     pos: SourceRange<'source>,
 }
 
 impl<'source> AstNodeSimpleLoop<'source> {
-    /// Constructs a new AstNodeSimpleLoop with the given condition, body, and
-    /// pos
+    /// Constructs a new AstNodeSimpleLoop with the given condition, body,
+    /// label, and pos
     pub fn new(
         condition: Option<AstNode<'source>>,
         body: Vec<AstNode<'source>>,
+        label: Option<String>,
         pos: SourceRange<'source>,
     ) -> Self {
         Self {
             condition: condition.map(Box::new),
             body,
+            label,
             pos,
         }
     }
@@ -37,6 +40,12 @@ impl<'source> AstNodeSimpleLoop<'source> {
         &self.body
     }
 
+    /// Returns the label of the simple loop node (without the leading `'`), if
+    /// it has one
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
     /// Returns the position in the source code of this while loop node
     pub fn pos(&self) -> &SourceRange<'source> {
         &self.pos
@@ -55,7 +64,10 @@ impl Display for AstNodeSimpleLoop<'_> {
             f,
             "{}",
             format_as_node(
-                "[Simple loop]",
+                &match &self.label {
+                    Some(label) => format!("[Simple loop] '{label}"),
+                    None => "[Simple loop]".to_string(),
+                },
                 vec![
                     self.condition.as_ref().map(|condition| {
                         format_as_node("Condition", vec![condition.to_string()])
@@ -75,7 +87,7 @@ impl Display for AstNodeSimpleLoop<'_> {
 
 impl PartialEq for AstNodeSimpleLoop<'_> {
     fn eq(&self, other: &Self) -> bool {
-        self.condition == other.condition && self.body == other.body
+        self.condition == other.condition && self.body == other.body && self.label == other.label
     }
 }
 impl Eq for AstNodeSimpleLoop<'_> {}
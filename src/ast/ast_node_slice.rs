@@ -0,0 +1,109 @@
+use std::fmt::Display;
+
+use crate::source_range::SourceRange;
+
+use super::{ast_node_format::format_as_node, AstNode};
+
+#[derive(Debug, Clone)]
+/// A slice usage suffix (`root[start:end:step]`, where each of the start, end,
+/// and step may be omitted)
+pub struct AstNodeSlice<'source> {
+    root: Box<AstNode<'source>>,
+    start: Option<Box<AstNode<'source>>>,
+    end: Option<Box<AstNode<'source>>>,
+    step: Option<Box<AstNode<'source>>>,
+    optional: bool,
+    pos: SourceRange<'source>,
+}
+impl<'source> AstNodeSlice<'source> {
+    /// Constructs a new AstNodeSlice
+    ///
+    /// An optional slice (`root?[start:end]`) evaluates to null instead of
+    /// failing when the root is null
+    pub fn new(
+        root: AstNode<'source>,
+        start: Option<AstNode<'source>>,
+        end: Option<AstNode<'source>>,
+        step: Option<AstNode<'source>>,
+        optional: bool,
+        pos: SourceRange<'source>,
+    ) -> Self {
+        Self {
+            root: Box::new(root),
+            start: start.map(Box::new),
+            end: end.map(Box::new),
+            step: step.map(Box::new),
+            optional,
+            pos,
+        }
+    }
+
+    /// Returns the root of the slice node
+    pub fn root(&self) -> &AstNode<'source> {
+        &self.root
+    }
+
+    /// Returns the node representing the start of the slice, if it has one
+    pub fn start(&self) -> Option<&AstNode<'source>> {
+        self.start.as_deref()
+    }
+
+    /// Returns the node representing the end of the slice, if it has one
+    pub fn end(&self) -> Option<&AstNode<'source>> {
+        self.end.as_deref()
+    }
+
+    /// Returns the node representing the step of the slice, if it has one
+    pub fn step(&self) -> Option<&AstNode<'source>> {
+        self.step.as_deref()
+    }
+
+    /// Returns whether or not this is an optional slice (`?[...]`)
+    pub fn is_optional(&self) -> bool {
+        self.optional
+    }
+
+    /// Returns the position in the source code of this AstNodeSlice
+    pub fn pos(&self) -> &SourceRange<'source> {
+        &self.pos
+    }
+
+    /// Returns a mutable reference to the position in the source code of this
+    /// AstNodeSlice
+    pub fn pos_mut(&mut self) -> &mut SourceRange<'source> {
+        &mut self.pos
+    }
+}
+
+impl Display for AstNodeSlice<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut children = vec![format_as_node("Root", vec![self.root.to_string()])];
+        for (name, node) in [
+            ("Start", &self.start),
+            ("End", &self.end),
+            ("Step", &self.step),
+        ] {
+            if let Some(node) = node {
+                children.push(format_as_node(name, vec![node.to_string()]));
+            }
+        }
+
+        let name = match self.optional {
+            true => "[Optional Slice]",
+            false => "[Slice]",
+        };
+
+        write!(f, "{}", format_as_node(name, children))
+    }
+}
+
+impl PartialEq for AstNodeSlice<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.root == other.root
+            && self.start == other.start
+            && self.end == other.end
+            && self.step == other.step
+            && self.optional == other.optional
+    }
+}
+impl Eq for AstNodeSlice<'_> {}
@@ -0,0 +1,57 @@
+use std::fmt::Display;
+
+use crate::source_range::SourceRange;
+
+use super::{ast_node_format::format_as_node, AstNode};
+
+/// A spread AST node (`...body`), which expands a collection into separate
+/// elements of a list literal, arguments of a function call, or entries of a
+/// dict literal
+#[derive(Debug, Clone)]
+pub struct AstNodeSpread<'source> {
+    body: Box<AstNode<'source>>,
+    pos: SourceRange<'source>,
+}
+
+impl<'source> AstNodeSpread<'source> {
+    /// Constructs a new AstNodeSpread with the given body and pos
+    pub fn new(body: AstNode<'source>, pos: SourceRange<'source>) -> Self {
+        Self {
+            body: Box::new(body),
+            pos,
+        }
+    }
+
+    /// Returns the body of the spread node (the collection being spread)
+    pub fn body(&self) -> &AstNode<'source> {
+        &self.body
+    }
+
+    /// Returns the position in the source code of this spread node
+    pub fn pos(&self) -> &SourceRange<'source> {
+        &self.pos
+    }
+
+    /// Returns a mutable reference to the position in the source code of this
+    /// spread node
+    pub fn pos_mut(&mut self) -> &mut SourceRange<'source> {
+        &mut self.pos
+    }
+}
+
+impl Display for AstNodeSpread<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            format_as_node("[Spread]", vec![self.body.to_string()])
+        )
+    }
+}
+
+impl PartialEq for AstNodeSpread<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.body == other.body
+    }
+}
+impl Eq for AstNodeSpread<'_> {}
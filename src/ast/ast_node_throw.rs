@@ -0,0 +1,55 @@
+use std::fmt::Display;
+
+use crate::source_range::SourceRange;
+
+use super::{ast_node_format::format_as_node, *};
+
+/// A throw statement AST node
+#[derive(Debug, Clone)]
+pub struct AstNodeThrow<'source> {
+    body: Box<AstNode<'source>>,
+    pos: SourceRange<'source>,
+}
+
+impl<'source> AstNodeThrow<'source> {
+    /// Constructs a new AstNodeThrow with the given body and pos
+    pub fn new(body: AstNode<'source>, pos: SourceRange<'source>) -> Self {
+        Self {
+            body: Box::new(body),
+            pos,
+        }
+    }
+
+    /// Returns the expression whose value is thrown
+    pub fn body(&self) -> &AstNode<'source> {
+        &self.body
+    }
+
+    /// Returns the position in the source code of this throw statement
+    pub fn pos(&self) -> &SourceRange<'source> {
+        &self.pos
+    }
+
+    /// Returns a mutable reference to the position in the source code of this
+    /// throw statement
+    pub fn pos_mut(&mut self) -> &mut SourceRange<'source> {
+        &mut self.pos
+    }
+}
+
+impl Display for AstNodeThrow<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            format_as_node("[Throw]", vec![self.body.to_string()])
+        )
+    }
+}
+
+impl PartialEq for AstNodeThrow<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.body == other.body
+    }
+}
+impl Eq for AstNodeThrow<'_> {}
@@ -0,0 +1,118 @@
+use std::fmt::Display;
+
+use crate::source_range::SourceRange;
+
+use super::{ast_node_format::format_as_node, *};
+
+/// A try-catch statement AST node
+#[derive(Debug, Clone)]
+pub struct AstNodeTryCatch<'source> {
+    try_body: Vec<AstNode<'source>>,
+    error_ident: String,
+    catch_body: Vec<AstNode<'source>>,
+    pos: SourceRange<'source>,
+}
+
+impl<'source> AstNodeTryCatch<'source> {
+    /// Constructs a new AstNodeTryCatch with the given try body, error
+    /// identifier, catch body, and pos
+    pub fn new(
+        try_body: Vec<AstNode<'source>>,
+        error_ident: String,
+        catch_body: Vec<AstNode<'source>>,
+        pos: SourceRange<'source>,
+    ) -> Self {
+        Self {
+            try_body,
+            error_ident,
+            catch_body,
+            pos,
+        }
+    }
+
+    /// Returns the body of the try block
+    pub fn try_body(&self) -> &Vec<AstNode<'source>> {
+        &self.try_body
+    }
+
+    /// Returns the identifier the caught error is bound to in the catch block
+    pub fn error_ident(&self) -> &str {
+        &self.error_ident
+    }
+
+    /// Returns the body of the catch block
+    pub fn catch_body(&self) -> &Vec<AstNode<'source>> {
+        &self.catch_body
+    }
+
+    /// Returns the position in the source code of this try-catch statement
+    pub fn pos(&self) -> &SourceRange<'source> {
+        &self.pos
+    }
+
+    /// Returns a mutable reference to the position in the source code of this
+    /// try-catch statement
+    pub fn pos_mut(&mut self) -> &mut SourceRange<'source> {
+        &mut self.pos
+    }
+}
+
+impl Display for AstNodeTryCatch<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            format_as_node(
+                "[Try Catch]",
+                vec![
+                    format_as_node(
+                        "Try",
+                        self.try_body.iter().map(|node| node.to_string()).collect()
+                    ),
+                    format_as_node(
+                        &format!("Catch {}", self.error_ident),
+                        self.catch_body
+                            .iter()
+                            .map(|node| node.to_string())
+                            .collect()
+                    ),
+                ]
+            )
+        )
+    }
+}
+
+impl PartialEq for AstNodeTryCatch<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.try_body == other.try_body
+            && self.error_ident == other.error_ident
+            && self.catch_body == other.catch_body
+    }
+}
+impl Eq for AstNodeTryCatch<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ast_node_display_try_catch() {
+        let nowhere = SourceRange::new(" ", "", 0, 0);
+        let node: AstNode = AstNodeTryCatch::new(
+            vec![AstNodeVariableAccess::new("foo".to_string(), nowhere.clone()).into()],
+            "err".to_string(),
+            vec![AstNodeVariableAccess::new("err".to_string(), nowhere.clone()).into()],
+            nowhere,
+        )
+        .into();
+
+        assert_eq!(
+            node.to_string(),
+            "● [Try Catch]\n\
+            ├─● Try\n\
+            │ └─● [Variable Access] foo\n\
+            └─● Catch err\n  \
+              └─● [Variable Access] err"
+        );
+    }
+}
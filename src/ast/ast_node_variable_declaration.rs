@@ -4,27 +4,53 @@ use crate::source_range::SourceRange;
 
 use super::{ast_node_format::format_as_node, *};
 
-/// A variable declaration AST node
+/// A variable declaration AST node (either with `let`, or with `const` for
+/// constants)
 #[derive(Debug, Clone)]
 pub struct AstNodeVariableDeclaration<'source> {
-    declarations: Vec<(String, Option<AstNode<'source>>, SourceRange<'source>)>,
+    declarations: Vec<(
+        DestructuringPattern<'source>,
+        Option<AstNode<'source>>,
+        SourceRange<'source>,
+    )>,
+    is_constant: bool,
     pos: SourceRange<'source>,
 }
 
 impl<'source> AstNodeVariableDeclaration<'source> {
-    /// Constructs a new AstNodeVariableDeclaration with the given declarations
-    /// and pos
+    /// Constructs a new AstNodeVariableDeclaration with the given
+    /// declarations, constness, and pos
     pub fn new(
-        declarations: Vec<(String, Option<AstNode<'source>>, SourceRange<'source>)>,
+        declarations: Vec<(
+            DestructuringPattern<'source>,
+            Option<AstNode<'source>>,
+            SourceRange<'source>,
+        )>,
+        is_constant: bool,
         pos: SourceRange<'source>,
     ) -> Self {
-        Self { declarations, pos }
+        Self {
+            declarations,
+            is_constant,
+            pos,
+        }
     }
 
     /// Returns the list of declarations of the assignment node
-    pub fn declarations(&self) -> &Vec<(String, Option<AstNode<'source>>, SourceRange<'source>)> {
+    pub fn declarations(
+        &self,
+    ) -> &Vec<(
+        DestructuringPattern<'source>,
+        Option<AstNode<'source>>,
+        SourceRange<'source>,
+    )> {
         &self.declarations
     }
+
+    /// Returns whether or not the declared variables are constants
+    pub fn is_constant(&self) -> bool {
+        self.is_constant
+    }
     /// Returns the position in the source code of this variable declaration node
     pub fn pos(&self) -> &SourceRange<'source> {
         &self.pos
@@ -43,18 +69,30 @@ impl Display for AstNodeVariableDeclaration<'_> {
             f,
             "{}",
             format_as_node(
-                "[Variable Declaration]",
+                if self.is_constant {
+                    "[Constant Declaration]"
+                } else {
+                    "[Variable Declaration]"
+                },
                 self.declarations
                     .iter()
-                    .map(|(ident, value, _)| format_as_node(
-                        if value.is_some() {
-                            ident.to_string()
-                        } else {
-                            format!("{ident} (uninitialized)")
-                        }
-                        .as_str(),
-                        value.iter().map(|node| node.to_string()).collect()
-                    ))
+                    .map(|(pattern, value, _)| match pattern.ident() {
+                        Some(ident) => format_as_node(
+                            if value.is_some() {
+                                ident.to_string()
+                            } else {
+                                format!("{ident} (uninitialized)")
+                            }
+                            .as_str(),
+                            value.iter().map(|node| node.to_string()).collect()
+                        ),
+                        None => format_as_node(
+                            "Destructuring",
+                            std::iter::once(pattern.to_string())
+                                .chain(value.iter().map(|node| node.to_string()))
+                                .collect()
+                        ),
+                    })
                     .collect()
             )
         )
@@ -63,10 +101,12 @@ impl Display for AstNodeVariableDeclaration<'_> {
 
 impl PartialEq for AstNodeVariableDeclaration<'_> {
     fn eq(&self, other: &Self) -> bool {
-        self.declarations
-            .iter()
-            .zip(other.declarations.iter())
-            .all(|((ident1, value1, _), (ident2, value2, _))| ident1 == ident2 && value1 == value2)
+        self.is_constant == other.is_constant
+            && self.declarations.iter().zip(other.declarations.iter()).all(
+                |((pattern1, value1, _), (pattern2, value2, _))| {
+                    pattern1 == pattern2 && value1 == value2
+                },
+            )
     }
 }
 impl Eq for AstNodeVariableDeclaration<'_> {}
@@ -9,20 +9,23 @@ use super::{ast_node_format::format_as_node, *};
 pub struct AstNodeWhileLoop<'source> {
     condition: Box<AstNode<'source>>,
     body: Vec<AstNode<'source>>,
+    label: Option<String>,
     pos: SourceRange<'source>,
 }
 
 impl<'source> AstNodeWhileLoop<'source> {
-    /// Constructs a new AstNodeWhileLoop with the given condition, body, and
-    /// pos
+    /// Constructs a new AstNodeWhileLoop with the given condition, body,
+    /// label, and pos
     pub fn new(
         condition: AstNode<'source>,
         body: Vec<AstNode<'source>>,
+        label: Option<String>,
         pos: SourceRange<'source>,
     ) -> Self {
         Self {
             condition: Box::new(condition),
             body,
+            label,
             pos,
         }
     }
@@ -37,6 +40,12 @@ impl<'source> AstNodeWhileLoop<'source> {
         &self.body
     }
 
+    /// Returns the label of the while loop node (without the leading `'`), if
+    /// it has one
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
     /// Returns the position in the source code of this while loop node
     pub fn pos(&self) -> &SourceRange<'source> {
         &self.pos
@@ -55,7 +64,10 @@ impl Display for AstNodeWhileLoop<'_> {
             f,
             "{}",
             format_as_node(
-                "[While loop]",
+                &match &self.label {
+                    Some(label) => format!("[While loop] '{label}"),
+                    None => "[While loop]".to_string(),
+                },
                 vec![
                     format_as_node("Condition", vec![self.condition.to_string()]),
                     format_as_node(
@@ -70,7 +82,7 @@ impl Display for AstNodeWhileLoop<'_> {
 
 impl PartialEq for AstNodeWhileLoop<'_> {
     fn eq(&self, other: &Self) -> bool {
-        self.condition == other.condition && self.body == other.body
+        self.condition == other.condition && self.body == other.body && self.label == other.label
     }
 }
 impl Eq for AstNodeWhileLoop<'_> {}
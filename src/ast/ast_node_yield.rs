@@ -0,0 +1,58 @@
+use std::fmt::Display;
+
+use crate::source_range::SourceRange;
+
+use super::{ast_node_format::format_as_node, *};
+
+/// A yield statement AST node
+#[derive(Debug, Clone)]
+pub struct AstNodeYield<'source> {
+    body: Option<Box<AstNode<'source>>>,
+    pos: SourceRange<'source>,
+}
+
+impl<'source> AstNodeYield<'source> {
+    /// Constructs a new AstNodeYield with the given body and pos
+    pub fn new(body: Option<AstNode<'source>>, pos: SourceRange<'source>) -> Self {
+        Self {
+            body: body.map(Box::new),
+            pos,
+        }
+    }
+
+    /// Returns the expression whose value is yielded, if there is one
+    pub fn body(&self) -> Option<&AstNode<'source>> {
+        self.body.as_deref()
+    }
+
+    /// Returns the position in the source code of this yield statement
+    pub fn pos(&self) -> &SourceRange<'source> {
+        &self.pos
+    }
+
+    /// Returns a mutable reference to the position in the source code of this
+    /// yield statement
+    pub fn pos_mut(&mut self) -> &mut SourceRange<'source> {
+        &mut self.pos
+    }
+}
+
+impl Display for AstNodeYield<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            format_as_node(
+                "[Yield]",
+                self.body.iter().map(|body| body.to_string()).collect()
+            )
+        )
+    }
+}
+
+impl PartialEq for AstNodeYield<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.body == other.body
+    }
+}
+impl Eq for AstNodeYield<'_> {}
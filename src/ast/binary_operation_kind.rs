@@ -3,6 +3,9 @@ use std::fmt::Display;
 /// The kind of a binary operation
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum BinaryOperationKind {
+    /// The null-coalescing (??) binary operation
+    NullCoalescing,
+
     /// The logical or (||) binary operation
     LogicalOr,
 
@@ -49,6 +52,7 @@ impl Display for BinaryOperationKind {
             f,
             "{}",
             match self {
+                BinaryOperationKind::NullCoalescing => "??",
                 BinaryOperationKind::LogicalOr => "||",
                 BinaryOperationKind::LogicalAnd => "&&",
                 BinaryOperationKind::BitwiseOr => "|",
@@ -20,6 +20,12 @@ pub enum ComparisonKind {
 
     /// The greater than or equal to (>=) comparison
     GreaterThanOrEqual,
+
+    /// The membership (in) comparison
+    In,
+
+    /// The non-membership (not in) comparison
+    NotIn,
 }
 
 impl Display for ComparisonKind {
@@ -34,6 +40,8 @@ impl Display for ComparisonKind {
                 ComparisonKind::GreaterThan => ">",
                 ComparisonKind::LessThanOrEqual => "<=",
                 ComparisonKind::GreaterThanOrEqual => ">=",
+                ComparisonKind::In => "in",
+                ComparisonKind::NotIn => "not in",
             }
         )
     }
@@ -0,0 +1,115 @@
+use std::fmt::Display;
+
+use crate::source_range::SourceRange;
+
+use super::{ast_node_format::format_as_node, *};
+
+/// A clause in a list or dict comprehension
+#[derive(Debug, Clone)]
+pub enum ComprehensionClause<'source> {
+    /// A `for` clause, which evaluates the rest of the comprehension once for
+    /// each value of its iterable (like a for loop)
+    For {
+        /// The loop-control variable pattern of the clause
+        pattern: DestructuringPattern<'source>,
+
+        /// Whether the clause has several comma-separated loop-control
+        /// variables (like `for k, v in ...`), in which case `pattern` is a
+        /// list pattern containing each of them
+        multiple_variables: bool,
+
+        /// The iterable expression of the clause
+        iterable: Box<AstNode<'source>>,
+
+        /// The position of the clause in the source code
+        pos: SourceRange<'source>,
+    },
+
+    /// An `if` clause, which only evaluates the rest of the comprehension if
+    /// its condition is true
+    If {
+        /// The condition of the clause
+        condition: Box<AstNode<'source>>,
+
+        /// The position of the clause in the source code
+        pos: SourceRange<'source>,
+    },
+}
+
+impl<'source> ComprehensionClause<'source> {
+    /// Returns the position in the source code of this clause
+    pub fn pos(&self) -> &SourceRange<'source> {
+        match self {
+            ComprehensionClause::For {
+                pattern: _,
+                multiple_variables: _,
+                iterable: _,
+                pos,
+            } => pos,
+            ComprehensionClause::If { condition: _, pos } => pos,
+        }
+    }
+}
+
+impl Display for ComprehensionClause<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                ComprehensionClause::For {
+                    pattern,
+                    multiple_variables: _,
+                    iterable,
+                    pos: _,
+                } => format_as_node(
+                    "For",
+                    vec![
+                        format_as_node("Pattern", vec![pattern.to_string()]),
+                        format_as_node("Iterable", vec![iterable.to_string()]),
+                    ]
+                ),
+                ComprehensionClause::If { condition, pos: _ } => {
+                    format_as_node("If", vec![condition.to_string()])
+                }
+            }
+        )
+    }
+}
+
+impl PartialEq for ComprehensionClause<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                ComprehensionClause::For {
+                    pattern: pattern1,
+                    multiple_variables: multiple_variables1,
+                    iterable: iterable1,
+                    pos: _,
+                },
+                ComprehensionClause::For {
+                    pattern: pattern2,
+                    multiple_variables: multiple_variables2,
+                    iterable: iterable2,
+                    pos: _,
+                },
+            ) => {
+                pattern1 == pattern2
+                    && multiple_variables1 == multiple_variables2
+                    && iterable1 == iterable2
+            }
+            (
+                ComprehensionClause::If {
+                    condition: condition1,
+                    pos: _,
+                },
+                ComprehensionClause::If {
+                    condition: condition2,
+                    pos: _,
+                },
+            ) => condition1 == condition2,
+            _ => false,
+        }
+    }
+}
+impl Eq for ComprehensionClause<'_> {}
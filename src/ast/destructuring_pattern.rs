@@ -0,0 +1,185 @@
+use std::fmt::Display;
+
+use crate::source_range::SourceRange;
+
+use super::{ast_node_format::format_as_node, *};
+
+/// A pattern which destructures a value into one or more targets, as used in
+/// variable declarations, assignments, and for loops
+#[derive(Debug, Clone)]
+pub enum DestructuringPattern<'source> {
+    /// A single target, which receives the whole value. In variable
+    /// declarations and for loops, this is always a variable access (naming
+    /// the variable to declare), but in assignments it may be any lvalue
+    Target(Box<AstNode<'source>>),
+
+    /// A list pattern, which destructures a list element-by-element
+    List {
+        /// The patterns for each element of the list (not including the rest
+        /// pattern)
+        elements: Vec<DestructuringPattern<'source>>,
+
+        /// The rest pattern (if any), which receives a list of all the
+        /// elements not matched by the other element patterns, along with
+        /// the number of element patterns which come before it
+        rest: Option<(usize, Box<DestructuringPattern<'source>>)>,
+
+        /// The position of the pattern in the source code
+        pos: SourceRange<'source>,
+    },
+
+    /// A dict pattern, which destructures a dict entry-by-entry
+    Dict {
+        /// The keys to look up, and the patterns for their corresponding values
+        entries: Vec<(AstNode<'source>, DestructuringPattern<'source>)>,
+
+        /// The rest pattern (if any), which receives a dict of all the entries
+        /// whose keys weren't looked up by the other entries
+        rest: Option<Box<DestructuringPattern<'source>>>,
+
+        /// The position of the pattern in the source code
+        pos: SourceRange<'source>,
+    },
+}
+
+impl<'source> DestructuringPattern<'source> {
+    /// Returns the identifier of the pattern, if it is just a single variable
+    pub fn ident(&self) -> Option<&str> {
+        match self {
+            DestructuringPattern::Target(target) => match target.as_ref() {
+                AstNode::VariableAccess(node) => Some(node.ident()),
+                _ => None,
+            },
+            DestructuringPattern::List { .. } | DestructuringPattern::Dict { .. } => None,
+        }
+    }
+
+    /// Returns the position in the source code of this pattern
+    pub fn pos(&self) -> &SourceRange<'source> {
+        match self {
+            DestructuringPattern::Target(target) => target.pos(),
+            DestructuringPattern::List {
+                elements: _,
+                rest: _,
+                pos,
+            } => pos,
+            DestructuringPattern::Dict {
+                entries: _,
+                rest: _,
+                pos,
+            } => pos,
+        }
+    }
+}
+
+impl Display for DestructuringPattern<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                DestructuringPattern::Target(target) => target.to_string(),
+                DestructuringPattern::List {
+                    elements,
+                    rest,
+                    pos: _,
+                } => {
+                    let mut children: Vec<String> =
+                        elements.iter().map(|element| element.to_string()).collect();
+                    if let Some((index, rest)) = rest {
+                        children.insert(*index, format_as_node("Rest", vec![rest.to_string()]));
+                    }
+
+                    format_as_node("[List Pattern]", children)
+                }
+                DestructuringPattern::Dict {
+                    entries,
+                    rest,
+                    pos: _,
+                } => {
+                    let mut children: Vec<String> = entries
+                        .iter()
+                        .map(|(key, value)| {
+                            format_as_node(
+                                "Entry",
+                                vec![
+                                    format_as_node("Key", vec![key.to_string()]),
+                                    format_as_node("Value", vec![value.to_string()]),
+                                ],
+                            )
+                        })
+                        .collect();
+                    if let Some(rest) = rest {
+                        children.push(format_as_node("Rest", vec![rest.to_string()]));
+                    }
+
+                    format_as_node("[Dict Pattern]", children)
+                }
+            }
+        )
+    }
+}
+
+impl PartialEq for DestructuringPattern<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Target(l_target), Self::Target(r_target)) => l_target == r_target,
+            (
+                Self::List {
+                    elements: l_elements,
+                    rest: l_rest,
+                    pos: _,
+                },
+                Self::List {
+                    elements: r_elements,
+                    rest: r_rest,
+                    pos: _,
+                },
+            ) => l_elements == r_elements && l_rest == r_rest,
+            (
+                Self::Dict {
+                    entries: l_entries,
+                    rest: l_rest,
+                    pos: _,
+                },
+                Self::Dict {
+                    entries: r_entries,
+                    rest: r_rest,
+                    pos: _,
+                },
+            ) => l_entries == r_entries && l_rest == r_rest,
+            _ => false,
+        }
+    }
+}
+impl Eq for DestructuringPattern<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_destructuring_pattern_display() {
+        let nowhere = SourceRange::new(" ", "", 0, 0);
+        let target = |ident: &str| {
+            DestructuringPattern::Target(Box::new(
+                AstNodeVariableAccess::new(ident.to_string(), nowhere.clone()).into(),
+            ))
+        };
+        let pattern = DestructuringPattern::List {
+            elements: vec![target("first"), target("last")],
+            rest: Some((1, Box::new(target("middle")))),
+            pos: nowhere.clone(),
+        };
+
+        assert_eq!(
+            pattern.to_string(),
+            "\
+● [List Pattern]
+├─● [Variable Access] first
+├─● Rest
+│ └─● [Variable Access] middle
+└─● [Variable Access] last"
+        );
+    }
+}
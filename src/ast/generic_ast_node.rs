@@ -9,24 +9,44 @@ use super::*;
 pub enum AstNode<'source> {
     /// A function declaration node
     FunctionDeclaration(AstNodeFunctionDeclaration<'source>),
+    /// An anonymous function node
+    AnonymousFunction(AstNodeAnonymousFunction<'source>),
     /// A variable declaration node
     VariableDeclaration(AstNodeVariableDeclaration<'source>),
+    /// An import node
+    Import(AstNodeImport<'source>),
+    /// A record declaration node
+    RecordDeclaration(AstNodeRecordDeclaration<'source>),
     /// A variable access node
     VariableAccess(AstNodeVariableAccess<'source>),
     /// A literal node
     Literal(AstNodeLiteral<'source>),
     /// A list literal node
     ListLiteral(AstNodeListLiteral<'source>),
+    /// A list comprehension node
+    ListComprehension(AstNodeListComprehension<'source>),
     /// A formatted string literal node
     FormattedStringLiteral(AstNodeFormattedStringLiteral<'source>),
     /// A dict literal node
     DictLiteral(AstNodeDictLiteral<'source>),
+    /// A dict comprehension node
+    DictComprehension(AstNodeDictComprehension<'source>),
+    /// A set literal node
+    SetLiteral(AstNodeSetLiteral<'source>),
+    /// A record literal node
+    RecordLiteral(AstNodeRecordLiteral<'source>),
+    /// A range literal node
+    RangeLiteral(AstNodeRangeLiteral<'source>),
     /// A type cast node
     TypeCast(AstNodeTypeCast<'source>),
     /// A dot member access node
     DotMemberAccess(AstNodeDotMemberAccess<'source>),
     /// A computed member access node
     ComputedMemberAccess(AstNodeComputedMemberAccess<'source>),
+    /// A slice node
+    Slice(AstNodeSlice<'source>),
+    /// A spread node
+    Spread(AstNodeSpread<'source>),
     /// A function call node
     FunctionCall(AstNodeFunctionCall<'source>),
     /// A binary operation node
@@ -39,6 +59,8 @@ pub enum AstNode<'source> {
     InlineConditional(AstNodeInlineConditional<'source>),
     /// An assignment node
     Assignment(AstNodeAssignment<'source>),
+    /// A destructuring assignment node
+    DestructuringAssignment(AstNodeDestructuringAssignment<'source>),
     /// A jump statement node
     JumpStatement(AstNodeJumpStatement<'source>),
     /// A simple (`loop`) loop node
@@ -51,6 +73,12 @@ pub enum AstNode<'source> {
     MatchStatement(AstNodeMatchStatement<'source>),
     /// An if-else statement node
     IfElseStatement(AstNodeIfElseStatement<'source>),
+    /// A try-catch statement node
+    TryCatch(AstNodeTryCatch<'source>),
+    /// A throw statement node
+    Throw(AstNodeThrow<'source>),
+    /// A yield statement node
+    Yield(AstNodeYield<'source>),
 }
 
 impl<'source> AstNode<'source> {
@@ -58,27 +86,41 @@ impl<'source> AstNode<'source> {
     pub fn pos(&self) -> &SourceRange<'source> {
         match self {
             AstNode::FunctionDeclaration(node) => node.pos(),
+            AstNode::AnonymousFunction(node) => node.pos(),
             AstNode::VariableDeclaration(node) => node.pos(),
+            AstNode::Import(node) => node.pos(),
+            AstNode::RecordDeclaration(node) => node.pos(),
             AstNode::VariableAccess(node) => node.pos(),
             AstNode::Literal(node) => node.pos(),
             AstNode::FormattedStringLiteral(node) => node.pos(),
             AstNode::ListLiteral(node) => node.pos(),
+            AstNode::ListComprehension(node) => node.pos(),
             AstNode::DictLiteral(node) => node.pos(),
+            AstNode::DictComprehension(node) => node.pos(),
+            AstNode::SetLiteral(node) => node.pos(),
+            AstNode::RecordLiteral(node) => node.pos(),
+            AstNode::RangeLiteral(node) => node.pos(),
             AstNode::TypeCast(node) => node.pos(),
             AstNode::DotMemberAccess(node) => node.pos(),
             AstNode::ComputedMemberAccess(node) => node.pos(),
+            AstNode::Slice(node) => node.pos(),
+            AstNode::Spread(node) => node.pos(),
             AstNode::FunctionCall(node) => node.pos(),
             AstNode::BinaryOperation(node) => node.pos(),
             AstNode::UnaryOperation(node) => node.pos(),
             AstNode::Comparison(node) => node.pos(),
             AstNode::InlineConditional(node) => node.pos(),
             AstNode::Assignment(node) => node.pos(),
+            AstNode::DestructuringAssignment(node) => node.pos(),
             AstNode::JumpStatement(node) => node.pos(),
             AstNode::SimpleLoop(node) => node.pos(),
             AstNode::WhileLoop(node) => node.pos(),
             AstNode::ForLoop(node) => node.pos(),
             AstNode::MatchStatement(node) => node.pos(),
             AstNode::IfElseStatement(node) => node.pos(),
+            AstNode::TryCatch(node) => node.pos(),
+            AstNode::Throw(node) => node.pos(),
+            AstNode::Yield(node) => node.pos(),
         }
     }
     /// Returns a mutable reference to the position in the source code of this
@@ -86,27 +128,41 @@ impl<'source> AstNode<'source> {
     pub fn pos_mut(&mut self) -> &mut SourceRange<'source> {
         match self {
             AstNode::FunctionDeclaration(node) => node.pos_mut(),
+            AstNode::AnonymousFunction(node) => node.pos_mut(),
             AstNode::VariableDeclaration(node) => node.pos_mut(),
+            AstNode::Import(node) => node.pos_mut(),
+            AstNode::RecordDeclaration(node) => node.pos_mut(),
             AstNode::VariableAccess(node) => node.pos_mut(),
             AstNode::Literal(node) => node.pos_mut(),
             AstNode::FormattedStringLiteral(node) => node.pos_mut(),
             AstNode::ListLiteral(node) => node.pos_mut(),
+            AstNode::ListComprehension(node) => node.pos_mut(),
             AstNode::DictLiteral(node) => node.pos_mut(),
+            AstNode::DictComprehension(node) => node.pos_mut(),
+            AstNode::SetLiteral(node) => node.pos_mut(),
+            AstNode::RecordLiteral(node) => node.pos_mut(),
+            AstNode::RangeLiteral(node) => node.pos_mut(),
             AstNode::TypeCast(node) => node.pos_mut(),
             AstNode::DotMemberAccess(node) => node.pos_mut(),
             AstNode::ComputedMemberAccess(node) => node.pos_mut(),
+            AstNode::Slice(node) => node.pos_mut(),
+            AstNode::Spread(node) => node.pos_mut(),
             AstNode::FunctionCall(node) => node.pos_mut(),
             AstNode::BinaryOperation(node) => node.pos_mut(),
             AstNode::UnaryOperation(node) => node.pos_mut(),
             AstNode::Comparison(node) => node.pos_mut(),
             AstNode::InlineConditional(node) => node.pos_mut(),
             AstNode::Assignment(node) => node.pos_mut(),
+            AstNode::DestructuringAssignment(node) => node.pos_mut(),
             AstNode::JumpStatement(node) => node.pos_mut(),
             AstNode::SimpleLoop(node) => node.pos_mut(),
             AstNode::WhileLoop(node) => node.pos_mut(),
             AstNode::ForLoop(node) => node.pos_mut(),
             AstNode::MatchStatement(node) => node.pos_mut(),
             AstNode::IfElseStatement(node) => node.pos_mut(),
+            AstNode::TryCatch(node) => node.pos_mut(),
+            AstNode::Throw(node) => node.pos_mut(),
+            AstNode::Yield(node) => node.pos_mut(),
         }
     }
 }
@@ -118,27 +174,41 @@ impl Display for AstNode<'_> {
             "{}",
             match self {
                 AstNode::FunctionDeclaration(node) => node.to_string(),
+                AstNode::AnonymousFunction(node) => node.to_string(),
                 AstNode::VariableDeclaration(node) => node.to_string(),
+                AstNode::Import(node) => node.to_string(),
+                AstNode::RecordDeclaration(node) => node.to_string(),
                 AstNode::VariableAccess(node) => node.to_string(),
                 AstNode::Literal(node) => node.to_string(),
                 AstNode::FormattedStringLiteral(node) => node.to_string(),
                 AstNode::ListLiteral(node) => node.to_string(),
+                AstNode::ListComprehension(node) => node.to_string(),
                 AstNode::DictLiteral(node) => node.to_string(),
+                AstNode::DictComprehension(node) => node.to_string(),
+                AstNode::SetLiteral(node) => node.to_string(),
+                AstNode::RecordLiteral(node) => node.to_string(),
+                AstNode::RangeLiteral(node) => node.to_string(),
                 AstNode::TypeCast(node) => node.to_string(),
                 AstNode::DotMemberAccess(node) => node.to_string(),
                 AstNode::ComputedMemberAccess(node) => node.to_string(),
+                AstNode::Slice(node) => node.to_string(),
+                AstNode::Spread(node) => node.to_string(),
                 AstNode::FunctionCall(node) => node.to_string(),
                 AstNode::BinaryOperation(node) => node.to_string(),
                 AstNode::UnaryOperation(node) => node.to_string(),
                 AstNode::Comparison(node) => node.to_string(),
                 AstNode::InlineConditional(node) => node.to_string(),
                 AstNode::Assignment(node) => node.to_string(),
+                AstNode::DestructuringAssignment(node) => node.to_string(),
                 AstNode::JumpStatement(node) => node.to_string(),
                 AstNode::SimpleLoop(node) => node.to_string(),
                 AstNode::WhileLoop(node) => node.to_string(),
                 AstNode::ForLoop(node) => node.to_string(),
                 AstNode::MatchStatement(node) => node.to_string(),
                 AstNode::IfElseStatement(node) => node.to_string(),
+                AstNode::TryCatch(node) => node.to_string(),
+                AstNode::Throw(node) => node.to_string(),
+                AstNode::Yield(node) => node.to_string(),
             }
         )
     }
@@ -154,24 +224,38 @@ macro_rules! impl_from_specific_ast_node {
     };
 }
 impl_from_specific_ast_node!(AstNodeFunctionDeclaration, FunctionDeclaration);
+impl_from_specific_ast_node!(AstNodeAnonymousFunction, AnonymousFunction);
 impl_from_specific_ast_node!(AstNodeVariableDeclaration, VariableDeclaration);
+impl_from_specific_ast_node!(AstNodeImport, Import);
+impl_from_specific_ast_node!(AstNodeRecordDeclaration, RecordDeclaration);
 impl_from_specific_ast_node!(AstNodeVariableAccess, VariableAccess);
 impl_from_specific_ast_node!(AstNodeLiteral, Literal);
 impl_from_specific_ast_node!(AstNodeListLiteral, ListLiteral);
+impl_from_specific_ast_node!(AstNodeListComprehension, ListComprehension);
 impl_from_specific_ast_node!(AstNodeFormattedStringLiteral, FormattedStringLiteral);
 impl_from_specific_ast_node!(AstNodeDictLiteral, DictLiteral);
+impl_from_specific_ast_node!(AstNodeDictComprehension, DictComprehension);
+impl_from_specific_ast_node!(AstNodeSetLiteral, SetLiteral);
+impl_from_specific_ast_node!(AstNodeRecordLiteral, RecordLiteral);
+impl_from_specific_ast_node!(AstNodeRangeLiteral, RangeLiteral);
 impl_from_specific_ast_node!(AstNodeTypeCast, TypeCast);
 impl_from_specific_ast_node!(AstNodeDotMemberAccess, DotMemberAccess);
 impl_from_specific_ast_node!(AstNodeComputedMemberAccess, ComputedMemberAccess);
+impl_from_specific_ast_node!(AstNodeSlice, Slice);
+impl_from_specific_ast_node!(AstNodeSpread, Spread);
 impl_from_specific_ast_node!(AstNodeFunctionCall, FunctionCall);
 impl_from_specific_ast_node!(AstNodeBinaryOperation, BinaryOperation);
 impl_from_specific_ast_node!(AstNodeUnaryOperation, UnaryOperation);
 impl_from_specific_ast_node!(AstNodeComparison, Comparison);
 impl_from_specific_ast_node!(AstNodeInlineConditional, InlineConditional);
 impl_from_specific_ast_node!(AstNodeAssignment, Assignment);
+impl_from_specific_ast_node!(AstNodeDestructuringAssignment, DestructuringAssignment);
 impl_from_specific_ast_node!(AstNodeJumpStatement, JumpStatement);
 impl_from_specific_ast_node!(AstNodeSimpleLoop, SimpleLoop);
 impl_from_specific_ast_node!(AstNodeWhileLoop, WhileLoop);
 impl_from_specific_ast_node!(AstNodeForLoop, ForLoop);
 impl_from_specific_ast_node!(AstNodeMatchStatement, MatchStatement);
 impl_from_specific_ast_node!(AstNodeIfElseStatement, IfElseStatement);
+impl_from_specific_ast_node!(AstNodeTryCatch, TryCatch);
+impl_from_specific_ast_node!(AstNodeThrow, Throw);
+impl_from_specific_ast_node!(AstNodeYield, Yield);
@@ -5,30 +5,38 @@ use super::*;
 /// One arm in a match statement
 #[derive(Debug, Clone)]
 pub struct MatchArm<'source> {
-    pattern: Box<AstNode<'source>>,
+    pattern: MatchPattern<'source>,
+    guard: Option<Box<AstNode<'source>>>,
     body: Vec<AstNode<'source>>,
     pos: SourceRange<'source>,
 }
 
 impl<'source> MatchArm<'source> {
-    /// Constructs a new MatchArm from the given pattern, body, and pos
+    /// Constructs a new MatchArm from the given pattern, guard, body, and pos
     pub fn new(
-        pattern: AstNode<'source>,
+        pattern: MatchPattern<'source>,
+        guard: Option<AstNode<'source>>,
         body: Vec<AstNode<'source>>,
         pos: SourceRange<'source>,
     ) -> Self {
         Self {
-            pattern: Box::new(pattern),
+            pattern,
+            guard: guard.map(Box::new),
             body,
             pos,
         }
     }
 
     /// Returns the pattern of the match arm
-    pub fn pattern(&self) -> &AstNode<'source> {
+    pub fn pattern(&self) -> &MatchPattern<'source> {
         &self.pattern
     }
 
+    /// Returns the guard of the match arm (the condition after `if`), if any
+    pub fn guard(&self) -> Option<&AstNode<'source>> {
+        self.guard.as_deref()
+    }
+
     /// Returns the body of the match arm
     pub fn body(&self) -> &Vec<AstNode<'source>> {
         &self.body
@@ -48,7 +56,7 @@ impl<'source> MatchArm<'source> {
 
 impl PartialEq for MatchArm<'_> {
     fn eq(&self, other: &Self) -> bool {
-        self.pattern == other.pattern && self.body == other.body
+        self.pattern == other.pattern && self.guard == other.guard && self.body == other.body
     }
 }
 impl Eq for MatchArm<'_> {}
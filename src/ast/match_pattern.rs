@@ -0,0 +1,416 @@
+use std::fmt::Display;
+
+use crate::{icelang_type::IcelangType, source_range::SourceRange};
+
+use super::{ast_node_format::format_as_node, *};
+
+/// A pattern in a match arm, which a value may or may not match
+#[derive(Debug, Clone)]
+pub enum MatchPattern<'source> {
+    /// The wildcard pattern (`_`), which matches any value
+    Wildcard {
+        /// The position of the pattern in the source code
+        pos: SourceRange<'source>,
+    },
+
+    /// A binding pattern, which matches any value and binds it to a new
+    /// variable
+    Binding {
+        /// The identifier of the variable to bind
+        ident: String,
+
+        /// The position of the pattern in the source code
+        pos: SourceRange<'source>,
+    },
+
+    /// A value pattern, which matches values equal to the result of an
+    /// expression (a literal, or a constant accessed through dot member access)
+    Value(Box<AstNode<'source>>),
+
+    /// A range pattern (`start..end` or `start..=end`), which matches the ints
+    /// contained in a range
+    Range(AstNodeRangeLiteral<'source>),
+
+    /// A type pattern, which matches values of a specific type, and optionally
+    /// matches the value against another pattern
+    Type {
+        /// The type to match
+        icelang_type: IcelangType,
+
+        /// The pattern to match the value against (if any)
+        inner: Option<Box<MatchPattern<'source>>>,
+
+        /// The position of the pattern in the source code
+        pos: SourceRange<'source>,
+    },
+
+    /// A list pattern, which matches lists element-by-element
+    List {
+        /// The patterns for each element of the list (not including the rest
+        /// pattern)
+        elements: Vec<MatchPattern<'source>>,
+
+        /// The rest pattern (if any), which is matched against a list of all
+        /// the elements not matched by the other element patterns, along with
+        /// the number of element patterns which come before it
+        rest: Option<(usize, Box<MatchPattern<'source>>)>,
+
+        /// The position of the pattern in the source code
+        pos: SourceRange<'source>,
+    },
+
+    /// A dict pattern, which matches dicts containing specific keys
+    Dict {
+        /// The keys which must exist, and the patterns for their
+        /// corresponding values
+        entries: Vec<(AstNode<'source>, MatchPattern<'source>)>,
+
+        /// The rest pattern (if any), which is matched against a dict of all
+        /// the entries whose keys weren't looked up by the other entries
+        rest: Option<Box<MatchPattern<'source>>>,
+
+        /// The position of the pattern in the source code
+        pos: SourceRange<'source>,
+    },
+
+    /// An alternatives pattern (`a | b`), which matches values matching any of
+    /// its alternatives
+    Alternatives {
+        /// The alternatives, in the order they are tried
+        alternatives: Vec<MatchPattern<'source>>,
+
+        /// The position of the pattern in the source code
+        pos: SourceRange<'source>,
+    },
+}
+
+impl<'source> MatchPattern<'source> {
+    /// Returns whether or not this pattern matches every possible value
+    pub fn is_irrefutable(&self) -> bool {
+        match self {
+            MatchPattern::Wildcard { pos: _ } | MatchPattern::Binding { ident: _, pos: _ } => true,
+            MatchPattern::Alternatives {
+                alternatives,
+                pos: _,
+            } => alternatives.iter().any(MatchPattern::is_irrefutable),
+            MatchPattern::Value(_)
+            | MatchPattern::Range(_)
+            | MatchPattern::Type { .. }
+            | MatchPattern::List { .. }
+            | MatchPattern::Dict { .. } => false,
+        }
+    }
+
+    /// Returns the identifiers bound by this pattern (and their positions), in
+    /// the order they appear. For alternatives, only the bindings of the first
+    /// alternative are returned
+    pub fn bindings(&self) -> Vec<(&str, &SourceRange<'source>)> {
+        let mut bindings = Vec::new();
+        self.collect_bindings(&mut bindings);
+        bindings
+    }
+
+    fn collect_bindings<'a>(&'a self, bindings: &mut Vec<(&'a str, &'a SourceRange<'source>)>) {
+        match self {
+            MatchPattern::Wildcard { pos: _ } | MatchPattern::Value(_) | MatchPattern::Range(_) => {
+            }
+            MatchPattern::Binding { ident, pos } => bindings.push((ident, pos)),
+            MatchPattern::Type {
+                icelang_type: _,
+                inner,
+                pos: _,
+            } => {
+                if let Some(inner) = inner {
+                    inner.collect_bindings(bindings);
+                }
+            }
+            MatchPattern::List {
+                elements,
+                rest,
+                pos: _,
+            } => {
+                let rest_index = rest.as_ref().map_or(elements.len(), |(index, _)| *index);
+                for element in &elements[..rest_index] {
+                    element.collect_bindings(bindings);
+                }
+                if let Some((_, rest)) = rest {
+                    rest.collect_bindings(bindings);
+                }
+                for element in &elements[rest_index..] {
+                    element.collect_bindings(bindings);
+                }
+            }
+            MatchPattern::Dict {
+                entries,
+                rest,
+                pos: _,
+            } => {
+                for (_, value) in entries {
+                    value.collect_bindings(bindings);
+                }
+                if let Some(rest) = rest {
+                    rest.collect_bindings(bindings);
+                }
+            }
+            MatchPattern::Alternatives {
+                alternatives,
+                pos: _,
+            } => {
+                if let Some(first) = alternatives.first() {
+                    first.collect_bindings(bindings);
+                }
+            }
+        }
+    }
+
+    /// Returns the position in the source code of this pattern
+    pub fn pos(&self) -> &SourceRange<'source> {
+        match self {
+            MatchPattern::Wildcard { pos } => pos,
+            MatchPattern::Binding { ident: _, pos } => pos,
+            MatchPattern::Value(value) => value.pos(),
+            MatchPattern::Range(range) => range.pos(),
+            MatchPattern::Type {
+                icelang_type: _,
+                inner: _,
+                pos,
+            } => pos,
+            MatchPattern::List {
+                elements: _,
+                rest: _,
+                pos,
+            } => pos,
+            MatchPattern::Dict {
+                entries: _,
+                rest: _,
+                pos,
+            } => pos,
+            MatchPattern::Alternatives {
+                alternatives: _,
+                pos,
+            } => pos,
+        }
+    }
+}
+
+impl Display for MatchPattern<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                MatchPattern::Wildcard { pos: _ } => format_as_node("[Wildcard Pattern]", vec![]),
+                MatchPattern::Binding { ident, pos: _ } => {
+                    format_as_node(&format!("[Binding Pattern] {ident}"), vec![])
+                }
+                MatchPattern::Value(value) => value.to_string(),
+                MatchPattern::Range(range) =>
+                    format_as_node("[Range Pattern]", vec![range.to_string()]),
+                MatchPattern::Type {
+                    icelang_type,
+                    inner,
+                    pos: _,
+                } => format_as_node(
+                    &format!("[Type Pattern] {icelang_type}"),
+                    inner.iter().map(|inner| inner.to_string()).collect(),
+                ),
+                MatchPattern::List {
+                    elements,
+                    rest,
+                    pos: _,
+                } => {
+                    let mut children: Vec<String> =
+                        elements.iter().map(|element| element.to_string()).collect();
+                    if let Some((index, rest)) = rest {
+                        children.insert(*index, format_as_node("Rest", vec![rest.to_string()]));
+                    }
+
+                    format_as_node("[List Pattern]", children)
+                }
+                MatchPattern::Dict {
+                    entries,
+                    rest,
+                    pos: _,
+                } => {
+                    let mut children: Vec<String> = entries
+                        .iter()
+                        .map(|(key, value)| {
+                            format_as_node(
+                                "Entry",
+                                vec![
+                                    format_as_node("Key", vec![key.to_string()]),
+                                    format_as_node("Value", vec![value.to_string()]),
+                                ],
+                            )
+                        })
+                        .collect();
+                    if let Some(rest) = rest {
+                        children.push(format_as_node("Rest", vec![rest.to_string()]));
+                    }
+
+                    format_as_node("[Dict Pattern]", children)
+                }
+                MatchPattern::Alternatives {
+                    alternatives,
+                    pos: _,
+                } => format_as_node(
+                    "[Alternatives Pattern]",
+                    alternatives
+                        .iter()
+                        .map(|alternative| alternative.to_string())
+                        .collect(),
+                ),
+            }
+        )
+    }
+}
+
+impl PartialEq for MatchPattern<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Wildcard { pos: _ }, Self::Wildcard { pos: _ }) => true,
+            (
+                Self::Binding {
+                    ident: l_ident,
+                    pos: _,
+                },
+                Self::Binding {
+                    ident: r_ident,
+                    pos: _,
+                },
+            ) => l_ident == r_ident,
+            (Self::Value(l_value), Self::Value(r_value)) => l_value == r_value,
+            (Self::Range(l_range), Self::Range(r_range)) => l_range == r_range,
+            (
+                Self::Type {
+                    icelang_type: l_icelang_type,
+                    inner: l_inner,
+                    pos: _,
+                },
+                Self::Type {
+                    icelang_type: r_icelang_type,
+                    inner: r_inner,
+                    pos: _,
+                },
+            ) => l_icelang_type == r_icelang_type && l_inner == r_inner,
+            (
+                Self::List {
+                    elements: l_elements,
+                    rest: l_rest,
+                    pos: _,
+                },
+                Self::List {
+                    elements: r_elements,
+                    rest: r_rest,
+                    pos: _,
+                },
+            ) => l_elements == r_elements && l_rest == r_rest,
+            (
+                Self::Dict {
+                    entries: l_entries,
+                    rest: l_rest,
+                    pos: _,
+                },
+                Self::Dict {
+                    entries: r_entries,
+                    rest: r_rest,
+                    pos: _,
+                },
+            ) => l_entries == r_entries && l_rest == r_rest,
+            (
+                Self::Alternatives {
+                    alternatives: l_alternatives,
+                    pos: _,
+                },
+                Self::Alternatives {
+                    alternatives: r_alternatives,
+                    pos: _,
+                },
+            ) => l_alternatives == r_alternatives,
+            _ => false,
+        }
+    }
+}
+impl Eq for MatchPattern<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigInt;
+
+    use crate::value::Value;
+
+    use super::*;
+
+    #[test]
+    fn test_match_pattern_display() {
+        let nowhere = SourceRange::new(" ", "", 0, 0);
+        let pattern = MatchPattern::Alternatives {
+            alternatives: vec![
+                MatchPattern::List {
+                    elements: vec![MatchPattern::Binding {
+                        ident: "first".to_string(),
+                        pos: nowhere.clone(),
+                    }],
+                    rest: Some((
+                        1,
+                        Box::new(MatchPattern::Wildcard {
+                            pos: nowhere.clone(),
+                        }),
+                    )),
+                    pos: nowhere.clone(),
+                },
+                MatchPattern::Type {
+                    icelang_type: IcelangType::Int,
+                    inner: Some(Box::new(MatchPattern::Binding {
+                        ident: "first".to_string(),
+                        pos: nowhere.clone(),
+                    })),
+                    pos: nowhere.clone(),
+                },
+            ],
+            pos: nowhere.clone(),
+        };
+
+        assert_eq!(
+            pattern.to_string(),
+            "\
+● [Alternatives Pattern]
+├─● [List Pattern]
+│ ├─● [Binding Pattern] first
+│ └─● Rest
+│   └─● [Wildcard Pattern]
+└─● [Type Pattern] int
+  └─● [Binding Pattern] first"
+        );
+    }
+
+    #[test]
+    fn test_range_pattern_display() {
+        let nowhere = SourceRange::new(" ", "", 0, 0);
+        let int_literal = |value: i32| -> AstNode {
+            AstNodeLiteral::new(
+                value.to_string(),
+                IcelangType::Int,
+                Value::Int(BigInt::from(value)),
+                nowhere.clone(),
+            )
+            .into()
+        };
+        let pattern = MatchPattern::Range(AstNodeRangeLiteral::new(
+            int_literal(0),
+            int_literal(10),
+            true,
+        ));
+
+        assert_eq!(
+            pattern.to_string(),
+            "\
+● [Range Pattern]
+└─● [Literal] (range, inclusive)
+  ├─● Start
+  │ └─● [Literal] (int) 0
+  └─● End
+    └─● [Literal] (int) 10"
+        );
+    }
+}
@@ -1,10 +1,13 @@
 //! Contains code related to AST nodes
 
 mod assignment_kind;
+mod ast_node_anonymous_function;
 mod ast_node_assignment;
 mod ast_node_binary_operation;
 mod ast_node_comparison;
 mod ast_node_computed_member_access;
+mod ast_node_destructuring_assignment;
+mod ast_node_dict_comprehension;
 mod ast_node_dict_literal;
 mod ast_node_dot_member_access;
 mod ast_node_for_loop;
@@ -13,52 +16,83 @@ mod ast_node_formatted_string_literal;
 mod ast_node_function_call;
 mod ast_node_function_declaration;
 mod ast_node_if_else_statement;
+mod ast_node_import;
 mod ast_node_inline_conditional;
 mod ast_node_jump_statement;
+mod ast_node_list_comprehension;
 mod ast_node_list_literal;
 mod ast_node_literal;
 mod ast_node_match_statement;
+mod ast_node_range_literal;
+mod ast_node_record_declaration;
+mod ast_node_record_literal;
+mod ast_node_set_literal;
 mod ast_node_simple_loop;
+mod ast_node_slice;
+mod ast_node_spread;
+mod ast_node_throw;
+mod ast_node_try_catch;
 mod ast_node_type_cast;
 mod ast_node_unary_operation;
 mod ast_node_variable_access;
 mod ast_node_variable_declaration;
 mod ast_node_while_loop;
+mod ast_node_yield;
 mod ast_struct;
 mod binary_operation_kind;
 mod comparison_kind;
+mod comprehension_clause;
+mod destructuring_pattern;
 mod generic_ast_node;
 mod jump_statement_kind;
 mod match_arm;
+mod match_pattern;
 mod unary_operation_kind;
 
 pub use assignment_kind::AssignmentKind;
+pub use ast_node_anonymous_function::AstNodeAnonymousFunction;
 pub use ast_node_assignment::AstNodeAssignment;
 pub use ast_node_binary_operation::AstNodeBinaryOperation;
 pub use ast_node_comparison::AstNodeComparison;
 pub use ast_node_computed_member_access::AstNodeComputedMemberAccess;
-pub use ast_node_dict_literal::AstNodeDictLiteral;
+pub use ast_node_destructuring_assignment::AstNodeDestructuringAssignment;
+pub use ast_node_dict_comprehension::AstNodeDictComprehension;
+pub use ast_node_dict_literal::{AstNodeDictLiteral, DictLiteralEntry};
 pub use ast_node_dot_member_access::AstNodeDotMemberAccess;
 pub use ast_node_for_loop::AstNodeForLoop;
 pub use ast_node_formatted_string_literal::AstNodeFormattedStringLiteral;
 pub use ast_node_function_call::AstNodeFunctionCall;
 pub use ast_node_function_declaration::AstNodeFunctionDeclaration;
 pub use ast_node_if_else_statement::AstNodeIfElseStatement;
+pub use ast_node_import::AstNodeImport;
 pub use ast_node_inline_conditional::AstNodeInlineConditional;
 pub use ast_node_jump_statement::AstNodeJumpStatement;
+pub use ast_node_list_comprehension::AstNodeListComprehension;
 pub use ast_node_list_literal::AstNodeListLiteral;
 pub use ast_node_literal::AstNodeLiteral;
 pub use ast_node_match_statement::AstNodeMatchStatement;
+pub use ast_node_range_literal::AstNodeRangeLiteral;
+pub use ast_node_record_declaration::AstNodeRecordDeclaration;
+pub use ast_node_record_literal::AstNodeRecordLiteral;
+pub use ast_node_set_literal::AstNodeSetLiteral;
 pub use ast_node_simple_loop::AstNodeSimpleLoop;
+pub use ast_node_slice::AstNodeSlice;
+pub use ast_node_spread::AstNodeSpread;
+pub use ast_node_throw::AstNodeThrow;
+pub use ast_node_try_catch::AstNodeTryCatch;
 pub use ast_node_type_cast::AstNodeTypeCast;
 pub use ast_node_unary_operation::AstNodeUnaryOperation;
 pub use ast_node_variable_access::AstNodeVariableAccess;
 pub use ast_node_variable_declaration::AstNodeVariableDeclaration;
 pub use ast_node_while_loop::AstNodeWhileLoop;
+pub use ast_node_yield::AstNodeYield;
 pub use ast_struct::Ast;
 pub use binary_operation_kind::BinaryOperationKind;
 pub use comparison_kind::ComparisonKind;
+pub use comprehension_clause::ComprehensionClause;
+pub use destructuring_pattern::DestructuringPattern;
 pub use generic_ast_node::AstNode;
 pub use jump_statement_kind::JumpStatementKind;
 pub use match_arm::MatchArm;
+pub use match_pattern::MatchPattern;
 pub use unary_operation_kind::UnaryOperationKind;
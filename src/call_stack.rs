@@ -1,3 +1,5 @@
+use std::{cell::RefCell, rc::Rc};
+
 use crate::{
     ast::AstNode,
     function::{FunctionGroup, FunctionParameters},
@@ -6,6 +8,21 @@ use crate::{
     value::Value,
 };
 
+/// A single scope's symbol table. Scopes are shared so that functions declared
+/// inside of them can capture them, and keep them alive after they are popped
+pub type Scope<'source> = Rc<RefCell<SymbolTable<'source>>>;
+
+/// The scopes captured by a function when it was declared, ordered from the
+/// outermost scope to the innermost scope
+pub type Environment<'source> = Vec<Scope<'source>>;
+
+/// A position in a call stack, which the call stack can later be unwound to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CallStackDepth {
+    stack_frames: usize,
+    scopes: usize,
+}
+
 /// An icelang callstack
 #[derive(Debug, Clone)]
 pub struct CallStack<'source> {
@@ -17,23 +34,20 @@ impl<'source> CallStack<'source> {
     /// Constructs a new CallStack
     pub fn new(base_frame_display_name: String) -> Self {
         Self {
-            base_frame: StackFrame::new(base_frame_display_name),
+            base_frame: StackFrame::new(base_frame_display_name, Vec::new()),
             stack: Vec::new(),
         }
     }
 
     /// Returns the display name of the current scope
     pub fn scope_display_name(&self) -> &str {
-        if !self.stack.is_empty() {
-            self.stack[self.stack.len() - 1].display_name()
-        } else {
-            self.base_frame.display_name()
-        }
+        self.current_frame().display_name()
     }
 
-    /// Pushes a new stack frame to the call stack
-    pub fn push_stack_frame(&mut self, display_name: String) {
-        self.stack.push(StackFrame::new(display_name));
+    /// Pushes a new stack frame to the call stack. Variables and functions in
+    /// the given environment will be visible from within the new stack frame
+    pub fn push_stack_frame(&mut self, display_name: String, environment: Environment<'source>) {
+        self.stack.push(StackFrame::new(display_name, environment));
     }
 
     /// Pops a stack frame from the call stack
@@ -46,14 +60,30 @@ impl<'source> CallStack<'source> {
         self.stack.pop().unwrap();
     }
 
+    /// Pushes an existing stack frame (such as one returned by
+    /// `take_stack_frame`) to the call stack
+    pub fn push_existing_stack_frame(&mut self, frame: StackFrame<'source>) {
+        self.stack.push(frame);
+    }
+
+    /// Pops a stack frame from the call stack, and returns it
+    ///
+    /// # Panics
+    /// - If the call stack is empty
+    pub fn take_stack_frame(&mut self) -> StackFrame<'source> {
+        self.stack
+            .pop()
+            .expect("the call stack should not be empty")
+    }
+
     /// Pushes a new scope to the stack frame
     pub fn push_scope(&mut self) {
-        if self.stack.is_empty() {
-            self.base_frame.push_scope();
-        } else {
-            let last_index = self.stack.len() - 1;
-            self.stack[last_index].push_scope();
-        }
+        self.current_frame_mut().push_scope();
+    }
+
+    /// Pushes an existing scope to the stack frame
+    pub fn push_existing_scope(&mut self, scope: Scope<'source>) {
+        self.current_frame_mut().scopes.push(scope);
     }
 
     /// Pops a scope from the stack frame
@@ -61,101 +91,146 @@ impl<'source> CallStack<'source> {
     /// # Panics
     /// - If the stack frame's scope list is empty
     pub fn pop_scope(&mut self) {
-        if self.stack.is_empty() {
-            self.base_frame.pop_scope();
-        } else {
-            let last_index = self.stack.len() - 1;
-            self.stack[last_index].pop_scope();
-        }
+        self.current_frame_mut().pop_scope();
     }
 
-    /// Looks up a variable in the call stack
-    pub fn lookup_variable(&self, identifier: &str) -> Option<&Value> {
-        if !self.stack.is_empty() {
-            if let Some(value) = self.stack[self.stack.len() - 1].lookup_variable(identifier) {
-                return Some(value);
-            }
+    /// Returns the current depth of the call stack
+    pub fn depth(&self) -> CallStackDepth {
+        CallStackDepth {
+            stack_frames: self.stack.len(),
+            scopes: self.current_frame().scopes.len(),
         }
+    }
 
-        self.base_frame.lookup_variable(identifier)
+    /// Unwinds the call stack to a previous depth, popping every stack frame
+    /// and scope pushed since then
+    ///
+    /// # Panics
+    /// - If the call stack is already shallower than the given depth
+    pub fn unwind_to(&mut self, depth: CallStackDepth) {
+        assert!(self.stack.len() >= depth.stack_frames);
+        self.stack.truncate(depth.stack_frames);
+
+        let scopes = &mut self.current_frame_mut().scopes;
+        assert!(scopes.len() >= depth.scopes);
+        scopes.truncate(depth.scopes);
     }
 
-    /// Looks up a variable (mutably) in the call stack
-    pub fn lookup_variable_mut(&mut self, identifier: &str) -> Option<&mut Value> {
-        if !self.stack.is_empty() {
-            let last_index = self.stack.len() - 1;
-            if let Some(value) = self.stack[last_index].lookup_variable_mut(identifier) {
-                return Some(value);
-            }
-        }
+    /// Captures the environment of the current scope, for use by a function
+    /// declared in the current scope
+    pub fn capture_environment(&self) -> Environment<'source> {
+        self.current_frame().capture_environment()
+    }
 
-        self.base_frame.lookup_variable_mut(identifier)
+    /// Returns the innermost scope of the current stack frame
+    pub fn innermost_scope(&self) -> &Scope<'source> {
+        self.current_frame().innermost_scope()
+    }
+
+    /// Returns the global scope of the call stack
+    pub fn global_scope(&self) -> &Scope<'source> {
+        &self.base_frame.local
+    }
+
+    /// Looks up a variable in the call stack
+    pub fn lookup_variable(&self, identifier: &str) -> Option<Value<'source>> {
+        self.visible_scopes().find_map(|scope| {
+            scope
+                .borrow()
+                .lookup_variable(identifier)
+                .map(Value::reference_copy)
+        })
+    }
+
+    /// Looks up a variable in the call stack, unless a function with the same
+    /// identifier is declared in a more local scope (in which case the
+    /// function shadows the variable)
+    pub fn lookup_unshadowed_variable(&self, identifier: &str) -> Option<Value<'source>> {
+        self.visible_scopes()
+            .find_map(|scope| {
+                let scope = scope.borrow();
+                match scope.lookup_variable(identifier) {
+                    Some(value) => Some(Some(value.reference_copy())),
+                    None => scope.lookup_function(identifier).map(|_| None),
+                }
+            })
+            .flatten()
     }
 
     /// Looks up a variable in the call stack, only checking the most local
     /// scope
-    pub fn lookup_local_variable(&self, identifier: &str) -> Option<&Value> {
-        if !self.stack.is_empty() {
-            self.stack[self.stack.len() - 1].lookup_local_variable(identifier)
-        } else {
-            self.base_frame.lookup_local_variable(identifier)
-        }
+    pub fn lookup_local_variable(&self, identifier: &str) -> Option<Value<'source>> {
+        self.current_frame()
+            .innermost_scope()
+            .borrow()
+            .lookup_variable(identifier)
+            .map(Value::reference_copy)
     }
 
     /// Looks up a function in the call stack
-    pub fn lookup_function(&self, identifier: &str) -> Option<&FunctionGroup<'source>> {
-        if !self.stack.is_empty() {
-            if let Some(value) = self.stack[self.stack.len() - 1].lookup_function(identifier) {
-                return Some(value);
-            }
-        }
-
-        self.base_frame.lookup_function(identifier)
+    pub fn lookup_function(&self, identifier: &str) -> Option<Rc<FunctionGroup<'source>>> {
+        self.visible_scopes()
+            .find_map(|scope| scope.borrow().lookup_function(identifier).cloned())
     }
 
     /// Looks up a function in the call stack, only checking the most local
     /// scope
-    pub fn lookup_local_function(&self, identifier: &str) -> Option<&FunctionGroup<'source>> {
-        if !self.stack.is_empty() {
-            self.stack[self.stack.len() - 1].lookup_local_function(identifier)
-        } else {
-            self.base_frame.lookup_local_function(identifier)
-        }
+    pub fn lookup_local_function(&self, identifier: &str) -> Option<Rc<FunctionGroup<'source>>> {
+        self.current_frame()
+            .innermost_scope()
+            .borrow()
+            .lookup_function(identifier)
+            .cloned()
     }
 
     /// Assigns a new value to an already existing variable
     ///
     /// # Panics
     /// - If the variable isn't already defined
-    pub fn reassign_variable(&mut self, identifier: &str, value: Value) {
-        if !self.stack.is_empty() {
-            let last_index = self.stack.len() - 1;
-            if self.stack[last_index].lookup_variable(identifier).is_some() {
-                self.stack[last_index].reassign_variable(identifier, value);
-                return;
-            }
-        }
+    pub fn reassign_variable(&mut self, identifier: &str, value: Value<'source>) {
+        let scope = self
+            .visible_scopes()
+            .find(|scope| scope.borrow().lookup_variable(identifier).is_some())
+            .expect("variable should be defined");
 
-        self.base_frame.reassign_variable(identifier, value);
+        scope.borrow_mut().reassign_variable(identifier, value);
     }
 
     /// Declares a new variable and assigns the given value
     ///
     /// # Panics
     /// - If the variable is already defined
-    pub fn declare_variable(&mut self, identifier: String, value: Value) {
-        if self.stack.is_empty() {
-            self.base_frame.declare_variable(identifier, value);
-        } else {
-            let last_index = self.stack.len() - 1;
-            self.stack[last_index].declare_variable(identifier, value);
-        }
+    pub fn declare_variable(&mut self, identifier: String, value: Value<'source>) {
+        self.current_frame()
+            .innermost_scope()
+            .borrow_mut()
+            .declare_variable(identifier, value);
+    }
+
+    /// Declares a new constant (a variable which can't be reassigned) and
+    /// assigns the given value
+    ///
+    /// # Panics
+    /// - If the variable is already defined
+    pub fn declare_constant(&mut self, identifier: String, value: Value<'source>) {
+        self.current_frame()
+            .innermost_scope()
+            .borrow_mut()
+            .declare_constant(identifier, value);
+    }
+
+    /// Returns whether or not the variable with the given identifier refers
+    /// to a constant
+    pub fn is_constant(&self, identifier: &str) -> bool {
+        self.visible_scopes()
+            .find(|scope| scope.borrow().lookup_variable(identifier).is_some())
+            .is_some_and(|scope| scope.borrow().is_constant(identifier))
     }
 
     /// Declares a function (or overloads a function)
     ///
     /// # Panics
-    /// - If the function is already defined (including arity)
+    /// - If the function is already defined with a conflicting overload
     pub fn declare_function(
         &mut self,
         identifier: String,
@@ -163,30 +238,51 @@ impl<'source> CallStack<'source> {
         body: Vec<AstNode<'source>>,
         pos: SourceRange<'source>,
     ) {
-        if self.stack.is_empty() {
-            self.base_frame
-                .declare_function(identifier, parameters, body, pos);
-        } else {
-            let last_index = self.stack.len() - 1;
-            self.stack[last_index].declare_function(identifier, parameters, body, pos);
-        }
+        let environment = self.capture_environment();
+
+        self.current_frame()
+            .innermost_scope()
+            .borrow_mut()
+            .declare_function(identifier, parameters, body, environment, pos);
+    }
+
+    /// Returns the current (top-most) stack frame
+    fn current_frame(&self) -> &StackFrame<'source> {
+        self.stack.last().unwrap_or(&self.base_frame)
+    }
+
+    /// Returns the current (top-most) stack frame mutably
+    fn current_frame_mut(&mut self) -> &mut StackFrame<'source> {
+        self.stack.last_mut().unwrap_or(&mut self.base_frame)
+    }
+
+    /// Returns an iterator over every scope visible from the current stack
+    /// frame, from the innermost scope to the outermost (global) scope
+    ///
+    /// The global scope is only visible from within a function through the
+    /// function's captured environment, so that functions imported from
+    /// another module see that module's global scope rather than the caller's
+    fn visible_scopes(&self) -> impl Iterator<Item = &Scope<'source>> {
+        self.current_frame().visible_scopes()
     }
 }
 
 /// An icelang stack frame
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct StackFrame<'source> {
     display_name: String,
-    local: SymbolTable<'source>,
-    scopes: Vec<SymbolTable<'source>>,
+    environment: Environment<'source>,
+    local: Scope<'source>,
+    scopes: Vec<Scope<'source>>,
 }
 
 impl<'source> StackFrame<'source> {
     /// Constructs a new StackFrame
-    pub fn new(display_name: String) -> Self {
+    pub fn new(display_name: String, environment: Environment<'source>) -> Self {
         Self {
             display_name,
-            local: SymbolTable::new(),
+            environment,
+            local: Rc::new(RefCell::new(SymbolTable::new())),
             scopes: Vec::new(),
         }
     }
@@ -198,7 +294,7 @@ impl<'source> StackFrame<'source> {
 
     /// Pushes a new scope to the stack frame
     pub fn push_scope(&mut self) {
-        self.scopes.push(SymbolTable::new());
+        self.scopes.push(Rc::new(RefCell::new(SymbolTable::new())));
     }
 
     /// Pops a scope from the stack frame
@@ -211,106 +307,43 @@ impl<'source> StackFrame<'source> {
         self.scopes.pop().unwrap();
     }
 
-    /// Looks up a variable in the stack frame
-    pub fn lookup_variable(&self, identifier: &str) -> Option<&Value> {
-        for scope in self.scopes.iter().rev() {
-            if let Some(value) = scope.lookup_variable(identifier) {
-                return Some(value);
-            }
-        }
-
-        self.local.lookup_variable(identifier)
+    /// Captures every scope visible from within the stack frame
+    pub fn capture_environment(&self) -> Environment<'source> {
+        self.environment
+            .iter()
+            .chain(std::iter::once(&self.local))
+            .chain(self.scopes.iter())
+            .cloned()
+            .collect()
     }
 
-    /// Looks up a variable (mutably) in the stack frame
-    pub fn lookup_variable_mut(&mut self, identifier: &str) -> Option<&mut Value> {
-        for scope in self.scopes.iter_mut().rev() {
-            if let Some(value) = scope.lookup_variable_mut(identifier) {
-                return Some(value);
-            }
-        }
-
-        self.local.lookup_variable_mut(identifier)
+    /// Returns the innermost scope of the stack frame
+    fn innermost_scope(&self) -> &Scope<'source> {
+        self.scopes.last().unwrap_or(&self.local)
     }
 
-    /// Looks up a variable in the stack frame, only checking the most local
-    /// scope
-    pub fn lookup_local_variable(&self, identifier: &str) -> Option<&Value> {
-        if self.scopes.is_empty() {
-            self.local.lookup_variable(identifier)
-        } else {
-            let last_index = self.scopes.len() - 1;
-            self.scopes[last_index].lookup_variable(identifier)
-        }
-    }
-
-    /// Looks up a function in the stack frame
-    pub fn lookup_function(&self, identifier: &str) -> Option<&FunctionGroup<'source>> {
-        for scope in self.scopes.iter().rev() {
-            if let Some(value) = scope.lookup_function(identifier) {
-                return Some(value);
-            }
-        }
-
-        self.local.lookup_function(identifier)
-    }
-
-    /// Looks up a function in the stack frame, only checking the most local
-    /// scope
-    pub fn lookup_local_function(&self, identifier: &str) -> Option<&FunctionGroup<'source>> {
-        if self.scopes.is_empty() {
-            self.local.lookup_function(identifier)
-        } else {
-            let last_index = self.scopes.len() - 1;
-            self.scopes[last_index].lookup_function(identifier)
-        }
-    }
-
-    /// Assigns a new value to an already existing variable
-    ///
-    /// # Panics
-    /// - If the variable isn't already defined
-    pub fn reassign_variable(&mut self, identifier: &str, value: Value) {
-        for scope in self.scopes.iter_mut().rev() {
-            if scope.lookup_function(identifier).is_some() {
-                scope.reassign_variable(identifier, value);
-                return;
-            }
-        }
-
-        self.local.reassign_variable(identifier, value)
+    /// Returns an iterator over every scope visible from within the stack
+    /// frame, from the innermost scope to the outermost scope
+    fn visible_scopes(&self) -> impl Iterator<Item = &Scope<'source>> {
+        self.scopes
+            .iter()
+            .rev()
+            .chain(std::iter::once(&self.local))
+            .chain(self.environment.iter().rev())
     }
+}
 
-    /// Declares a new variable and assigns the given value
-    ///
-    /// # Panics
-    /// - If the variable is already defined
-    pub fn declare_variable(&mut self, identifier: String, value: Value) {
-        if self.scopes.is_empty() {
-            self.local.declare_variable(identifier, value);
-        } else {
-            let last_index = self.scopes.len() - 1;
-            self.scopes[last_index].declare_variable(identifier, value);
-        }
-    }
+impl<'source> Clone for StackFrame<'source> {
+    fn clone(&self) -> Self {
+        // The symbol tables owned by this stack frame are copied, but the
+        // captured environment is still shared
+        let copy_scope = |scope: &Scope<'source>| Rc::new(RefCell::new(scope.borrow().clone()));
 
-    /// Declares a function (or overloads a function)
-    ///
-    /// # Panics
-    /// - If the function is already defined (including arity)
-    pub fn declare_function(
-        &mut self,
-        identifier: String,
-        parameters: FunctionParameters<'source>,
-        body: Vec<AstNode<'source>>,
-        pos: SourceRange<'source>,
-    ) {
-        if self.scopes.is_empty() {
-            self.local
-                .declare_function(identifier, parameters, body, pos);
-        } else {
-            let last_index = self.scopes.len() - 1;
-            self.scopes[last_index].declare_function(identifier, parameters, body, pos);
+        Self {
+            display_name: self.display_name.clone(),
+            environment: self.environment.clone(),
+            local: copy_scope(&self.local),
+            scopes: self.scopes.iter().map(copy_scope).collect(),
         }
     }
 }
@@ -44,6 +44,14 @@ impl<'source> StackTrace<'source> {
     pub fn add_bottom(&mut self, scope_display_name: String, source_range: SourceRange<'source>) {
         self.sources.push_back((scope_display_name, source_range));
     }
+
+    /// Returns an iterator over the stack frames of the StackTrace, from the
+    /// top (most recent call) to the bottom
+    pub fn frames(&self) -> impl Iterator<Item = (&str, &SourceRange<'source>)> {
+        self.sources
+            .iter()
+            .map(|(scope_display_name, source_range)| (scope_display_name.as_str(), source_range))
+    }
 }
 
 impl Default for StackTrace<'_> {
@@ -68,11 +68,10 @@ impl<'source> LexerError<'source> {
             Self::UnexpectedEOF { why: _, pos } => pos,
         }
     }
-}
 
-impl Display for LexerError<'_> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let description = match self {
+    /// Returns a description of the error, without any source code context
+    pub fn description(&self) -> String {
+        match self {
             LexerError::IllegalChar {
                 character: c,
                 pos: _,
@@ -88,9 +87,19 @@ impl Display for LexerError<'_> {
             LexerError::InvalidEscapeSequence { pos: _ } => {
                 "invalid escape sequence in string literal".to_string()
             }
-        };
+        }
+    }
+}
 
-        error_formatting::write_error(f, IcelangErrorKind::Syntax, &description, self.pos(), None)
+impl Display for LexerError<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_formatting::write_error(
+            f,
+            IcelangErrorKind::Syntax,
+            &self.description(),
+            self.pos(),
+            None,
+        )
     }
 }
 
@@ -22,6 +22,12 @@ pub enum ParseError<'source> {
         /// before the EOF of the AstNode that was being built)
         pos: SourceRange<'source>,
     },
+    /// A match arm which can never run was encountered (a previous arm matches
+    /// every value)
+    UnreachableMatchArm {
+        /// The position of the unreachable match arm
+        pos: SourceRange<'source>,
+    },
 }
 
 impl<'source> ParseError<'source> {
@@ -35,25 +41,43 @@ impl<'source> ParseError<'source> {
         Self::UnexpectedEOF { why, pos }
     }
 
+    /// Constructs a new UnreachableMatchArm ParseError
+    pub fn new_unreachable_match_arm(pos: SourceRange<'source>) -> Self {
+        Self::UnreachableMatchArm { pos }
+    }
+
     /// Returns the SourceRange corresponding to this error
     pub fn pos(&self) -> &SourceRange<'source> {
         match self {
             Self::UnexpectedToken { why: _, pos } => pos,
             Self::UnexpectedEOF { why: _, pos } => pos,
+            Self::UnreachableMatchArm { pos } => pos,
         }
     }
-}
 
-impl Display for ParseError<'_> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let description = match self {
+    /// Returns a description of the error, without any source code context
+    pub fn description(&self) -> String {
+        match self {
             ParseError::UnexpectedToken { why, pos: _ } => format!("unexpected token ({why})"),
             ParseError::UnexpectedEOF { why, pos: _ } => {
                 format!("unexpected end-of-file ({why})")
             }
-        };
+            ParseError::UnreachableMatchArm { pos: _ } => {
+                "unreachable match arm (a previous arm matches every value)".to_string()
+            }
+        }
+    }
+}
 
-        error_formatting::write_error(f, IcelangErrorKind::Syntax, &description, self.pos(), None)
+impl Display for ParseError<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        error_formatting::write_error(
+            f,
+            IcelangErrorKind::Syntax,
+            &self.description(),
+            self.pos(),
+            None,
+        )
     }
 }
 
@@ -98,4 +122,14 @@ mod tests {
             assert!(parse_error.to_string().contains(reason));
         }
     }
+
+    #[test]
+    fn test_unreachable_match_arm_display() {
+        let nowhere = SourceRange::new(" ", "", 0, 0);
+
+        let parse_error = ParseError::new_unreachable_match_arm(nowhere);
+
+        assert!(parse_error.to_string().contains("unreachable match arm"));
+        assert!(!parse_error.to_string().contains("unexpected token"));
+    }
 }
@@ -71,7 +71,7 @@ pub enum RuntimeError<'source> {
         why: String,
     },
 
-    /// A function was called with an invalid number of arguments
+    /// A function was called with arguments which none of its overloads accept
     InvalidOverload {
         /// The position of the error
         pos: SourceRange<'source>,
@@ -80,10 +80,14 @@ pub enum RuntimeError<'source> {
         stack_trace: StackTrace<'source>,
 
         /// The name of the function
-        function_name: String,
+        function_name: Box<str>,
 
         /// The invalid number of arguments provided
         argument_count: usize,
+
+        /// An explanation of why no overload accepted the arguments (such as
+        /// which signature failed to match), if any
+        why: Option<Box<str>>,
     },
 
     /// Attempted to call something which is not a function
@@ -133,6 +137,66 @@ pub enum RuntimeError<'source> {
         /// An explanation of what went wrong
         why: String,
     },
+
+    /// A module could not be imported
+    Import {
+        /// The position of the error
+        pos: SourceRange<'source>,
+
+        /// The stack trace for the error
+        stack_trace: StackTrace<'source>,
+
+        /// An explanation of why the module couldn't be imported
+        why: String,
+    },
+
+    /// A thrown value was never caught
+    UncaughtThrow {
+        /// The position of the error
+        pos: SourceRange<'source>,
+
+        /// The stack trace for the error
+        stack_trace: StackTrace<'source>,
+
+        /// The thrown value, formatted for display
+        value: String,
+    },
+
+    /// A value couldn't be destructured by a pattern
+    Destructuring {
+        /// The position of the error
+        pos: SourceRange<'source>,
+
+        /// The stack trace for the error
+        stack_trace: StackTrace<'source>,
+
+        /// An explanation of why the value didn't fit the pattern
+        why: String,
+    },
+
+    /// Attempted to reassign a constant, or to modify a frozen value
+    Immutability {
+        /// The position of the error
+        pos: SourceRange<'source>,
+
+        /// The stack trace for the error
+        stack_trace: StackTrace<'source>,
+
+        /// An explanation of what went wrong
+        why: String,
+    },
+
+    /// A generator was resumed while it was already running, or after it had finished
+    Generator {
+        /// The position of the error
+        pos: SourceRange<'source>,
+
+        /// The stack trace for the error
+        stack_trace: StackTrace<'source>,
+
+        /// An explanation of what went wrong
+        why: String,
+    },
 }
 
 impl<'source> RuntimeError<'source> {
@@ -223,8 +287,29 @@ impl<'source> RuntimeError<'source> {
         Self::InvalidOverload {
             pos,
             stack_trace,
-            function_name,
+            function_name: function_name.into_boxed_str(),
             argument_count,
+            why: None,
+        }
+    }
+
+    /// Constructs a new InvalidOverload RuntimeError with an explanation of
+    /// why no overload accepted the arguments
+    pub fn new_invalid_overload_error_with_reason(
+        pos: SourceRange<'source>,
+        scope_display_name: String,
+        function_name: String,
+        argument_count: usize,
+        why: String,
+    ) -> Self {
+        let mut stack_trace = StackTrace::new();
+        stack_trace.add_bottom(scope_display_name, pos.clone());
+        Self::InvalidOverload {
+            pos,
+            stack_trace,
+            function_name: function_name.into_boxed_str(),
+            argument_count,
+            why: Some(why.into_boxed_str()),
         }
     }
 
@@ -285,6 +370,66 @@ impl<'source> RuntimeError<'source> {
         }
     }
 
+    /// Constructs a new Import RuntimeError
+    pub fn new_import_error(
+        pos: SourceRange<'source>,
+        scope_display_name: String,
+        why: String,
+    ) -> Self {
+        let mut stack_trace = StackTrace::new();
+        stack_trace.add_bottom(scope_display_name, pos.clone());
+        Self::Import {
+            pos,
+            stack_trace,
+            why,
+        }
+    }
+
+    /// Constructs a new Destructuring RuntimeError
+    pub fn new_destructuring_error(
+        pos: SourceRange<'source>,
+        scope_display_name: String,
+        why: String,
+    ) -> Self {
+        let mut stack_trace = StackTrace::new();
+        stack_trace.add_bottom(scope_display_name, pos.clone());
+        Self::Destructuring {
+            pos,
+            stack_trace,
+            why,
+        }
+    }
+
+    /// Constructs a new Immutability RuntimeError
+    pub fn new_immutability_error(
+        pos: SourceRange<'source>,
+        scope_display_name: String,
+        why: String,
+    ) -> Self {
+        let mut stack_trace = StackTrace::new();
+        stack_trace.add_bottom(scope_display_name, pos.clone());
+        Self::Immutability {
+            pos,
+            stack_trace,
+            why,
+        }
+    }
+
+    /// Constructs a new Generator RuntimeError
+    pub fn new_generator_error(
+        pos: SourceRange<'source>,
+        scope_display_name: String,
+        why: String,
+    ) -> Self {
+        let mut stack_trace = StackTrace::new();
+        stack_trace.add_bottom(scope_display_name, pos.clone());
+        Self::Generator {
+            pos,
+            stack_trace,
+            why,
+        }
+    }
+
     /// Returns the StackTrace corresponding to this error
     pub fn stack_trace(&self) -> &StackTrace<'source> {
         match self {
@@ -318,6 +463,7 @@ impl<'source> RuntimeError<'source> {
                 stack_trace,
                 function_name: _,
                 argument_count: _,
+                why: _,
             } => stack_trace,
             Self::CalledNonFunction {
                 pos: _,
@@ -339,6 +485,31 @@ impl<'source> RuntimeError<'source> {
                 stack_trace,
                 why: _,
             } => stack_trace,
+            Self::Import {
+                pos: _,
+                stack_trace,
+                why: _,
+            } => stack_trace,
+            Self::UncaughtThrow {
+                pos: _,
+                stack_trace,
+                value: _,
+            } => stack_trace,
+            Self::Generator {
+                pos: _,
+                stack_trace,
+                why: _,
+            } => stack_trace,
+            Self::Immutability {
+                pos: _,
+                stack_trace,
+                why: _,
+            } => stack_trace,
+            Self::Destructuring {
+                pos: _,
+                stack_trace,
+                why: _,
+            } => stack_trace,
         }
     }
 
@@ -375,6 +546,7 @@ impl<'source> RuntimeError<'source> {
                 stack_trace,
                 function_name: _,
                 argument_count: _,
+                why: _,
             } => stack_trace,
             Self::CalledNonFunction {
                 pos: _,
@@ -396,6 +568,31 @@ impl<'source> RuntimeError<'source> {
                 stack_trace,
                 why: _,
             } => stack_trace,
+            Self::Import {
+                pos: _,
+                stack_trace,
+                why: _,
+            } => stack_trace,
+            Self::UncaughtThrow {
+                pos: _,
+                stack_trace,
+                value: _,
+            } => stack_trace,
+            Self::Generator {
+                pos: _,
+                stack_trace,
+                why: _,
+            } => stack_trace,
+            Self::Immutability {
+                pos: _,
+                stack_trace,
+                why: _,
+            } => stack_trace,
+            Self::Destructuring {
+                pos: _,
+                stack_trace,
+                why: _,
+            } => stack_trace,
         }
     }
 
@@ -432,6 +629,7 @@ impl<'source> RuntimeError<'source> {
                 stack_trace: _,
                 function_name: _,
                 argument_count: _,
+                why: _,
             } => pos,
             Self::CalledNonFunction {
                 pos,
@@ -453,13 +651,58 @@ impl<'source> RuntimeError<'source> {
                 stack_trace: _,
                 why: _,
             } => pos,
+            Self::Import {
+                pos,
+                stack_trace: _,
+                why: _,
+            } => pos,
+            Self::UncaughtThrow {
+                pos,
+                stack_trace: _,
+                value: _,
+            } => pos,
+            Self::Generator {
+                pos,
+                stack_trace: _,
+                why: _,
+            } => pos,
+            Self::Immutability {
+                pos,
+                stack_trace: _,
+                why: _,
+            } => pos,
+            Self::Destructuring {
+                pos,
+                stack_trace: _,
+                why: _,
+            } => pos,
         }
     }
-}
 
-impl Display for RuntimeError<'_> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let description = match self {
+    /// Returns the name of the kind of this error
+    pub fn kind_name(&self) -> &'static str {
+        match self {
+            Self::Type { .. } => "type",
+            Self::Mathematical { .. } => "mathematical",
+            Self::IdentifierAlreadyDeclared { .. } => "identifier_already_declared",
+            Self::UndefinedReference { .. } => "undefined_reference",
+            Self::ResourceUnavailable { .. } => "resource_unavailable",
+            Self::InvalidOverload { .. } => "invalid_overload",
+            Self::CalledNonFunction { .. } => "called_non_function",
+            Self::InvalidMemberAccess { .. } => "invalid_member_access",
+            Self::InvalidJumpStatement { .. } => "invalid_jump_statement",
+            Self::Assertion { .. } => "assertion",
+            Self::Import { .. } => "import",
+            Self::UncaughtThrow { .. } => "uncaught_throw",
+            Self::Generator { .. } => "generator",
+            Self::Immutability { .. } => "immutability",
+            Self::Destructuring { .. } => "destructuring",
+        }
+    }
+
+    /// Returns a description of the error, without any source code context
+    pub fn description(&self) -> String {
+        match self {
             Self::Type {
                 pos: _,
                 stack_trace: _,
@@ -494,9 +737,11 @@ impl Display for RuntimeError<'_> {
                 stack_trace: _,
                 function_name,
                 argument_count,
-            } => {
-                format!("no overload for function \"{function_name}\" that takes {argument_count} arguments")
-            }
+                why,
+            } => match why {
+                Some(why) => format!("no overload for function \"{function_name}\" that takes {argument_count} arguments: {why}"),
+                None => format!("no overload for function \"{function_name}\" that takes {argument_count} arguments"),
+            },
             Self::CalledNonFunction {
                 pos: _,
                 stack_trace: _,
@@ -517,12 +762,41 @@ impl Display for RuntimeError<'_> {
                 stack_trace: _,
                 why,
             } => why.to_string(),
-        };
+            Self::Import {
+                pos: _,
+                stack_trace: _,
+                why,
+            } => why.to_string(),
+            Self::UncaughtThrow {
+                pos: _,
+                stack_trace: _,
+                value,
+            } => format!("uncaught thrown value: {value}"),
+            Self::Generator {
+                pos: _,
+                stack_trace: _,
+                why,
+            } => why.to_string(),
+            Self::Immutability {
+                pos: _,
+                stack_trace: _,
+                why,
+            } => why.to_string(),
+            Self::Destructuring {
+                pos: _,
+                stack_trace: _,
+                why,
+            } => why.to_string(),
+        }
+    }
+}
 
+impl Display for RuntimeError<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         error_formatting::write_error(
             f,
             IcelangErrorKind::Runtime,
-            &description,
+            &self.description(),
             self.pos(),
             Some(self.stack_trace()),
         )
@@ -0,0 +1,455 @@
+//! Contains code related to format specs (which control how a value is
+//! formatted in a replacement field, like the `>8.3` in `f"{x:>8.3}"`)
+
+use std::fmt::Display;
+
+use crate::value::Value;
+
+/// How a formatted value is aligned within its width
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatAlignment {
+    /// Left-aligned (`<`)
+    Left,
+    /// Right-aligned (`>`)
+    Right,
+    /// Centered (`^`)
+    Center,
+}
+
+impl FormatAlignment {
+    /// Returns the alignment represented by the given character, if any
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '<' => Some(Self::Left),
+            '>' => Some(Self::Right),
+            '^' => Some(Self::Center),
+            _ => None,
+        }
+    }
+}
+
+impl Display for FormatAlignment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Left => "<",
+                Self::Right => ">",
+                Self::Center => "^",
+            }
+        )
+    }
+}
+
+/// How a value is written out before any padding is applied
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatKind {
+    /// The normal, human-readable representation of the value
+    Display,
+    /// The debug representation of the value (`?`)
+    Debug,
+    /// Lowercase hexadecimal, for ints and bytes (`x`)
+    LowerHex,
+    /// Uppercase hexadecimal, for ints and bytes (`X`)
+    UpperHex,
+    /// Binary, for ints and bytes (`b`)
+    Binary,
+    /// Octal, for ints and bytes (`o`)
+    Octal,
+}
+
+impl FormatKind {
+    /// Returns the radix of this kind, if it's one of the radix kinds
+    fn radix(&self) -> Option<u32> {
+        match self {
+            Self::Display | Self::Debug => None,
+            Self::LowerHex | Self::UpperHex => Some(16),
+            Self::Binary => Some(2),
+            Self::Octal => Some(8),
+        }
+    }
+}
+
+impl Display for FormatKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Display => "",
+                Self::Debug => "?",
+                Self::LowerHex => "x",
+                Self::UpperHex => "X",
+                Self::Binary => "b",
+                Self::Octal => "o",
+            }
+        )
+    }
+}
+
+/// A parsed format spec, following the grammar:
+/// ```text
+/// format_spec = [[fill] align] [sign] ["0"] [width] ["." precision] [kind]
+/// align       = "<" | ">" | "^"
+/// sign        = "+" | "-"
+/// kind        = "?" | "x" | "X" | "b" | "o"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatSpec {
+    fill: char,
+    alignment: Option<FormatAlignment>,
+    always_sign: bool,
+    zero_pad: bool,
+    width: Option<usize>,
+    precision: Option<usize>,
+    kind: FormatKind,
+}
+
+impl FormatSpec {
+    /// Parses a format spec (not including the leading `:`), returning a
+    /// description of the problem if it's invalid
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let chars: Vec<char> = spec.chars().collect();
+        let mut index = 0;
+
+        // Fill and alignment
+        let (fill, alignment) = match (
+            chars.first(),
+            chars.get(1).copied().and_then(FormatAlignment::from_char),
+        ) {
+            (Some(&fill), Some(alignment)) => {
+                index += 2;
+                (fill, Some(alignment))
+            }
+            (Some(&c), None) if FormatAlignment::from_char(c).is_some() => {
+                index += 1;
+                (' ', FormatAlignment::from_char(c))
+            }
+            _ => (' ', None),
+        };
+
+        // Sign
+        let always_sign = match chars.get(index) {
+            Some('+') => {
+                index += 1;
+                true
+            }
+            Some('-') => {
+                index += 1;
+                false
+            }
+            _ => false,
+        };
+
+        // Zero padding
+        let zero_pad = chars.get(index) == Some(&'0');
+        if zero_pad {
+            index += 1;
+        }
+
+        // Width
+        let width = parse_number(&chars, &mut index, "width")?;
+
+        // Precision
+        let precision = if chars.get(index) == Some(&'.') {
+            index += 1;
+            match parse_number(&chars, &mut index, "precision")? {
+                Some(precision) => Some(precision),
+                None => return Err("expected precision after `.`".to_string()),
+            }
+        } else {
+            None
+        };
+
+        // Kind
+        let kind = match chars.get(index) {
+            Some('?') => FormatKind::Debug,
+            Some('x') => FormatKind::LowerHex,
+            Some('X') => FormatKind::UpperHex,
+            Some('b') => FormatKind::Binary,
+            Some('o') => FormatKind::Octal,
+            _ => FormatKind::Display,
+        };
+        if kind != FormatKind::Display {
+            index += 1;
+        }
+
+        // Nothing else may follow
+        if let Some(c) = chars.get(index) {
+            return Err(format!("unexpected character '{c}'"));
+        }
+
+        Ok(Self {
+            fill,
+            alignment,
+            always_sign,
+            zero_pad,
+            width,
+            precision,
+            kind,
+        })
+    }
+
+    /// Formats the given value according to this format spec, or returns None
+    /// if this format spec can't be applied to the value's type
+    pub fn apply(&self, value: &Value) -> Option<String> {
+        let is_number = matches!(value, Value::Int(_) | Value::Byte(_) | Value::Float(_));
+
+        // Write the value itself
+        let mut body = match (self.kind.radix(), value) {
+            (Some(_), _) if self.precision.is_some() => return None,
+            (Some(radix), Value::Int(value)) => value.to_str_radix(radix),
+            (Some(radix), Value::Byte(value)) => {
+                num_bigint::BigInt::from(*value).to_str_radix(radix)
+            }
+            (Some(_), _) => return None,
+            (None, value) => match (self.precision, value) {
+                (None, value) if self.kind == FormatKind::Debug => value.icelang_debug(),
+                (None, value) => value.icelang_display(),
+                (Some(precision), Value::Float(value)) if value.is_finite() => {
+                    format!("{value:.precision$}")
+                }
+                // NaN and the infinities have no digits to round
+                (Some(_), value @ Value::Float(_)) => value.icelang_display(),
+                (Some(precision), Value::String(value)) if self.kind == FormatKind::Display => {
+                    value.chars().take(precision).collect()
+                }
+                (Some(_), _) => return None,
+            },
+        };
+        if self.kind == FormatKind::UpperHex {
+            body = body.to_uppercase();
+        }
+
+        // Signs and zero padding only make sense for numbers
+        if (self.always_sign || self.zero_pad) && !is_number {
+            return None;
+        }
+        // NaN has no sign to show
+        let is_nan = matches!(value, Value::Float(value) if value.is_nan());
+        if self.always_sign && !body.starts_with('-') && !is_nan {
+            body.insert(0, '+');
+        }
+
+        // Pad the value out to the width
+        let len = body.chars().count();
+        let padding = self.width.unwrap_or(0).saturating_sub(len);
+        if padding == 0 {
+            return Some(body);
+        }
+        if self.zero_pad {
+            // Zeros go between the sign and the digits
+            let sign_len = usize::from(body.starts_with(['+', '-']));
+            body.insert_str(sign_len, &"0".repeat(padding));
+            return Some(body);
+        }
+
+        // Numbers are right-aligned by default, and everything else is
+        // left-aligned
+        let alignment = self.alignment.unwrap_or(if is_number {
+            FormatAlignment::Right
+        } else {
+            FormatAlignment::Left
+        });
+        let (before, after) = match alignment {
+            FormatAlignment::Left => (0, padding),
+            FormatAlignment::Right => (padding, 0),
+            FormatAlignment::Center => (padding / 2, padding - padding / 2),
+        };
+        let fill = |count| std::iter::repeat_n(self.fill, count);
+
+        Some(
+            fill(before)
+                .chain(body.chars())
+                .chain(fill(after))
+                .collect(),
+        )
+    }
+}
+
+impl Default for FormatSpec {
+    /// The empty format spec, which formats values the same way as when no
+    /// format spec is given at all
+    fn default() -> Self {
+        Self {
+            fill: ' ',
+            alignment: None,
+            always_sign: false,
+            zero_pad: false,
+            width: None,
+            precision: None,
+            kind: FormatKind::Display,
+        }
+    }
+}
+
+impl Display for FormatSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // A space fill is the default, so it doesn't need to be written out
+        match (self.fill, self.alignment) {
+            (' ', Some(alignment)) => write!(f, "{alignment}")?,
+            (fill, Some(alignment)) => write!(f, "{fill}{alignment}")?,
+            (_, None) => {}
+        }
+        if self.always_sign {
+            write!(f, "+")?;
+        }
+        if self.zero_pad {
+            write!(f, "0")?;
+        }
+        if let Some(width) = self.width {
+            write!(f, "{width}")?;
+        }
+        if let Some(precision) = self.precision {
+            write!(f, ".{precision}")?;
+        }
+        write!(f, "{}", self.kind)
+    }
+}
+
+/// Parses an optional decimal number from the chars of a format spec,
+/// advancing the index past it
+fn parse_number(chars: &[char], index: &mut usize, name: &str) -> Result<Option<usize>, String> {
+    let start = *index;
+    while chars.get(*index).is_some_and(char::is_ascii_digit) {
+        *index += 1;
+    }
+
+    if start == *index {
+        return Ok(None);
+    }
+
+    chars[start..*index]
+        .iter()
+        .collect::<String>()
+        .parse()
+        .map(Some)
+        .map_err(|_| format!("{name} is too large"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::*;
+
+    fn format(spec: &str, value: &Value) -> Option<String> {
+        FormatSpec::parse(spec).unwrap().apply(value)
+    }
+
+    #[test]
+    fn test_format_spec_parse() {
+        let specs = [
+            "", ">8", "<8", "^8", "*^8", "+", "08", "+08.3", ".3", "?", "x", "X", "b", "o",
+            "->10x", "0>4b", "_<5?",
+        ];
+
+        for spec in specs {
+            let parsed = FormatSpec::parse(spec).unwrap();
+
+            assert_eq!(parsed.to_string(), spec);
+        }
+
+        assert_eq!(
+            FormatSpec::parse("~^+012.5x"),
+            Ok(FormatSpec {
+                fill: '~',
+                alignment: Some(FormatAlignment::Center),
+                always_sign: true,
+                zero_pad: true,
+                width: Some(12),
+                precision: Some(5),
+                kind: FormatKind::LowerHex,
+            })
+        );
+    }
+
+    #[test]
+    fn test_format_spec_parse_invalid() {
+        let specs = [
+            "8x8",
+            "xx",
+            ".",
+            "3.x",
+            "z",
+            "+-",
+            "99999999999999999999999",
+        ];
+
+        for spec in specs {
+            assert!(FormatSpec::parse(spec).is_err(), "{spec}");
+        }
+    }
+
+    #[test]
+    fn test_format_spec_apply() {
+        let int = Value::Int(42.into());
+        let negative_int = Value::Int((-42).into());
+        let byte = Value::Byte(0x2A);
+        let float = Value::Float(1.23456);
+        let string = Value::String("hello".into());
+
+        let cases = [
+            ("", &int, "42"),
+            ("5", &int, "   42"),
+            ("<5", &int, "42   "),
+            ("^6", &int, "  42  "),
+            ("*^7", &int, "**42***"),
+            ("+", &int, "+42"),
+            ("+", &negative_int, "-42"),
+            ("05", &negative_int, "-0042"),
+            ("+06", &int, "+00042"),
+            ("x", &int, "2a"),
+            ("X", &int, "2A"),
+            ("b", &int, "101010"),
+            ("o", &int, "52"),
+            ("x", &negative_int, "-2a"),
+            ("", &byte, "2A"),
+            ("b", &byte, "101010"),
+            ("08b", &byte, "00101010"),
+            ("x", &byte, "2a"),
+            (".2", &float, "1.23"),
+            ("8.3", &float, "   1.235"),
+            ("+.1", &float, "+1.2"),
+            (".2", &Value::Float(f64::NEG_INFINITY), "-Infinity"),
+            ("+.2", &Value::Float(f64::INFINITY), "+Infinity"),
+            ("+", &Value::Float(f64::NAN), "NaN"),
+            ("+6.1", &Value::Float(f64::NAN), "   NaN"),
+            ("", &Value::Float(1e300), "1.0e300"),
+            ("", &string, "hello"),
+            ("8", &string, "hello   "),
+            (">8", &string, "   hello"),
+            (".2", &string, "he"),
+            ("?", &string, "\"hello\""),
+            ("9?", &string, "\"hello\"  "),
+        ];
+
+        for (spec, value, expected) in cases {
+            assert_eq!(format(spec, value).as_deref(), Some(expected), "{spec}");
+        }
+    }
+
+    #[test]
+    fn test_format_spec_apply_invalid() {
+        let float = Value::Float(1.5);
+        let string = Value::String("hello".into());
+        let list = Value::List(Rc::new(RefCell::new(vec![Value::Int(1.into())].into())));
+
+        let cases = [
+            ("x", &float),
+            ("b", &string),
+            ("+", &string),
+            ("05", &list),
+            (".3", &list),
+            (".3?", &string),
+            (".3x", &Value::Int(1.into())),
+        ];
+
+        for (spec, value) in cases {
+            assert_eq!(format(spec, value), None, "{spec}");
+        }
+
+        assert_eq!(format("?", &list).as_deref(), Some("[1]"));
+    }
+}
@@ -1,8 +1,15 @@
 //! Contains code related to icelang functions
 
-use std::{collections::HashMap, fmt::Display};
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    rc::Rc,
+};
 
-use crate::{ast::AstNode, source_range::SourceRange};
+use crate::{
+    ast::AstNode, call_stack::Environment, icelang_std_lib::StdLibFunction, record::RecordType,
+    source_range::SourceRange,
+};
 
 /// Represents parameters to an icelang function
 #[derive(Debug, Clone)]
@@ -12,11 +19,15 @@ pub enum FunctionParameters<'source> {
         /// The identifier for the parameter list parameter
         parameter_name: (String, SourceRange<'source>),
     },
-    /// A "normal" polyadic (fixed-arity) function (one which only accepts a
-    /// fixed number of arguments)
+    /// A "normal" polyadic function (one which accepts a fixed number of
+    /// arguments, or a range of numbers of arguments if some of its parameters
+    /// have default values or it has a rest parameter)
     Polyadic {
-        /// The parameter identifiers
-        parameters: Vec<(String, SourceRange<'source>)>,
+        /// The parameter identifiers, along with their default values (if any)
+        parameters: Vec<(String, Option<AstNode<'source>>, SourceRange<'source>)>,
+        /// The identifier for the rest parameter (if any), which collects any
+        /// extra positional arguments into a list
+        rest_parameter: Option<(String, SourceRange<'source>)>,
     },
 }
 
@@ -25,7 +36,7 @@ impl FunctionParameters<'_> {
     pub fn is_variadic(&self) -> bool {
         match self {
             FunctionParameters::Variadic { parameter_name: _ } => true,
-            FunctionParameters::Polyadic { parameters: _ } => false,
+            FunctionParameters::Polyadic { .. } => false,
         }
     }
 
@@ -33,11 +44,12 @@ impl FunctionParameters<'_> {
     pub fn is_polyadic(&self) -> bool {
         match self {
             FunctionParameters::Variadic { parameter_name: _ } => false,
-            FunctionParameters::Polyadic { parameters: _ } => true,
+            FunctionParameters::Polyadic { .. } => true,
         }
     }
 
-    /// Returns the arity of a polyadic FunctionParameters
+    /// Returns the number of (non-rest) parameters of a polyadic
+    /// FunctionParameters
     ///
     /// # Panics
     /// - If self is not the Polyadic variant
@@ -46,7 +58,87 @@ impl FunctionParameters<'_> {
             FunctionParameters::Variadic { parameter_name: _ } => {
                 panic!("attempted to get the arity of a variadic FunctionParameters");
             }
-            FunctionParameters::Polyadic { parameters } => parameters.len(),
+            FunctionParameters::Polyadic {
+                parameters,
+                rest_parameter: _,
+            } => parameters.len(),
+        }
+    }
+
+    /// Returns the minimum number of arguments accepted
+    pub fn min_argument_count(&self) -> usize {
+        match self {
+            FunctionParameters::Variadic { parameter_name: _ } => 0,
+            FunctionParameters::Polyadic {
+                parameters,
+                rest_parameter: _,
+            } => parameters
+                .iter()
+                .filter(|(_, default_value, _)| default_value.is_none())
+                .count(),
+        }
+    }
+
+    /// Returns the maximum number of arguments accepted, or None if there is
+    /// no maximum
+    pub fn max_argument_count(&self) -> Option<usize> {
+        match self {
+            FunctionParameters::Variadic { parameter_name: _ } => None,
+            FunctionParameters::Polyadic {
+                parameters,
+                rest_parameter: None,
+            } => Some(parameters.len()),
+            FunctionParameters::Polyadic {
+                parameters: _,
+                rest_parameter: Some(_),
+            } => None,
+        }
+    }
+
+    /// Returns whether or not `argument_count` arguments are accepted
+    pub fn accepts_argument_count(&self, argument_count: usize) -> bool {
+        argument_count >= self.min_argument_count()
+            && self
+                .max_argument_count()
+                .is_none_or(|max| argument_count <= max)
+    }
+
+    /// Returns whether or not there is some number of arguments which both
+    /// self and other accept
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.max_argument_count()
+            .is_none_or(|max| other.min_argument_count() <= max)
+            && other
+                .max_argument_count()
+                .is_none_or(|max| self.min_argument_count() <= max)
+    }
+
+    /// Returns whether or not overloads with the parameters self and other
+    /// conflict, meaning they can't both be declared
+    ///
+    /// Polyadic overloads conflict if there is any number of arguments they
+    /// both accept. A variadic overload is only called when no polyadic
+    /// overload accepts the arguments, so it conflicts with other variadic
+    /// overloads, and with polyadic overloads which have a rest parameter
+    /// (since those accept every number of arguments from some point on)
+    pub fn conflicts_with(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Variadic { .. }, Self::Variadic { .. }) => true,
+            (
+                Self::Variadic { .. },
+                Self::Polyadic {
+                    parameters: _,
+                    rest_parameter,
+                },
+            )
+            | (
+                Self::Polyadic {
+                    parameters: _,
+                    rest_parameter,
+                },
+                Self::Variadic { .. },
+            ) => rest_parameter.is_some(),
+            (Self::Polyadic { .. }, Self::Polyadic { .. }) => self.overlaps(other),
         }
     }
 }
@@ -65,14 +157,22 @@ impl PartialEq for FunctionParameters<'_> {
             (
                 Self::Polyadic {
                     parameters: l_parameters,
+                    rest_parameter: l_rest_parameter,
                 },
                 Self::Polyadic {
                     parameters: r_parameters,
+                    rest_parameter: r_rest_parameter,
                 },
-            ) => l_parameters
-                .iter()
-                .zip(r_parameters.iter())
-                .all(|((l_parameter, _), (r_parameter, _))| l_parameter == r_parameter),
+            ) => {
+                l_parameters.len() == r_parameters.len()
+                    && l_parameters.iter().zip(r_parameters.iter()).all(
+                        |((l_parameter, l_default_value, _), (r_parameter, r_default_value, _))| {
+                            l_parameter == r_parameter && l_default_value == r_default_value
+                        },
+                    )
+                    && l_rest_parameter.as_ref().map(|(name, _)| name)
+                        == r_rest_parameter.as_ref().map(|(name, _)| name)
+            }
             _ => false,
         }
     }
@@ -86,12 +186,25 @@ impl Display for FunctionParameters<'_> {
             FunctionParameters::Variadic {
                 parameter_name: list_name,
             } => write!(f, "[{}]", list_name.0),
-            FunctionParameters::Polyadic { parameters } => write!(
+            FunctionParameters::Polyadic {
+                parameters,
+                rest_parameter,
+            } => write!(
                 f,
                 "{}",
                 parameters
                     .iter()
-                    .map(|(parameter_name, _)| parameter_name.to_string())
+                    .map(|(parameter_name, default_value, _)| match default_value {
+                        Some(default_value) => {
+                            format!("{parameter_name} = {}", default_value.pos().read())
+                        }
+                        None => parameter_name.to_string(),
+                    })
+                    .chain(
+                        rest_parameter
+                            .iter()
+                            .map(|(rest_parameter_name, _)| format!("[{rest_parameter_name}]"))
+                    )
                     .collect::<Vec<_>>() // TODO refactor once intersperse is stabilized
                     .join(", ")
             ),
@@ -103,7 +216,7 @@ impl Display for FunctionParameters<'_> {
 #[derive(Debug, Clone)]
 pub struct FunctionGroup<'source> {
     variadic_overload: Option<Function<'source>>,
-    polyadic_overloads: HashMap<usize, Function<'source>>,
+    polyadic_overloads: Vec<Function<'source>>,
 }
 
 impl<'source> FunctionGroup<'source> {
@@ -111,14 +224,16 @@ impl<'source> FunctionGroup<'source> {
     pub fn new() -> Self {
         Self {
             variadic_overload: None,
-            polyadic_overloads: HashMap::new(),
+            polyadic_overloads: Vec::new(),
         }
     }
 
-    /// Gets the function overload for `arg_count` arguments, or None if there
-    /// is no overload for `arg_count` arguments
+    /// Gets the polyadic function overload which accepts `arg_count`
+    /// arguments, or None if there is no such overload
     pub fn get_polyadic_overload(&self, arg_count: usize) -> Option<&Function<'source>> {
-        self.polyadic_overloads.get(&arg_count)
+        self.polyadic_overloads
+            .iter()
+            .find(|overload| overload.parameters().accepts_argument_count(arg_count))
     }
 
     /// Gets the variadic overload, or None if there is no variadic overload
@@ -126,25 +241,46 @@ impl<'source> FunctionGroup<'source> {
         self.variadic_overload.as_ref()
     }
 
+    /// Gets the existing overload which would conflict with an overload with
+    /// the given parameters, or None if there is no such overload (see
+    /// [`FunctionParameters::conflicts_with`])
+    pub fn get_conflicting_overload(
+        &self,
+        parameters: &FunctionParameters<'source>,
+    ) -> Option<&Function<'source>> {
+        self.overloads()
+            .find(|overload| overload.parameters().conflicts_with(parameters))
+    }
+
+    /// Returns every overload in the function group, with the polyadic
+    /// overloads first (in the order they were added)
+    pub fn overloads(&self) -> impl Iterator<Item = &Function<'source>> {
+        self.polyadic_overloads
+            .iter()
+            .chain(self.variadic_overload.iter())
+    }
+
     /// Adds a function overload
     ///
     /// # Panics
-    /// - If an overload with the same arity already exists
+    /// - If a conflicting overload already exists
     pub fn add_overload(&mut self, func: Function<'source>) {
+        if self.get_conflicting_overload(&func.parameters).is_some() {
+            match &func.parameters {
+                FunctionParameters::Variadic { parameter_name: _ } => {
+                    panic!("duplicate variadic function definition")
+                }
+                FunctionParameters::Polyadic { .. } => {
+                    panic!("duplicate polyadic function definition")
+                }
+            }
+        }
+
         match &func.parameters {
             FunctionParameters::Variadic { parameter_name: _ } => {
-                match self.get_variadic_overload() {
-                    Some(_) => panic!("duplicate variadic function definition"),
-                    None => self.variadic_overload = Some(func),
-                };
-            }
-            FunctionParameters::Polyadic { parameters } => {
-                let arity = parameters.len();
-                match self.get_polyadic_overload(arity) {
-                    Some(_) => panic!("duplicate polyadic function definition"),
-                    None => self.polyadic_overloads.insert(arity, func),
-                };
+                self.variadic_overload = Some(func)
             }
+            FunctionParameters::Polyadic { .. } => self.polyadic_overloads.push(func),
         }
     }
 }
@@ -156,23 +292,31 @@ impl Default for FunctionGroup<'_> {
 }
 
 /// A non-builtin icelang function
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct Function<'source> {
     parameters: FunctionParameters<'source>,
-    body: Vec<AstNode<'source>>,
+    body: Rc<[AstNode<'source>]>,
+    is_generator: bool,
+    environment: Environment<'source>,
     pos: SourceRange<'source>,
 }
 
 impl<'source> Function<'source> {
-    /// Constructs a new Function with the given parameters and body
+    /// Constructs a new Function with the given parameters, body, and the
+    /// environment it captured when it was declared
     pub fn new(
         parameters: FunctionParameters<'source>,
         body: Vec<AstNode<'source>>,
+        environment: Environment<'source>,
         pos: SourceRange<'source>,
     ) -> Self {
+        let is_generator = contains_yield(&body);
+
         Self {
             parameters,
-            body,
+            body: body.into(),
+            is_generator,
+            environment,
             pos,
         }
     }
@@ -183,12 +327,184 @@ impl<'source> Function<'source> {
     }
 
     /// Gets the body of the function
-    pub fn body(&self) -> &Vec<AstNode<'source>> {
+    pub fn body(&self) -> &Rc<[AstNode<'source>]> {
         &self.body
     }
 
+    /// Returns whether or not the function is a generator function (one whose
+    /// body contains a `yield`)
+    pub fn is_generator(&self) -> bool {
+        self.is_generator
+    }
+
+    /// Gets the environment captured by the function when it was declared
+    pub fn environment(&self) -> &Environment<'source> {
+        &self.environment
+    }
+
     /// Gets the position of the function in the source code
     pub fn pos(&self) -> &SourceRange<'source> {
         &self.pos
     }
 }
+
+impl Debug for Function<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The captured environment is intentionally omitted, since it may
+        // contain this very function
+        f.debug_struct("Function")
+            .field("parameters", &self.parameters)
+            .field("body", &self.body)
+            .field("pos", &self.pos)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Returns whether or not a function body contains a `yield`
+///
+/// The parser only allows `yield` as a statement of a function body, or of a
+/// code block of a statement in a function body, so only those code blocks
+/// need to be searched (and not nested functions, or expressions)
+fn contains_yield(body: &[AstNode]) -> bool {
+    body.iter().any(|statement| match statement {
+        AstNode::Yield(_) => true,
+        AstNode::SimpleLoop(node) => contains_yield(node.body()),
+        AstNode::WhileLoop(node) => contains_yield(node.body()),
+        AstNode::ForLoop(node) => contains_yield(node.body()),
+        AstNode::MatchStatement(node) => node.arms().iter().any(|arm| contains_yield(arm.body())),
+        AstNode::IfElseStatement(node) => {
+            node.conditional_branches()
+                .iter()
+                .any(|(_, body)| contains_yield(body))
+                || node.else_branch().is_some_and(|body| contains_yield(body))
+        }
+        AstNode::TryCatch(node) => {
+            contains_yield(node.try_body()) || contains_yield(node.catch_body())
+        }
+        _ => false,
+    })
+}
+
+/// A first-class icelang function value, which can be stored in variables and
+/// collections, passed as an argument, and returned from functions
+#[derive(Debug, Clone)]
+pub enum FunctionValue<'source> {
+    /// A (possibly overloaded) user-defined function
+    UserDefined {
+        /// The name of the function, or None if the function is anonymous
+        name: Option<Rc<str>>,
+        /// The overloads of the function
+        function_group: Rc<FunctionGroup<'source>>,
+    },
+    /// A function from the icelang standard library
+    StdLib(StdLibFunction),
+    /// The constructor of a user-defined record type, which takes one argument
+    /// per field (in declaration order)
+    RecordConstructor(Rc<RecordType>),
+}
+
+impl FunctionValue<'_> {
+    /// Gets the name of the function, or None if the function is anonymous
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            FunctionValue::UserDefined {
+                name,
+                function_group: _,
+            } => name.as_deref(),
+            FunctionValue::StdLib(std_lib_function) => Some(std_lib_function.identifier()),
+            FunctionValue::RecordConstructor(record_type) => Some(record_type.name()),
+        }
+    }
+}
+
+impl PartialEq for FunctionValue<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Self::UserDefined {
+                    name: _,
+                    function_group: l_function_group,
+                },
+                Self::UserDefined {
+                    name: _,
+                    function_group: r_function_group,
+                },
+            ) => Rc::ptr_eq(l_function_group, r_function_group),
+            (Self::StdLib(l_std_lib_function), Self::StdLib(r_std_lib_function)) => {
+                l_std_lib_function == r_std_lib_function
+            }
+            (Self::RecordConstructor(l_record_type), Self::RecordConstructor(r_record_type)) => {
+                Rc::ptr_eq(l_record_type, r_record_type)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Eq for FunctionValue<'_> {}
+
+impl Hash for FunctionValue<'_> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            FunctionValue::UserDefined {
+                name: _,
+                function_group,
+            } => Rc::as_ptr(function_group).hash(state),
+            FunctionValue::StdLib(std_lib_function) => std_lib_function.hash(state),
+            FunctionValue::RecordConstructor(record_type) => Rc::as_ptr(record_type).hash(state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pos() -> SourceRange<'static> {
+        SourceRange::new("fn f() {}", "overloads.ice", 0, 1)
+    }
+
+    fn variadic() -> FunctionParameters<'static> {
+        FunctionParameters::Variadic {
+            parameter_name: ("args".to_string(), pos()),
+        }
+    }
+
+    fn polyadic(parameter_count: usize, has_rest_parameter: bool) -> FunctionParameters<'static> {
+        FunctionParameters::Polyadic {
+            parameters: (0..parameter_count)
+                .map(|i| (format!("p{i}"), None, pos()))
+                .collect(),
+            rest_parameter: has_rest_parameter.then(|| ("rest".to_string(), pos())),
+        }
+    }
+
+    #[test]
+    fn test_conflicts_with() {
+        let cases = [
+            // Variadic overloads conflict with each other
+            (variadic(), variadic(), true),
+            // A variadic overload is a fallback for fixed polyadic overloads
+            (variadic(), polyadic(0, false), false),
+            (variadic(), polyadic(2, false), false),
+            // ... but not for polyadic overloads with a rest parameter, which
+            // accept every number of arguments past their fixed parameters
+            (variadic(), polyadic(0, true), true),
+            (variadic(), polyadic(1, true), true),
+            (variadic(), polyadic(3, true), true),
+            // Polyadic overloads conflict if they accept a common number of
+            // arguments
+            (polyadic(1, false), polyadic(1, false), true),
+            (polyadic(1, false), polyadic(2, false), false),
+            (polyadic(1, true), polyadic(2, false), true),
+            (polyadic(2, true), polyadic(1, false), false),
+            (polyadic(1, true), polyadic(3, true), true),
+        ];
+
+        for (lhs, rhs, conflicts) in cases {
+            assert_eq!(lhs.conflicts_with(&rhs), conflicts, "({lhs}) vs ({rhs})");
+            assert_eq!(rhs.conflicts_with(&lhs), conflicts, "({rhs}) vs ({lhs})");
+        }
+    }
+}
@@ -0,0 +1,181 @@
+//! Contains code related to icelang generators (suspended calls to generator
+//! functions, which produce their values one at a time)
+
+use std::{cell::RefCell, fmt::Debug, rc::Rc};
+
+use num_bigint::BigInt;
+
+use crate::{
+    ast::AstNode,
+    call_stack::{Scope, StackFrame},
+    value::Value,
+};
+
+/// A call to a generator function (a function whose body contains a `yield`)
+///
+/// Calling a generator function doesn't run its body. Instead, the body is run
+/// each time the generator is resumed, until it reaches a `yield` (which
+/// suspends the generator and produces a value) or finishes
+pub struct Generator<'source> {
+    name: Option<Rc<str>>,
+    body: Rc<[AstNode<'source>]>,
+    status: GeneratorStatus<'source>,
+}
+
+impl<'source> Generator<'source> {
+    /// Constructs a new Generator which will run the given body in the given
+    /// stack frame (which should already contain the function's arguments)
+    pub(crate) fn new(
+        name: Option<Rc<str>>,
+        body: Rc<[AstNode<'source>]>,
+        frame: StackFrame<'source>,
+    ) -> Self {
+        Self {
+            name,
+            body,
+            status: GeneratorStatus::Suspended {
+                frame,
+                resume_points: Vec::new(),
+            },
+        }
+    }
+
+    /// Constructs a new Generator which pairs each value from the given
+    /// iterator with its index, as an `[index, value]` list (see
+    /// `enumerate(...)`)
+    pub(crate) fn new_enumerate(values: ForLoopIterator<'source>) -> Self {
+        Self {
+            name: Some("enumerate".into()),
+            body: Vec::new().into(),
+            status: GeneratorStatus::Enumerating {
+                values,
+                next_index: BigInt::from(0),
+            },
+        }
+    }
+
+    /// Returns the name of the generator function, or None if it was
+    /// anonymous
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns whether or not the generator has finished (and so won't produce
+    /// any more values)
+    pub fn is_finished(&self) -> bool {
+        matches!(self.status, GeneratorStatus::Finished)
+    }
+
+    /// Returns the body of the generator function
+    pub(crate) fn body(&self) -> &Rc<[AstNode<'source>]> {
+        &self.body
+    }
+
+    /// Replaces the status of the generator, returning its old status
+    pub(crate) fn replace_status(
+        &mut self,
+        status: GeneratorStatus<'source>,
+    ) -> GeneratorStatus<'source> {
+        std::mem::replace(&mut self.status, status)
+    }
+}
+
+impl Debug for Generator<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The stack frame is intentionally omitted, since it may contain this
+        // very generator
+        f.debug_struct("Generator")
+            .field("name", &self.name)
+            .field("body", &self.body)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Whether a generator is waiting to be resumed, running, or finished
+#[derive(Debug)]
+pub(crate) enum GeneratorStatus<'source> {
+    /// The generator is waiting to be resumed
+    Suspended {
+        /// The stack frame the generator's body runs in
+        frame: StackFrame<'source>,
+        /// Where the generator left off, ordered from the innermost construct
+        /// to the outermost one. A generator which hasn't started yet has no
+        /// resume points
+        resume_points: Vec<ResumePoint<'source>>,
+    },
+
+    /// The generator (made by `enumerate(...)`) is waiting to be resumed, and
+    /// will pair each remaining value of another iterator with its index
+    Enumerating {
+        /// The values which haven't been paired with an index yet
+        values: ForLoopIterator<'source>,
+        /// The index of the next value
+        next_index: BigInt,
+    },
+
+    /// The generator is currently running (so it can't be resumed again until
+    /// it suspends)
+    Running,
+
+    /// The generator has finished, and won't produce any more values
+    Finished,
+}
+
+/// A record of where a suspended generator left off inside of one of the
+/// constructs enclosing the `yield` it suspended at
+///
+/// A `yield` bubbles up through the constructs enclosing it (much like a
+/// `throw`), and each of them records where it should pick up again. When the
+/// generator is resumed, its body is interpreted again from the top, and each
+/// of those constructs takes its record (from the outermost construct to the
+/// innermost one) and skips straight to where it left off, without
+/// re-evaluating anything it had already evaluated
+#[derive(Debug)]
+pub(crate) enum ResumePoint<'source> {
+    /// Resume at the statement with the given index in a list of statements
+    Statement(usize),
+
+    /// Resume in the given scope, which was pushed by the construct before
+    /// the generator suspended
+    Scope(Scope<'source>),
+
+    /// Resume in the middle of an iteration of a `loop` or `while` loop. For a
+    /// `loop` with a number of iterations, this is the number of iterations
+    /// remaining (including the suspended iteration)
+    Loop(Option<BigInt>),
+
+    /// Resume in the middle of an iteration of a `for` loop, which gets the
+    /// rest of its values from the given iterator
+    ForLoop(ForLoopIterator<'source>),
+
+    /// Resume in the branch (of an if-else statement) or arm (of a match
+    /// statement) with the given index
+    Branch(usize),
+
+    /// Resume in the try block or catch block of a try-catch statement
+    TryCatch {
+        /// Whether the generator suspended in the catch block
+        in_catch_block: bool,
+    },
+
+    /// Resume just after the `yield` the generator suspended at
+    Yield,
+}
+
+/// The source of the values a `for` loop iterates over
+pub(crate) enum ForLoopIterator<'source> {
+    /// Values which are computed ahead of time, or without running any code
+    Values(Box<dyn Iterator<Item = Value<'source>> + 'source>),
+
+    /// The values produced by a generator
+    Generator(Rc<RefCell<Generator<'source>>>),
+}
+
+impl Debug for ForLoopIterator<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Values(_) => f.debug_tuple("Values").finish_non_exhaustive(),
+            Self::Generator(generator) => f.debug_tuple("Generator").field(generator).finish(),
+        }
+    }
+}
@@ -4,7 +4,8 @@ use num_bigint::BigInt;
 
 use crate::{
     error::runtime_error::RuntimeError,
-    interpreter::{NonLinearControlFlow, RuntimeResult},
+    generator::Generator,
+    interpreter::{iterate_value, resume_generator, NonLinearControlFlow, RuntimeResult},
     runtime_state::RuntimeState,
     source_range::SourceRange,
     value::Value,
@@ -12,10 +13,10 @@ use crate::{
 
 /// The `len` icelang standard library function
 pub fn isl_len<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
         1 => {
             match &arguments[0] {
@@ -25,12 +26,18 @@ pub fn isl_len<'source>(
                 Value::List(list) => {
                     Ok(Value::Int(BigInt::from(list.borrow().len())))
                 },
+                Value::Set(set) => {
+                    Ok(Value::Int(BigInt::from(set.borrow().len())))
+                },
+                Value::Range(range) => {
+                    Ok(Value::Int(range.len()))
+                },
                 _ => Err(NonLinearControlFlow::RuntimeError(
                     RuntimeError::new_assertion_error(
                         pos.clone(),
                         state.scope_display_name().to_string(),
                         format!(
-                            "`len(...)` expects a list or string as it's first argument, but got a value of type {}",
+                            "`len(...)` expects a list, set, string, or range as it's first argument, but got a value of type {}",
                             arguments[0].icelang_type()
                         )
                     )
@@ -50,15 +57,41 @@ pub fn isl_len<'source>(
 
 /// The `push` icelang standard library function
 pub fn isl_push<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
         2 => {
             match &arguments[0] {
                 Value::List(list) => {
-                    list.borrow_mut().push(arguments[1].reference_copy());
+                    let mut list = list.borrow_mut();
+                    let Some(list) = list.get_mut() else {
+                        return Err(NonLinearControlFlow::RuntimeError(
+                            RuntimeError::new_immutability_error(
+                                pos.clone(),
+                                state.scope_display_name().to_string(),
+                                "`push(...)` cannot modify a frozen list".to_string(),
+                            )
+                        ));
+                    };
+
+                    list.push(arguments[1].reference_copy());
+                    Ok(Value::Null)
+                },
+                Value::Set(set) => {
+                    let mut set = set.borrow_mut();
+                    let Some(set) = set.get_mut() else {
+                        return Err(NonLinearControlFlow::RuntimeError(
+                            RuntimeError::new_immutability_error(
+                                pos.clone(),
+                                state.scope_display_name().to_string(),
+                                "`push(...)` cannot modify a frozen set".to_string(),
+                            )
+                        ));
+                    };
+
+                    set.insert(arguments[1].reference_copy());
                     Ok(Value::Null)
                 },
                 _ => Err(NonLinearControlFlow::RuntimeError(
@@ -66,7 +99,7 @@ pub fn isl_push<'source>(
                         pos.clone(),
                         state.scope_display_name().to_string(),
                         format!(
-                            "`push(...)` expects a list as it's first argument, but got a value of type {}",
+                            "`push(...)` expects a list or set as it's first argument, but got a value of type {}",
                             arguments[0].icelang_type()
                         )
                     )
@@ -86,15 +119,26 @@ pub fn isl_push<'source>(
 
 /// The `pop` icelang standard library function
 pub fn isl_pop<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
         1 => {
             match &arguments[0] {
                 Value::List(list) => {
-                    Ok(list.borrow_mut().pop().unwrap_or(Value::Null))
+                    let mut list = list.borrow_mut();
+                    let Some(list) = list.get_mut() else {
+                        return Err(NonLinearControlFlow::RuntimeError(
+                            RuntimeError::new_immutability_error(
+                                pos.clone(),
+                                state.scope_display_name().to_string(),
+                                "`pop(...)` cannot modify a frozen list".to_string(),
+                            )
+                        ));
+                    };
+
+                    Ok(list.pop().unwrap_or(Value::Null))
                 },
                 _ => Err(NonLinearControlFlow::RuntimeError(
                     RuntimeError::new_assertion_error(
@@ -121,16 +165,27 @@ pub fn isl_pop<'source>(
 
 /// The `push_start` icelang standard library function
 pub fn isl_push_start<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
         2 => {
             match &arguments[0] {
                 Value::List(list) => {
                     // TODO switch to a VecDeque and use `.push_start(...)`
-                    list.borrow_mut().insert(0, arguments[1].reference_copy());
+                    let mut list = list.borrow_mut();
+                    let Some(list) = list.get_mut() else {
+                        return Err(NonLinearControlFlow::RuntimeError(
+                            RuntimeError::new_immutability_error(
+                                pos.clone(),
+                                state.scope_display_name().to_string(),
+                                "`push_start(...)` cannot modify a frozen list".to_string(),
+                            )
+                        ));
+                    };
+
+                    list.insert(0, arguments[1].reference_copy());
                     Ok(Value::Null)
                 },
                 _ => Err(NonLinearControlFlow::RuntimeError(
@@ -158,16 +213,25 @@ pub fn isl_push_start<'source>(
 
 /// The `pop_start` icelang standard library function
 pub fn isl_pop_start<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
         1 => {
             match &arguments[0] {
                 Value::List(list) => {
                     // TODO switch to a VecDeque and use `.pop_front(...)`
                     let mut list = list.borrow_mut();
+                    let Some(list) = list.get_mut() else {
+                        return Err(NonLinearControlFlow::RuntimeError(
+                            RuntimeError::new_immutability_error(
+                                pos.clone(),
+                                state.scope_display_name().to_string(),
+                                "`pop_start(...)` cannot modify a frozen list".to_string(),
+                            )
+                        ));
+                    };
 
                     Ok(if list.is_empty() {
                         Value::Null
@@ -200,10 +264,10 @@ pub fn isl_pop_start<'source>(
 
 /// The `contains_key` icelang standard library function
 pub fn isl_contains_key<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
         2 => {
             match &arguments[0] {
@@ -237,24 +301,49 @@ pub fn isl_contains_key<'source>(
 
 /// The `remove_entry` icelang standard library function
 pub fn isl_remove_entry<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
         2 => {
             match &arguments[0] {
                 Value::Dict(dict) => {
                     let mut dict = dict.borrow_mut();
+                    let Some(dict) = dict.get_mut() else {
+                        return Err(NonLinearControlFlow::RuntimeError(
+                            RuntimeError::new_immutability_error(
+                                pos.clone(),
+                                state.scope_display_name().to_string(),
+                                "`remove_entry(...)` cannot modify a frozen dict".to_string(),
+                            )
+                        ));
+                    };
+
+                    // The remaining entries keep their insertion order
+                    Ok(dict.shift_remove_entry(&arguments[1]).map(|(_, value)| value).unwrap_or(Value::Null))
+                },
+                Value::Set(set) => {
+                    let mut set = set.borrow_mut();
+                    let Some(set) = set.get_mut() else {
+                        return Err(NonLinearControlFlow::RuntimeError(
+                            RuntimeError::new_immutability_error(
+                                pos.clone(),
+                                state.scope_display_name().to_string(),
+                                "`remove_entry(...)` cannot modify a frozen set".to_string(),
+                            )
+                        ));
+                    };
 
-                    Ok(dict.remove_entry(&arguments[1]).map(|(_, value)| value).unwrap_or(Value::Null))
+                    // The remaining elements keep their insertion order
+                    Ok(set.shift_take(&arguments[1]).unwrap_or(Value::Null))
                 },
                 _ => Err(NonLinearControlFlow::RuntimeError(
                     RuntimeError::new_assertion_error(
                         pos.clone(),
                         state.scope_display_name().to_string(),
                         format!(
-                            "`remove_entry(...)` expects a dict as it's first argument, but got a value of type {}",
+                            "`remove_entry(...)` expects a dict or set as it's first argument, but got a value of type {}",
                             arguments[0].icelang_type()
                         )
                     )
@@ -274,10 +363,10 @@ pub fn isl_remove_entry<'source>(
 
 /// The `keys` icelang standard library function
 pub fn isl_keys<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
         1 => {
             match &arguments[0] {
@@ -308,3 +397,226 @@ pub fn isl_keys<'source>(
         )),
     }
 }
+
+/// The `values` icelang standard library function
+pub fn isl_values<'source>(
+    arguments: Vec<Value<'source>>,
+    pos: &SourceRange<'source>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, Value<'source>> {
+    match arguments.len() {
+        1 => {
+            match &arguments[0] {
+                Value::Dict(dict) => {
+                    let dict = dict.borrow();
+
+                    Ok(Value::List(Rc::new(RefCell::new(dict.values().map(Value::reference_copy).collect()))))
+                },
+                _ => Err(NonLinearControlFlow::RuntimeError(
+                    RuntimeError::new_assertion_error(
+                        pos.clone(),
+                        state.scope_display_name().to_string(),
+                        format!(
+                            "`values(...)` expects a dict as it's first argument, but got a value of type {}",
+                            arguments[0].icelang_type()
+                        )
+                    )
+                )),
+            }
+        }
+        argument_count => Err(NonLinearControlFlow::RuntimeError(
+            RuntimeError::new_invalid_overload_error(
+                pos.clone(),
+                state.scope_display_name().to_string(),
+                "values".to_string(),
+                argument_count,
+            ),
+        )),
+    }
+}
+
+/// The `entries` icelang standard library function
+pub fn isl_entries<'source>(
+    arguments: Vec<Value<'source>>,
+    pos: &SourceRange<'source>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, Value<'source>> {
+    match arguments.len() {
+        1 => {
+            match &arguments[0] {
+                Value::Dict(dict) => {
+                    let dict = dict.borrow();
+
+                    // Each entry is a `[key, value]` list
+                    Ok(Value::List(Rc::new(RefCell::new(dict.iter().map(|(key, value)| {
+                        Value::List(Rc::new(RefCell::new(vec![key.reference_copy(), value.reference_copy()].into())))
+                    }).collect()))))
+                },
+                _ => Err(NonLinearControlFlow::RuntimeError(
+                    RuntimeError::new_assertion_error(
+                        pos.clone(),
+                        state.scope_display_name().to_string(),
+                        format!(
+                            "`entries(...)` expects a dict as it's first argument, but got a value of type {}",
+                            arguments[0].icelang_type()
+                        )
+                    )
+                )),
+            }
+        }
+        argument_count => Err(NonLinearControlFlow::RuntimeError(
+            RuntimeError::new_invalid_overload_error(
+                pos.clone(),
+                state.scope_display_name().to_string(),
+                "entries".to_string(),
+                argument_count,
+            ),
+        )),
+    }
+}
+
+/// The `enumerate` icelang standard library function
+pub fn isl_enumerate<'source>(
+    arguments: Vec<Value<'source>>,
+    pos: &SourceRange<'source>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, Value<'source>> {
+    match arguments.len() {
+        1 => {
+            let lazy = match &arguments[0] {
+                Value::String(_) | Value::List(_) | Value::Dict(_) | Value::Set(_) => false,
+                Value::Range(_) | Value::Generator(_) => true,
+                _ => return Err(NonLinearControlFlow::RuntimeError(
+                    RuntimeError::new_assertion_error(
+                        pos.clone(),
+                        state.scope_display_name().to_string(),
+                        format!(
+                            "`enumerate(...)` expects a string, list, dict, set, range, or generator as it's first argument, but got a value of type {}",
+                            arguments[0].icelang_type()
+                        )
+                    )
+                )),
+            };
+
+            // The values are the same ones a `for` loop with a single loop
+            // variable would visit (so a dict is enumerated by its keys). Each
+            // is paired with its index in an `[index, value]` list
+            let iterable = arguments.into_iter().next().unwrap();
+            let values = iterate_value(iterable, false, pos, state)?;
+            let enumerated = Rc::new(RefCell::new(Generator::new_enumerate(values)));
+            if lazy {
+                // Ranges may be huge, and generators may never finish, so
+                // these are enumerated lazily by a generator
+                return Ok(Value::Generator(enumerated));
+            }
+
+            let mut pairs = Vec::new();
+            while let Some(pair) = resume_generator(&enumerated, pos, state)? {
+                pairs.push(pair);
+            }
+
+            Ok(Value::List(Rc::new(RefCell::new(pairs.into()))))
+        }
+        argument_count => Err(NonLinearControlFlow::RuntimeError(
+            RuntimeError::new_invalid_overload_error(
+                pos.clone(),
+                state.scope_display_name().to_string(),
+                "enumerate".to_string(),
+                argument_count,
+            ),
+        )),
+    }
+}
+
+/// The `next` icelang standard library function
+pub fn isl_next<'source>(
+    arguments: Vec<Value<'source>>,
+    pos: &SourceRange<'source>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, Value<'source>> {
+    match arguments.len() {
+        1 | 2 => {
+            match &arguments[0] {
+                Value::Generator(generator) => {
+                    match (resume_generator(generator, pos, state)?, arguments.get(1)) {
+                        (Some(value), _) => Ok(value),
+                        // The default value is used once the generator is finished
+                        (None, Some(default)) => Ok(default.reference_copy()),
+                        (None, None) => Err(NonLinearControlFlow::RuntimeError(
+                            RuntimeError::new_generator_error(
+                                pos.clone(),
+                                state.scope_display_name().to_string(),
+                                "`next(...)` was called on a finished generator without a default value".to_string(),
+                            )
+                        )),
+                    }
+                },
+                _ => Err(NonLinearControlFlow::RuntimeError(
+                    RuntimeError::new_assertion_error(
+                        pos.clone(),
+                        state.scope_display_name().to_string(),
+                        format!(
+                            "`next(...)` expects a generator as it's first argument, but got a value of type {}",
+                            arguments[0].icelang_type()
+                        )
+                    )
+                )),
+            }
+        }
+        argument_count => Err(NonLinearControlFlow::RuntimeError(
+            RuntimeError::new_invalid_overload_error(
+                pos.clone(),
+                state.scope_display_name().to_string(),
+                "next".to_string(),
+                argument_count,
+            ),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::run_icelang;
+
+    #[test]
+    fn test_enumerate_collections() {
+        run_icelang(
+            r#"
+            assert(f"{enumerate("ab")}" == "[[0, a], [1, b]]");
+            assert(f"{enumerate([5, 6])}" == "[[0, 5], [1, 6]]");
+            assert(f"{enumerate({"x": 1, "y": 2})}" == "[[0, x], [1, y]]");
+            assert(f"{enumerate({5, 6})}" == "[[0, 5], [1, 6]]");
+            "#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_enumerate_lazily() {
+        run_icelang(
+            r#"
+            let pairs = [];
+            for i, n in enumerate(10..13) {
+                push(pairs, f"{i}:{n}");
+            };
+            assert(f"{pairs}" == "[0:10, 1:11, 2:12]");
+
+            let huge = enumerate(range(10e30));
+            assert(typeof(huge) == "generator");
+            assert(f"{next(huge)}" == "[0, 0]");
+
+            fn naturals() {
+                let n = 0;
+                loop {
+                    yield n;
+                    n += 1;
+                };
+            };
+            let enumerated = enumerate(naturals());
+            next(enumerated);
+            assert(f"{next(enumerated)}" == "[1, 1]");
+            "#,
+        )
+        .unwrap();
+    }
+}
@@ -8,10 +8,10 @@ use crate::{
 
 /// The `error` icelang standard library function
 pub fn isl_error<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
         0 => Err(NonLinearControlFlow::RuntimeError(
             RuntimeError::new_assertion_error(
@@ -46,10 +46,10 @@ pub fn isl_error<'source>(
 
 /// The `assert` icelang standard library function
 pub fn isl_assert<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
         1 => match &arguments[0] {
             Value::Bool(true) => Ok(Value::Null),
@@ -84,10 +84,10 @@ pub fn isl_assert<'source>(
 
 /// The `todo` icelang standard library function
 pub fn isl_todo<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
         0 => Err(NonLinearControlFlow::RuntimeError(
             RuntimeError::new_assertion_error(
@@ -109,10 +109,10 @@ pub fn isl_todo<'source>(
 
 /// The `unimplemented` icelang standard library function
 pub fn isl_unimplemented<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
         0 => Err(NonLinearControlFlow::RuntimeError(
             RuntimeError::new_assertion_error(
@@ -134,10 +134,10 @@ pub fn isl_unimplemented<'source>(
 
 /// The `unreachable` icelang standard library function
 pub fn isl_unreachable<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
         0 => Err(NonLinearControlFlow::RuntimeError(
             RuntimeError::new_assertion_error(
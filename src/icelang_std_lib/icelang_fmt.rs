@@ -11,12 +11,12 @@ pub trait IcelangFmt {
     fn icelang_fmt(&self, buffer: &mut impl Write, fmt_args: &IcelangFmtArgs) -> std::fmt::Result;
 }
 
-impl IcelangFmt for Value {
+impl<'source> IcelangFmt for Value<'source> {
     fn icelang_fmt(&self, buffer: &mut impl Write, fmt_args: &IcelangFmtArgs) -> std::fmt::Result {
         match self {
             Value::Int(value) => write!(buffer, "{value}"),
             Value::Byte(value) => write!(buffer, "{value:02X}"),
-            Value::Float(value) => write!(buffer, "{value:?}"), // TODO implement correctly
+            Value::Float(value) => write_float(buffer, *value),
             Value::Bool(value) => match value {
                 true => write!(buffer, "true"),
                 false => write!(buffer, "false"),
@@ -99,7 +99,234 @@ impl IcelangFmt for Value {
                 write!(buffer, "}}")?;
                 Ok(())
             }
+            Value::Set(value) => {
+                write!(buffer, "{{")?;
+
+                let borrowed = value.borrow();
+                let mut values_iter = borrowed.iter();
+
+                // Write the first element
+                if let Some(element) = values_iter.next() {
+                    element.icelang_fmt(buffer, fmt_args)?;
+                }
+
+                // Write any remaining elements (with a comma prepended)
+                for element in values_iter {
+                    write!(buffer, ", ")?;
+                    element.icelang_fmt(buffer, fmt_args)?;
+                }
+
+                write!(buffer, "}}")?;
+                Ok(())
+            }
+            Value::Function(value) => match value.name() {
+                Some(name) => write!(buffer, "<function {name}>"),
+                None => write!(buffer, "<anonymous function>"),
+            },
+            Value::Record(value) => {
+                let borrowed = value.borrow();
+                write!(buffer, "{} {{", borrowed.record_type().name())?;
+
+                let mut fields_iter = borrowed.fields();
+
+                // Write the first field
+                if let Some((name, value)) = fields_iter.next() {
+                    write!(buffer, " {name}: ")?;
+                    value.icelang_fmt(buffer, fmt_args)?;
+                }
+
+                // Write any remaining fields (with a comma prepended)
+                for (name, value) in fields_iter {
+                    write!(buffer, ", {name}: ")?;
+                    value.icelang_fmt(buffer, fmt_args)?;
+                }
+
+                write!(buffer, " }}")?;
+                Ok(())
+            }
+            Value::Range(value) => write!(buffer, "{value}"),
+            Value::Generator(value) => match value.borrow().name() {
+                Some(name) => write!(buffer, "<generator {name}>"),
+                None => write!(buffer, "<anonymous generator>"),
+            },
             Value::Null => write!(buffer, "null"),
         }
     }
 }
+
+/// Writes a float in the same form as an icelang float literal (or as `NaN`,
+/// `Infinity`, or `-Infinity`), using the shortest representation which still
+/// parses back to exactly the same float
+fn write_float(buffer: &mut impl Write, value: f64) -> std::fmt::Result {
+    if value.is_nan() {
+        return write!(buffer, "NaN");
+    }
+    if value.is_sign_negative() {
+        write!(buffer, "-")?;
+    }
+    if value.is_infinite() {
+        return write!(buffer, "Infinity");
+    }
+
+    // Rust's scientific formatting already finds the shortest (and closest)
+    // digits which round-trip, like "1.25e-7", so we only need to decide
+    // where the decimal point goes
+    let scientific = format!("{:e}", value.abs());
+    let (mantissa, exponent) = scientific.split_once('e').unwrap();
+    let exponent: i32 = exponent.parse().unwrap();
+    let digits = mantissa.replace('.', "");
+
+    // Normal form, like "0.000125" or "1250.0"
+    let normal = match usize::try_from(exponent) {
+        Err(_) => format!(
+            "0.{}{digits}",
+            "0".repeat(exponent.unsigned_abs() as usize - 1)
+        ),
+        Ok(exponent) if exponent + 1 < digits.len() => {
+            format!("{}.{}", &digits[..=exponent], &digits[exponent + 1..])
+        }
+        Ok(exponent) => format!("{digits}{}.0", "0".repeat(exponent + 1 - digits.len())),
+    };
+
+    // Scientific form, like "1.25e-7" or "1.0e300"
+    let scientific = match &digits[1..] {
+        "" => format!("{}.0e{exponent}", &digits[..1]),
+        fraction => format!("{}.{fraction}e{exponent}", &digits[..1]),
+    };
+
+    // Normal form wins ties
+    if scientific.len() < normal.len() {
+        write!(buffer, "{scientific}")
+    } else {
+        write!(buffer, "{normal}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    use crate::{lexer::tokenize, test_utils::*, token::Token};
+
+    use super::*;
+
+    /// Lexes the displayed form of a float, and returns the float it lexes to
+    fn relex(displayed: &str) -> f64 {
+        let tokens = tokenize(displayed, "float.ice").unwrap();
+
+        // Negative floats are lexed as a negation followed by a float literal
+        let (negative, literal) = match tokens.as_slice() {
+            [Token::Literal(literal)] => (false, literal),
+            [Token::Punctuator(minus), Token::Literal(literal)] if minus.punctuator() == "-" => {
+                (true, literal)
+            }
+            _ => panic!("{displayed} didn't lex as a float literal"),
+        };
+
+        // The literal itself must be a valid float literal
+        let Value::Float(value) = literal.value() else {
+            panic!("{displayed} didn't lex as a float literal");
+        };
+        if negative {
+            -value
+        } else {
+            *value
+        }
+    }
+
+    fn assert_round_trips(value: f64) {
+        let displayed = Value::Float(value).icelang_display();
+        let relexed = relex(&displayed);
+
+        assert!(
+            relexed.to_bits() == value.to_bits() || relexed.is_nan() && value.is_nan(),
+            "{value:?} was displayed as {displayed}, which lexes to {relexed:?}"
+        );
+    }
+
+    #[test]
+    fn test_float_display() {
+        let cases = [
+            (0.0, "0.0"),
+            (-0.0, "-0.0"),
+            (1.0, "1.0"),
+            (-2.5, "-2.5"),
+            (0.1, "0.1"),
+            (100.0, "100.0"),
+            (123456.789, "123456.789"),
+            (1000.0, "1.0e3"),
+            (0.00125, "0.00125"),
+            (0.000125, "1.25e-4"),
+            (1.5e-7, "1.5e-7"),
+            (1e15, "1.0e15"),
+            (1e300, "1.0e300"),
+            (-1e-300, "-1.0e-300"),
+            (1.7976931348623157e308, "1.7976931348623157e308"),
+            (5e-324, "5.0e-324"),
+            (0.1 + 0.2, "0.30000000000000004"),
+            (f64::INFINITY, "Infinity"),
+            (f64::NEG_INFINITY, "-Infinity"),
+            (f64::NAN, "NaN"),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(Value::Float(value).icelang_display(), expected);
+            assert_round_trips(value);
+        }
+    }
+
+    #[test]
+    fn test_float_display_round_trips_random_bits() {
+        let mut rng = make_rng();
+
+        for _ in 0..RAND_ITERATIONS {
+            assert_round_trips(f64::from_bits(rng.gen()));
+        }
+    }
+
+    #[test]
+    fn test_float_display_round_trips_random_values() {
+        let mut rng = make_rng();
+
+        for _ in 0..RAND_ITERATIONS {
+            // Short decimals, like the ones people tend to write
+            let decimal = rng.gen_range(-100_000..100_000) as f64 / 10f64.powi(rng.gen_range(0..8));
+            assert_round_trips(decimal);
+
+            // Values spread over the whole range of exponents
+            let scaled = rng.gen::<f64>() * 10f64.powi(rng.gen_range(-320..=308));
+            assert_round_trips(scaled);
+        }
+    }
+
+    #[test]
+    fn test_float_display_is_shortest() {
+        let mut rng = make_rng();
+
+        for _ in 0..RAND_ITERATIONS {
+            let value = f64::from_bits(rng.gen());
+            if !value.is_finite() {
+                continue;
+            }
+            let displayed = Value::Float(value).icelang_display();
+
+            // Rust's formatting also uses the fewest digits, so any extra
+            // length can only come from where the decimal point was put
+            let digit_count = |s: &str| {
+                s.split('e')
+                    .next()
+                    .unwrap()
+                    .trim_start_matches(['-', '0', '.'])
+                    .trim_end_matches(['0', '.'])
+                    .chars()
+                    .filter(char::is_ascii_digit)
+                    .count()
+            };
+            assert_eq!(
+                digit_count(&displayed),
+                digit_count(&format!("{value:e}")),
+                "{value:?} was displayed as {displayed}"
+            );
+        }
+    }
+}
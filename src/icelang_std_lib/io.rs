@@ -14,16 +14,16 @@ use crate::{
 
 /// The `args` icelang standard library function
 pub fn isl_args<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
         0 => Ok(Value::List(Rc::new(RefCell::new(
             std::env::args()
                 .skip(1)
                 .map(|arg| Value::String(arg.into()))
-                .collect::<Vec<_>>(),
+                .collect(),
         )))),
         argument_count => Err(NonLinearControlFlow::RuntimeError(
             RuntimeError::new_invalid_overload_error(
@@ -38,10 +38,10 @@ pub fn isl_args<'source>(
 
 /// The `print` icelang standard library function
 pub fn isl_print<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
         1 => {
             print!("{}", arguments[0].icelang_display());
@@ -64,10 +64,10 @@ pub fn isl_print<'source>(
 
 /// The `println` icelang standard library function
 pub fn isl_println<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
         0 => {
             println!();
@@ -92,10 +92,10 @@ pub fn isl_println<'source>(
 
 /// The `eprint` icelang standard library function
 pub fn isl_eprint<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
         1 => {
             eprint!("{}", arguments[0].icelang_display());
@@ -118,10 +118,10 @@ pub fn isl_eprint<'source>(
 
 /// The `eprintln` icelang standard library function
 pub fn isl_eprintln<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
         0 => {
             eprintln!();
@@ -145,10 +145,10 @@ pub fn isl_eprintln<'source>(
 }
 
 pub fn isl_print_bin<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
         1 => {
             let list = match &arguments[0] {
@@ -202,10 +202,10 @@ pub fn isl_print_bin<'source>(
 
 /// The `input` icelang standard library function
 pub fn isl_input<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
         0 => match std::io::stdin().lines().next() {
             Some(input_result) => match input_result {
@@ -227,10 +227,10 @@ pub fn isl_input<'source>(
 
 /// The `input_bin` icelang standard library function
 pub fn isl_input_bin<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
         0 => {
             let mut buf: Vec<u8> = Vec::new();
@@ -254,10 +254,10 @@ pub fn isl_input_bin<'source>(
 
 /// The `read_file` icelang standard library function
 pub fn isl_read_file<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
         1 => {
             let Value::String(path) = &arguments[0] else {
@@ -319,10 +319,10 @@ pub fn isl_read_file<'source>(
 
 /// The `read_file_bin` icelang standard library function
 pub fn isl_read_file_bin<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
         1 => {
             let Value::String(path) = &arguments[0] else {
@@ -383,10 +383,10 @@ pub fn isl_read_file_bin<'source>(
 
 /// The `write_file` icelang standard library function
 pub fn isl_write_file<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
         2 => {
             let Value::String(path) = &arguments[0] else {
@@ -436,10 +436,10 @@ pub fn isl_write_file<'source>(
 
 /// The `write_file_bin` icelang standard library function
 pub fn isl_write_file_bin<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
         2 => {
             let Value::String(path) = &arguments[0] else {
@@ -1,12 +1,13 @@
-use std::{cell::RefCell, rc::Rc};
+use std::rc::Rc;
 
 use num_bigint::BigInt;
-use num_traits::{Signed, ToPrimitive, Zero};
+use num_traits::{Signed, Zero};
 use rand::Rng;
 
 use crate::{
     error::runtime_error::RuntimeError,
     interpreter::{NonLinearControlFlow, RuntimeResult},
+    range::Range,
     runtime_state::RuntimeState,
     source_range::SourceRange,
     value::Value,
@@ -14,25 +15,27 @@ use crate::{
 
 /// The `typeof` icelang standard library function
 pub fn isl_typeof<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
-        1 => Ok(Value::String(
-            match arguments[0] {
-                Value::Int(_) => "int",
-                Value::Byte(_) => "byte",
-                Value::Float(_) => "float",
-                Value::Bool(_) => "bool",
-                Value::String(_) => "string",
-                Value::List(_) => "list",
-                Value::Dict(_) => "dict",
-                Value::Null => "null",
-            }
-            .to_string()
-            .into(),
-        )),
+        1 => Ok(Value::String(match &arguments[0] {
+            Value::Int(_) => "int".into(),
+            Value::Byte(_) => "byte".into(),
+            Value::Float(_) => "float".into(),
+            Value::Bool(_) => "bool".into(),
+            Value::String(_) => "string".into(),
+            Value::List(_) => "list".into(),
+            Value::Dict(_) => "dict".into(),
+            Value::Set(_) => "set".into(),
+            Value::Function(_) => "function".into(),
+            // Records are named after their (user-defined) record type
+            Value::Record(record) => record.borrow().record_type().name().into(),
+            Value::Range(_) => "range".into(),
+            Value::Generator(_) => "generator".into(),
+            Value::Null => "null".into(),
+        })),
         argument_count => Err(NonLinearControlFlow::RuntimeError(
             RuntimeError::new_invalid_overload_error(
                 pos.clone(),
@@ -46,10 +49,10 @@ pub fn isl_typeof<'source>(
 
 /// The `copy` icelang standard library function
 pub fn isl_copy<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
         1 => Ok(arguments[0].deep_copy()),
         argument_count => Err(NonLinearControlFlow::RuntimeError(
@@ -63,12 +66,35 @@ pub fn isl_copy<'source>(
     }
 }
 
+/// The `freeze` icelang standard library function
+pub fn isl_freeze<'source>(
+    arguments: Vec<Value<'source>>,
+    pos: &SourceRange<'source>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, Value<'source>> {
+    match arguments.len() {
+        1 => {
+            arguments[0].freeze();
+
+            Ok(arguments[0].reference_copy())
+        }
+        argument_count => Err(NonLinearControlFlow::RuntimeError(
+            RuntimeError::new_invalid_overload_error(
+                pos.clone(),
+                state.scope_display_name().to_string(),
+                "freeze".to_string(),
+                argument_count,
+            ),
+        )),
+    }
+}
+
 /// The `range` icelang standard library function
 pub fn isl_range<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     let (start, end, step) = match arguments.len() {
         1 => match &arguments[0] {
             Value::Int(end) => (BigInt::from(0), end.to_owned(), BigInt::from(1)),
@@ -153,11 +179,11 @@ pub fn isl_range<'source>(
         }
     };
 
-    // If start == end, there is no iteration to do - this list should be empty
+    // If start == end, there is no iteration to do - this range should be empty
     // note that this applies even if step is 0 - this is the only situation in
     // which 0 is allowed for the step
     if start == end {
-        return Ok(Value::List(Rc::new(RefCell::new(Vec::new()))));
+        return Ok(Value::Range(Rc::new(Range::new(start, end, step))));
     }
 
     // Zero step is an error (we already handled start == end)
@@ -193,33 +219,15 @@ pub fn isl_range<'source>(
         ));
     }
 
-    let capacity: BigInt = (&end - &start) / &step;
-    let Some(capacity): Option<usize> = capacity.to_usize() else {
-        return Err(NonLinearControlFlow::RuntimeError(
-            RuntimeError::new_resource_unavailable_error(
-                pos.clone(),
-                state.scope_display_name().to_string(),
-                "range results in list with too many elements".to_string()
-            )
-        ));
-    };
-
-    let mut values = Vec::with_capacity(capacity);
-    let mut curr = start;
-    while (&end - &curr).sign() == step.sign() {
-        values.push(Value::Int(curr.clone()));
-        curr += &step;
-    }
-
-    Ok(Value::List(Rc::new(RefCell::new(values))))
+    Ok(Value::Range(Rc::new(Range::new(start, end, step))))
 }
 
 /// The `rand` icelang standard library function
 pub fn isl_rand<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
         0 => Ok(Value::Float(state.rng_mut().gen())),
         argument_count => Err(NonLinearControlFlow::RuntimeError(
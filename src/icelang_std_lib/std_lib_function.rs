@@ -5,12 +5,14 @@ use crate::{
 
 use super::{
     error::{isl_assert, isl_error, isl_todo, isl_unimplemented, isl_unreachable},
-    misc::{isl_copy, isl_rand, isl_range, isl_typeof},
-    string::{isl_from_codepoint, isl_to_codepoint},
+    misc::{isl_copy, isl_freeze, isl_rand, isl_range, isl_typeof},
+    string::{isl_fmt, isl_from_codepoint, isl_parse_float, isl_parse_int, isl_to_codepoint},
     time::{isl_now, isl_sleep},
     *,
 };
 
+/// A function from the icelang standard library
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum StdLibFunction {
     // I/O
     Args,
@@ -35,10 +37,17 @@ pub enum StdLibFunction {
     ContainsKey,
     RemoveEntry,
     Keys,
+    Values,
+    Entries,
+    Enumerate,
+    Next,
 
     // String
     FromCodepoint,
     ToCodepoint,
+    Fmt,
+    ParseInt,
+    ParseFloat,
 
     // Time
     Now,
@@ -54,6 +63,7 @@ pub enum StdLibFunction {
     // Miscellaneous
     Typeof,
     Copy,
+    Freeze,
     Range,
     Rand,
 }
@@ -86,10 +96,17 @@ impl StdLibFunction {
             "contains_key" => Some(Self::ContainsKey),
             "remove_entry" => Some(Self::RemoveEntry),
             "keys" => Some(Self::Keys),
+            "values" => Some(Self::Values),
+            "entries" => Some(Self::Entries),
+            "enumerate" => Some(Self::Enumerate),
+            "next" => Some(Self::Next),
 
             // String
             "from_codepoint" => Some(Self::FromCodepoint),
             "to_codepoint" => Some(Self::ToCodepoint),
+            "fmt" => Some(Self::Fmt),
+            "parse_int" => Some(Self::ParseInt),
+            "parse_float" => Some(Self::ParseFloat),
 
             // Time
             "now" => Some(Self::Now),
@@ -105,6 +122,7 @@ impl StdLibFunction {
             // Miscellaneous
             "typeof" => Some(Self::Typeof),
             "copy" => Some(Self::Copy),
+            "freeze" => Some(Self::Freeze),
             "range" => Some(Self::Range),
             "rand" => Some(Self::Rand),
 
@@ -112,14 +130,72 @@ impl StdLibFunction {
         }
     }
 
+    /// Gets the identifier of the icelang stdlib function
+    pub fn identifier(&self) -> &'static str {
+        match self {
+            // I/O
+            Self::Args => "args",
+            Self::Print => "print",
+            Self::Println => "println",
+            Self::Eprint => "eprint",
+            Self::Eprintln => "eprintln",
+            Self::PrintBin => "print_bin",
+            Self::Input => "input",
+            Self::InputBin => "input_bin",
+            Self::ReadFile => "read_file",
+            Self::ReadFileBin => "read_file_bin",
+            Self::WriteFile => "write_file",
+            Self::WriteFileBin => "write_file_bin",
+
+            // Collections
+            Self::Len => "len",
+            Self::Push => "push",
+            Self::Pop => "pop",
+            Self::PushStart => "push_start",
+            Self::PopStart => "pop_start",
+            Self::ContainsKey => "contains_key",
+            Self::RemoveEntry => "remove_entry",
+            Self::Keys => "keys",
+            Self::Values => "values",
+            Self::Entries => "entries",
+            Self::Enumerate => "enumerate",
+            Self::Next => "next",
+
+            // String
+            Self::FromCodepoint => "from_codepoint",
+            Self::ToCodepoint => "to_codepoint",
+            Self::Fmt => "fmt",
+            Self::ParseInt => "parse_int",
+            Self::ParseFloat => "parse_float",
+
+            // Time
+            Self::Now => "now",
+            Self::Sleep => "sleep",
+
+            // Error
+            Self::Error => "error",
+            Self::Assert => "assert",
+            Self::Todo => "todo",
+            Self::Unimplemented => "unimplemented",
+            Self::Unreachable => "unreachable",
+
+            // Miscellaneous
+            Self::Typeof => "typeof",
+            Self::Copy => "copy",
+            Self::Freeze => "freeze",
+            Self::Range => "range",
+            Self::Rand => "rand",
+        }
+    }
+
     /// Gets the Rust function corresponding to the icelang stdlib function
     pub fn as_fn_pointer(
         &self,
     ) -> for<'source> fn(
-        Vec<Value>,
+        Vec<Value<'source>>,
         &SourceRange<'source>,
         &mut RuntimeState<'source>,
-    ) -> RuntimeResult<'source, Value> {
+    ) -> RuntimeResult<'source, Value<'source>> {
         match self {
             // I/O
             Self::Args => isl_args,
@@ -144,10 +220,17 @@ impl StdLibFunction {
             Self::ContainsKey => isl_contains_key,
             Self::RemoveEntry => isl_remove_entry,
             Self::Keys => isl_keys,
+            Self::Values => isl_values,
+            Self::Entries => isl_entries,
+            Self::Enumerate => isl_enumerate,
+            Self::Next => isl_next,
 
             // String
             Self::FromCodepoint => isl_from_codepoint,
             Self::ToCodepoint => isl_to_codepoint,
+            Self::Fmt => isl_fmt,
+            Self::ParseInt => isl_parse_int,
+            Self::ParseFloat => isl_parse_float,
 
             // Time
             Self::Now => isl_now,
@@ -163,6 +246,7 @@ impl StdLibFunction {
             // Miscellaneous
             Self::Typeof => isl_typeof,
             Self::Copy => isl_copy,
+            Self::Freeze => isl_freeze,
             Self::Range => isl_range,
             Self::Rand => isl_rand,
         }
@@ -1,9 +1,10 @@
 use num_bigint::BigInt;
-use num_traits::ToPrimitive;
+use num_traits::{Num, ToPrimitive};
 
 use crate::{
     error::runtime_error::RuntimeError,
-    interpreter::{NonLinearControlFlow, RuntimeResult},
+    format_spec::FormatSpec,
+    interpreter::{strip_underscores, NonLinearControlFlow, RuntimeResult},
     runtime_state::RuntimeState,
     source_range::SourceRange,
     value::Value,
@@ -11,10 +12,10 @@ use crate::{
 
 /// The `from_codepoint` icelang standard library function
 pub fn isl_from_codepoint<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
         1 => {
             match &arguments[0] {
@@ -57,10 +58,10 @@ pub fn isl_from_codepoint<'source>(
 
 /// The `to_codepoint` icelang standard library function
 pub fn isl_to_codepoint<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
         1 => {
             match &arguments[0] {
@@ -95,3 +96,381 @@ pub fn isl_to_codepoint<'source>(
         )),
     }
 }
+
+/// The `fmt` icelang standard library function
+pub fn isl_fmt<'source>(
+    arguments: Vec<Value<'source>>,
+    pos: &SourceRange<'source>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, Value<'source>> {
+    let Some((template, arguments)) = arguments.split_first() else {
+        return Err(NonLinearControlFlow::RuntimeError(
+            RuntimeError::new_invalid_overload_error(
+                pos.clone(),
+                state.scope_display_name().to_string(),
+                "fmt".to_string(),
+                0,
+            ),
+        ));
+    };
+
+    let Value::String(template) = template else {
+        return Err(NonLinearControlFlow::RuntimeError(
+            RuntimeError::new_assertion_error(
+                pos.clone(),
+                state.scope_display_name().to_string(),
+                format!(
+                    "`fmt(...)` expects a string as it's first argument, but got a value of type {}",
+                    template.icelang_type()
+                )
+            )
+        ));
+    };
+
+    match format_template(template, arguments) {
+        Ok(formatted) => Ok(Value::String(formatted.into())),
+        Err(why) => Err(NonLinearControlFlow::RuntimeError(
+            RuntimeError::new_assertion_error(
+                pos.clone(),
+                state.scope_display_name().to_string(),
+                format!("`fmt(...)` {why}"),
+            ),
+        )),
+    }
+}
+
+/// Fills in the placeholders of a `fmt(...)` template with the given
+/// arguments, or returns a description of what's wrong if that isn't possible
+///
+/// A placeholder is either `{}` (the next positional argument), `{0}` (the
+/// positional argument at that index), or `{name}` (the entry with that key in
+/// the last argument, which must be a dict), and may end with a format spec
+/// (like `{:>8.3}`)
+fn format_template(template: &str, arguments: &[Value]) -> Result<String, String> {
+    let mut formatted = String::new();
+    let mut next_index = 0;
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            // Escaped curly braces
+            ('{', Some('{')) | ('}', Some('}')) => {
+                chars.next();
+                formatted.push(c);
+            }
+            ('{', _) => {
+                // Read the placeholder up to its closing '}'
+                let mut placeholder = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => placeholder.push(c),
+                        None => return Err("template has an unclosed placeholder".to_string()),
+                    }
+                }
+
+                // Split off the format spec, if there is one
+                let (name, format_spec) = match placeholder.split_once(':') {
+                    Some((name, format_spec)) => (
+                        name,
+                        FormatSpec::parse(format_spec).map_err(|why| {
+                            format!("template has an invalid format spec `{format_spec}`: {why}")
+                        })?,
+                    ),
+                    None => (placeholder.as_str(), FormatSpec::default()),
+                };
+
+                // Find the value to fill the placeholder with
+                let value = if name.is_empty() {
+                    next_index += 1;
+                    arguments
+                        .get(next_index - 1)
+                        .map(Value::reference_copy)
+                        .ok_or_else(|| {
+                            format!(
+                                "template has more placeholders than the {} argument(s) given",
+                                arguments.len()
+                            )
+                        })?
+                } else if let Ok(index) = name.parse::<usize>() {
+                    arguments.get(index).map(Value::reference_copy).ok_or_else(|| {
+                        format!("template refers to argument {index}, but only {} argument(s) were given", arguments.len())
+                    })?
+                } else {
+                    let Some(Value::Dict(named)) = arguments.last() else {
+                        return Err(format!(
+                            "template refers to named argument `{name}`, but the last argument isn't a dict"
+                        ));
+                    };
+                    named
+                        .borrow()
+                        .get(&Value::String(name.into()))
+                        .map(Value::reference_copy)
+                        .ok_or_else(|| {
+                            format!(
+                                "template refers to named argument `{name}`, which doesn't exist"
+                            )
+                        })?
+                };
+
+                formatted += &format_spec.apply(&value).ok_or_else(|| {
+                    format!(
+                        "template has format spec `{format_spec}`, which can't be applied to a value of type {}",
+                        value.icelang_type()
+                    )
+                })?;
+            }
+            ('}', _) => {
+                return Err(
+                    "template has an unmatched `}` (use `}}` for a literal `}`)".to_string()
+                );
+            }
+            (c, _) => formatted.push(c),
+        }
+    }
+
+    Ok(formatted)
+}
+
+/// The `parse_int` icelang standard library function
+pub fn isl_parse_int<'source>(
+    arguments: Vec<Value<'source>>,
+    pos: &SourceRange<'source>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, Value<'source>> {
+    let (string, radix) = match arguments.as_slice() {
+        [Value::String(string)] => (string, BigInt::from(10)),
+        [Value::String(string), Value::Int(radix)] => (string, radix.clone()),
+        [Value::String(_), radix] => {
+            return Err(NonLinearControlFlow::RuntimeError(
+                RuntimeError::new_assertion_error(
+                    pos.clone(),
+                    state.scope_display_name().to_string(),
+                    format!(
+                        "`parse_int(...)` expects an int as it's second argument, but got a value of type {}",
+                        radix.icelang_type()
+                    )
+                )
+            ));
+        }
+        [string] | [string, _] => {
+            return Err(NonLinearControlFlow::RuntimeError(
+                RuntimeError::new_assertion_error(
+                    pos.clone(),
+                    state.scope_display_name().to_string(),
+                    format!(
+                        "`parse_int(...)` expects a string as it's first argument, but got a value of type {}",
+                        string.icelang_type()
+                    )
+                )
+            ));
+        }
+        _ => {
+            return Err(NonLinearControlFlow::RuntimeError(
+                RuntimeError::new_invalid_overload_error(
+                    pos.clone(),
+                    state.scope_display_name().to_string(),
+                    "parse_int".to_string(),
+                    arguments.len(),
+                ),
+            ));
+        }
+    };
+
+    let Some(radix) = radix.to_u32().filter(|radix| (2..=36).contains(radix)) else {
+        return Err(NonLinearControlFlow::RuntimeError(
+            RuntimeError::new_assertion_error(
+                pos.clone(),
+                state.scope_display_name().to_string(),
+                format!("`parse_int(...)` expects a radix between 2 and 36, but got {radix}"),
+            ),
+        ));
+    };
+
+    Ok(parse_int(string, radix)
+        .map(Value::Int)
+        .unwrap_or(Value::Null))
+}
+
+/// Parses a string as an int in the given radix, with an optional sign (like
+/// `-ff` or `+1_000`)
+///
+/// Underscores are only allowed between two digits.
+fn parse_int(string: &str, radix: u32) -> Option<BigInt> {
+    let digits = string.strip_prefix(['+', '-']).unwrap_or(string);
+    if digits.split('_').any(|group| group.is_empty()) {
+        return None;
+    }
+
+    // The sign and underscores were already validated, and are handled by
+    // from_str_radix
+    BigInt::from_str_radix(string, radix).ok()
+}
+
+/// The `parse_float` icelang standard library function
+pub fn isl_parse_float<'source>(
+    arguments: Vec<Value<'source>>,
+    pos: &SourceRange<'source>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, Value<'source>> {
+    match arguments.as_slice() {
+        [Value::String(string)] => Ok(parse_float(string)
+            .map(Value::Float)
+            .unwrap_or(Value::Null)),
+        [argument] => Err(NonLinearControlFlow::RuntimeError(
+            RuntimeError::new_assertion_error(
+                pos.clone(),
+                state.scope_display_name().to_string(),
+                format!(
+                    "`parse_float(...)` expects a string as it's first argument, but got a value of type {}",
+                    argument.icelang_type()
+                )
+            )
+        )),
+        _ => Err(NonLinearControlFlow::RuntimeError(
+            RuntimeError::new_invalid_overload_error(
+                pos.clone(),
+                state.scope_display_name().to_string(),
+                "parse_float".to_string(),
+                arguments.len(),
+            ),
+        )),
+    }
+}
+
+/// Parses a string as a decimal number with an optional sign, fractional part,
+/// and exponent (like `-12`, `0.5`, or `1.5e-3`), or as `Infinity` or `NaN`
+///
+/// Underscores are allowed among the digits, in the same way as when casting a
+/// string to a float.
+fn parse_float(string: &str) -> Option<f64> {
+    match string {
+        "Infinity" | "+Infinity" => return Some(f64::INFINITY),
+        "-Infinity" => return Some(f64::NEG_INFINITY),
+        "NaN" => return Some(f64::NAN),
+        _ => {}
+    }
+
+    let (sign, unsigned) = match string.split_at_checked(1) {
+        Some((sign @ ("+" | "-"), unsigned)) => (sign, unsigned),
+        _ => ("", string),
+    };
+    let (mantissa, exponent) = match unsigned.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, Some(exponent)),
+        None => (unsigned, None),
+    };
+
+    // Either side of the decimal point may be empty (like `.5` or `5.`), but
+    // not both
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if integer.is_empty() && fraction.is_empty() {
+        return None;
+    }
+    let optional_digits = |digits: &str| match digits {
+        "" => Some(String::new()),
+        digits => strip_underscores(digits, 10),
+    };
+
+    let mut normalized = format!(
+        "{sign}{}.{}",
+        optional_digits(integer)?,
+        optional_digits(fraction)?
+    );
+    if let Some(exponent) = exponent {
+        let (exponent_sign, digits) = match exponent.split_at_checked(1) {
+            Some((sign @ ("+" | "-"), digits)) => (sign, digits),
+            _ => ("", exponent),
+        };
+        normalized += &format!("e{exponent_sign}{}", strip_underscores(digits, 10)?);
+    }
+
+    // Rust's float parser saturates huge exponents, so this is always quick
+    normalized.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_int() {
+        let cases = [
+            ("42", 10, Some(42)),
+            ("-42", 10, Some(-42)),
+            ("+42", 10, Some(42)),
+            ("007", 10, Some(7)),
+            ("1_000", 10, Some(1000)),
+            ("ff", 16, Some(255)),
+            ("-FF", 16, Some(-255)),
+            ("zz", 36, Some(1295)),
+            ("101", 2, Some(5)),
+            ("12", 2, None),
+            ("ff", 10, None),
+            ("0x1F", 16, None),
+            ("1e3", 10, None),
+            ("", 10, None),
+            ("-", 10, None),
+            ("+-5", 10, None),
+            ("-+5", 10, None),
+            ("--5", 10, None),
+            (" 5", 10, None),
+            ("5 ", 10, None),
+            ("_12", 10, None),
+            ("12_", 10, None),
+            ("1__2", 10, None),
+            ("-_1", 10, None),
+            ("_", 10, None),
+        ];
+
+        for (string, radix, expected) in cases {
+            assert_eq!(
+                parse_int(string, radix),
+                expected.map(BigInt::from),
+                "parse_int({string:?}, {radix})"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_float() {
+        let cases = [
+            ("12", Some(12.0)),
+            ("-0.5", Some(-0.5)),
+            ("+1.5e3", Some(1500.0)),
+            ("1.5E3", Some(1500.0)),
+            ("1e-3", Some(0.001)),
+            (".5", Some(0.5)),
+            ("5.", Some(5.0)),
+            ("1e999999999999", Some(f64::INFINITY)),
+            ("1_000.0", Some(1000.0)),
+            ("-1_0.2_5e1_0", Some(-10.25e10)),
+            ("+.5_0", Some(0.5)),
+            ("Infinity", Some(f64::INFINITY)),
+            ("+Infinity", Some(f64::INFINITY)),
+            ("-Infinity", Some(f64::NEG_INFINITY)),
+            ("", None),
+            ("-", None),
+            (" 1", None),
+            ("1 ", None),
+            ("e5", None),
+            ("1e", None),
+            (".", None),
+            ("_", None),
+            ("1._", None),
+            ("1e_", None),
+            ("1e+", None),
+            ("1.5.3", None),
+            ("+-5", None),
+            ("inf", None),
+            ("infinity", None),
+            ("nan", None),
+            ("0x1F", None),
+        ];
+
+        for (string, expected) in cases {
+            assert_eq!(parse_float(string), expected, "parse_float({string:?})");
+        }
+
+        assert!(parse_float("NaN").unwrap().is_nan());
+    }
+}
@@ -11,10 +11,10 @@ use crate::{
 
 /// The `now` icelang standard library function
 pub fn isl_now<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
         0 => Ok(Value::Int(BigInt::from(
             std::time::SystemTime::now()
@@ -35,10 +35,10 @@ pub fn isl_now<'source>(
 
 /// The `sleep` icelang standard library function
 pub fn isl_sleep<'source>(
-    arguments: Vec<Value>,
+    arguments: Vec<Value<'source>>,
     pos: &SourceRange<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match arguments.len() {
         1 => {
             match &arguments[0] {
@@ -20,6 +20,16 @@ pub enum IcelangType {
     List,
     /// A resizable dictionary mapping keys to values
     Dict,
+    /// A resizable collection of unique values
+    Set,
+    /// A callable function
+    Function,
+    /// An instance of a user-defined record type
+    Record,
+    /// A lazy sequence of evenly spaced ints
+    Range,
+    /// A suspended call to a generator function
+    Generator,
     /// A "nothing" value, representing the absence of a value
     Null,
 }
@@ -37,6 +47,11 @@ impl Display for IcelangType {
                 Self::String => "string",
                 Self::List => "list",
                 Self::Dict => "dict",
+                Self::Set => "set",
+                Self::Function => "function",
+                Self::Record => "record",
+                Self::Range => "range",
+                Self::Generator => "generator",
                 Self::Null => "null",
             }
         )
@@ -56,6 +71,11 @@ mod tests {
         assert_eq!(IcelangType::String.to_string(), "string");
         assert_eq!(IcelangType::List.to_string(), "list");
         assert_eq!(IcelangType::Dict.to_string(), "dict");
+        assert_eq!(IcelangType::Set.to_string(), "set");
+        assert_eq!(IcelangType::Function.to_string(), "function");
+        assert_eq!(IcelangType::Record.to_string(), "record");
+        assert_eq!(IcelangType::Range.to_string(), "range");
+        assert_eq!(IcelangType::Generator.to_string(), "generator");
         assert_eq!(IcelangType::Null.to_string(), "null");
     }
 }
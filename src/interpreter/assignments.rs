@@ -1,4 +1,5 @@
-use num_traits::{Signed, ToPrimitive};
+use num_bigint::BigInt;
+use num_traits::{One, ToPrimitive};
 
 use crate::{
     ast::{AssignmentKind, AstNode, AstNodeAssignment, BinaryOperationKind},
@@ -10,37 +11,88 @@ use crate::{
 
 use super::{
     core::interpret_expression,
+    member_access::{
+        interpret_computed_member_access, interpret_dot_member_access, interpret_slice_bounds,
+        resolve_index,
+    },
     runtime_result::{NonLinearControlFlow, RuntimeResult},
 };
 
 /// Assigns a value to an lvalue node
+///
+/// # Panics
+/// - If the node isn't an lvalue (a variable access, dot member access,
+///   computed member access, or slice)
 pub fn assign_to_lvalue<'source>(
     lvalue: &AstNode<'source>,
-    value: Value,
+    value: Value<'source>,
     state: &mut RuntimeState<'source>,
 ) -> RuntimeResult<'source, ()> {
     // TODO Can we avoid the to_string() here? If the lookup fails, we don't
     // need the mutable borrow anymore... I think?
     let scope_display_name = state.scope_display_name().to_string();
     match lvalue {
-        AstNode::VariableAccess(node) => match state.lookup_variable_mut(node.ident()) {
-            Some(lvalue) => *lvalue = value,
-            None => {
+        AstNode::VariableAccess(node) => {
+            if state.lookup_variable(node.ident()).is_none() {
                 return Err(NonLinearControlFlow::RuntimeError(
                     RuntimeError::new_undefined_reference_error(
                         node.pos().clone(),
                         scope_display_name,
                         node.ident().to_string(),
                     ),
-                ))
+                ));
             }
-        },
+
+            if state.is_constant(node.ident()) {
+                return Err(NonLinearControlFlow::RuntimeError(
+                    RuntimeError::new_immutability_error(
+                        node.pos().clone(),
+                        scope_display_name,
+                        format!("cannot reassign constant \"{}\"", node.ident()),
+                    ),
+                ));
+            }
+
+            state.reassign_variable(node.ident(), value);
+        }
         AstNode::DotMemberAccess(node) => {
             let root = interpret_expression(node.root(), state)?;
+            if root.is_frozen() {
+                return Err(NonLinearControlFlow::RuntimeError(
+                    RuntimeError::new_immutability_error(
+                        node.pos().clone(),
+                        scope_display_name,
+                        format!("cannot modify a frozen {}", root.icelang_type()),
+                    ),
+                ));
+            }
             match root {
                 Value::Dict(dict) => {
                     let key = Value::String(node.member().into());
-                    dict.borrow_mut().insert(key, value);
+                    let mut dict = dict.borrow_mut();
+                    dict.get_mut()
+                        .expect("dict should not be frozen")
+                        .insert(key, value);
+                }
+                Value::Record(record) => {
+                    let mut record = record.borrow_mut();
+                    let record = record.get_mut().expect("record should not be frozen");
+                    let record_type_name = record.record_type().name().to_string();
+                    match record.get_mut(node.member()) {
+                        Some(field) => *field = value,
+                        None => {
+                            return Err(NonLinearControlFlow::RuntimeError(
+                                RuntimeError::new_invalid_member_access_error(
+                                    node.pos().clone(),
+                                    scope_display_name,
+                                    format!(
+                                        "record type {record_type_name} has no field \"{}\"",
+                                        node.member()
+                                    ),
+                                ),
+                            ))
+                        }
+                    }
                 }
                 root => {
                     return Err(NonLinearControlFlow::RuntimeError(
@@ -56,30 +108,22 @@ pub fn assign_to_lvalue<'source>(
         AstNode::ComputedMemberAccess(node) => {
             let root = interpret_expression(node.root(), state)?;
             let member = interpret_expression(node.member_node(), state)?;
+            if root.is_frozen() {
+                return Err(NonLinearControlFlow::RuntimeError(
+                    RuntimeError::new_immutability_error(
+                        node.pos().clone(),
+                        scope_display_name,
+                        format!("cannot modify a frozen {}", root.icelang_type()),
+                    ),
+                ));
+            }
             match root {
                 Value::List(list) => {
                     let mut list = list.borrow_mut();
-                    let index: usize = match member {
-                        Value::Int(index) => {
-                            if index.is_negative() {
-                                return Err(NonLinearControlFlow::RuntimeError(
-                                    RuntimeError::new_invalid_member_access_error(
-                                        node.pos().clone(),
-                                        scope_display_name,
-                                        format!(
-                                            "index out of bounds (index {}, length {})",
-                                            index,
-                                            list.len(),
-                                        ),
-                                    ),
-                                ));
-                            }
-                            match index.to_usize() {
-                                Some(index) => index,
-                                None => todo!(),
-                            }
-                        }
-                        Value::Byte(byte) => byte as usize,
+                    let list = list.get_mut().expect("list should not be frozen");
+                    let index = match member {
+                        Value::Int(index) => index,
+                        Value::Byte(byte) => BigInt::from(byte),
                         member => {
                             return Err(NonLinearControlFlow::RuntimeError(
                                 RuntimeError::new_invalid_member_access_error(
@@ -95,7 +139,7 @@ pub fn assign_to_lvalue<'source>(
                     };
 
                     // Ensure the index is in-bounds
-                    if index >= list.len() {
+                    let Some(resolved_index) = resolve_index(&index, list.len()) else {
                         return Err(NonLinearControlFlow::RuntimeError(
                             RuntimeError::new_invalid_member_access_error(
                                 node.pos().clone(),
@@ -107,12 +151,15 @@ pub fn assign_to_lvalue<'source>(
                                 ),
                             ),
                         ));
-                    }
+                    };
 
-                    list[index] = value;
+                    list[resolved_index] = value;
                 }
                 Value::Dict(dict) => {
-                    dict.borrow_mut().insert(member, value);
+                    let mut dict = dict.borrow_mut();
+                    dict.get_mut()
+                        .expect("dict should not be frozen")
+                        .insert(member, value);
                 }
                 root => {
                     return Err(NonLinearControlFlow::RuntimeError(
@@ -125,7 +172,92 @@ pub fn assign_to_lvalue<'source>(
                 }
             }
         }
-        _ => todo!(),
+        AstNode::Slice(node) => {
+            let root = interpret_expression(node.root(), state)?;
+            let bounds = interpret_slice_bounds(node, state)?;
+            if root.is_frozen() {
+                return Err(NonLinearControlFlow::RuntimeError(
+                    RuntimeError::new_immutability_error(
+                        node.pos().clone(),
+                        scope_display_name,
+                        format!("cannot modify a frozen {}", root.icelang_type()),
+                    ),
+                ));
+            }
+            match root {
+                Value::List(list) => {
+                    // Take the new elements before borrowing the list, since
+                    // they may be the same list (as in `x[1:] = x`)
+                    let elements: Vec<_> = match value {
+                        Value::List(elements) => elements
+                            .borrow()
+                            .iter()
+                            .map(Value::reference_copy)
+                            .collect(),
+                        value => {
+                            return Err(NonLinearControlFlow::RuntimeError(
+                                RuntimeError::new_type_error(
+                                    node.pos().clone(),
+                                    scope_display_name,
+                                    format!(
+                                        "cannot assign a value of type {} to a slice of a list",
+                                        value.icelang_type()
+                                    ),
+                                ),
+                            ));
+                        }
+                    };
+
+                    let mut list = list.borrow_mut();
+                    let list = list.get_mut().expect("list should not be frozen");
+                    let indices = bounds.indices(list.len());
+
+                    if bounds.step().is_one() {
+                        // A contiguous slice is replaced by the new elements,
+                        // which may change the length of the list
+                        let start = indices.start().to_usize().unwrap();
+                        let end = indices.end().to_usize().unwrap().max(start);
+                        list.splice(start..end, elements);
+                    } else {
+                        // Any other slice must be replaced element by element
+                        if BigInt::from(elements.len()) != indices.len() {
+                            return Err(NonLinearControlFlow::RuntimeError(
+                                RuntimeError::new_invalid_member_access_error(
+                                    node.pos().clone(),
+                                    scope_display_name,
+                                    format!(
+                                        "cannot assign {} element{} to a slice of length {} with a step of {}",
+                                        elements.len(),
+                                        match elements.len() {
+                                            1 => "",
+                                            _ => "s",
+                                        },
+                                        indices.len(),
+                                        bounds.step(),
+                                    ),
+                                ),
+                            ));
+                        }
+                        for (index, element) in indices.iter().zip(elements) {
+                            list[index.to_usize().unwrap()] = element;
+                        }
+                    }
+                }
+                root => {
+                    return Err(NonLinearControlFlow::RuntimeError(
+                        RuntimeError::new_invalid_member_access_error(
+                            node.pos().clone(),
+                            scope_display_name,
+                            format!(
+                                "cannot assign to a slice of a value of type {}",
+                                root.icelang_type()
+                            ),
+                        ),
+                    ));
+                }
+            }
+        }
+        _ => panic!("expected lvalue"),
     };
 
     Ok(())
@@ -138,7 +270,7 @@ pub fn assign_to_lvalue<'source>(
 pub fn interpret_assignment<'source>(
     assignment: &AstNodeAssignment<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     macro_rules! augmented_assignment {
         ($operation: expr) => {{
             let lhs = interpret_expression(assignment.lhs(), state)?;
@@ -254,5 +386,96 @@ pub fn interpret_assignment<'source>(
             assign_to_lvalue(assignment.lhs(), Value::Bool(rhs_value), state)?;
             Ok(Value::Bool(rhs_value))
         }
+        AssignmentKind::NullCoalescing => {
+            // A key missing from a dict counts as null, so that it's inserted
+            let lhs = match assignment.lhs() {
+                AstNode::DotMemberAccess(node) => interpret_dot_member_access(node, true, state)?,
+                AstNode::ComputedMemberAccess(node) => {
+                    interpret_computed_member_access(node, true, state)?
+                }
+                lhs => interpret_expression(lhs, state)?,
+            };
+
+            // Short-circuit if the lhs isn't null
+            if !matches!(lhs, Value::Null) {
+                return Ok(lhs);
+            }
+
+            let rhs = interpret_expression(assignment.rhs(), state)?;
+
+            assign_to_lvalue(assignment.lhs(), rhs.reference_copy(), state)?;
+            Ok(rhs)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::run_with_error_of;
+
+    #[test]
+    fn test_const_cannot_be_reassigned() {
+        run_with_error_of(
+            r#"
+            const limit = 10, [low, high] = [1, 5];
+            let reassigned = "immutability: cannot reassign constant \"limit\"";
+            assert(error_of(fn() { limit = 11; }) == reassigned);
+            assert(error_of(fn() { limit += 1; }) == reassigned);
+            assert(error_of(fn() { [limit, low] = [0, 0]; }) == reassigned);
+            assert(error_of(fn() { high = 0; }) == "immutability: cannot reassign constant \"high\"");
+            assert(limit == 10 && low == 1 && high == 5);
+
+            // Constants can still be shadowed, and their contents modified
+            const values = [1];
+            fn shadow() {
+                let values = 2;
+                values = 3;
+                return values;
+            };
+            assert(shadow() == 3);
+            push(values, 2);
+            assert(len(values) == 2);
+            "#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_frozen_values_cannot_be_modified() {
+        run_with_error_of(
+            r#"
+            record Point { x, y };
+            let items = freeze([1, [2], {"a": 3}]);
+            let table = freeze({"nested": [1], "n": 2});
+            let unique = freeze({1, 2});
+            let point = freeze(Point { x: 1, y: [2] });
+
+            let frozen_list = "immutability: cannot modify a frozen list";
+            assert(error_of(fn() { items[0] = 5; }) == frozen_list);
+            assert(error_of(fn() { items[0] += 5; }) == frozen_list);
+            assert(error_of(fn() { items[1][0] = 5; }) == frozen_list);
+            assert(error_of(fn() { items[0:1] = []; }) == frozen_list);
+            assert(error_of(fn() { table.nested[0] = 5; }) == frozen_list);
+            assert(error_of(fn() { point.y[0] = 5; }) == frozen_list);
+            assert(error_of(fn() { items[2].a = 5; }) == "immutability: cannot modify a frozen dict");
+            assert(error_of(fn() { table.n = 5; }) == "immutability: cannot modify a frozen dict");
+            assert(error_of(fn() { table["m"] = 5; }) == "immutability: cannot modify a frozen dict");
+            assert(error_of(fn() { point.x = 5; }) == "immutability: cannot modify a frozen record");
+
+            assert(error_of(fn() { push(items, 1); }) == "immutability: `push(...)` cannot modify a frozen list");
+            assert(error_of(fn() { pop(items[1]); }) == "immutability: `pop(...)` cannot modify a frozen list");
+            assert(error_of(fn() { push(unique, 3); }) == "immutability: `push(...)` cannot modify a frozen set");
+            assert(error_of(fn() { remove_entry(unique, 1); }) == "immutability: `remove_entry(...)` cannot modify a frozen set");
+            assert(error_of(fn() { remove_entry(table, "n"); }) == "immutability: `remove_entry(...)` cannot modify a frozen dict");
+
+            assert(f"{items}" == "[1, [2], {a: 3}]" && table.n == 2 && len(unique) == 2);
+
+            // A copy of a frozen value can be modified
+            let copied = copy(items);
+            copied[1][0] = 5;
+            assert(copied[1][0] == 5 && items[1][0] == 2);
+            "#,
+        )
+        .unwrap();
     }
 }
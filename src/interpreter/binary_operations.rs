@@ -22,7 +22,7 @@ macro_rules! impl_bin_op {
         fn $func_name<'source>(
             node: &AstNodeBinaryOperation<'source>,
             state: &mut RuntimeState<'source>,
-        ) -> RuntimeResult<'source, Value> {
+        ) -> RuntimeResult<'source, Value<'source>> {
             assert!(node.operation() == BinaryOperationKind::$operation_kind);
 
             let lhs = interpret_expression(node.lhs(), state)?;
@@ -40,10 +40,26 @@ macro_rules! impl_bin_op {
     };
 }
 
+fn interpret_null_coalescing<'source>(
+    node: &AstNodeBinaryOperation<'source>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, Value<'source>> {
+    assert!(node.operation() == BinaryOperationKind::NullCoalescing);
+
+    let lhs = interpret_expression(node.lhs(), state)?;
+
+    // Short-circuit if the lhs isn't null
+    if !matches!(lhs, Value::Null) {
+        return Ok(lhs);
+    }
+
+    interpret_expression(node.rhs(), state)
+}
+
 fn interpret_logical_or<'source>(
     node: &AstNodeBinaryOperation<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     assert!(node.operation() == BinaryOperationKind::LogicalOr);
 
     let lhs = interpret_expression(node.lhs(), state)?;
@@ -87,7 +103,7 @@ fn interpret_logical_or<'source>(
 fn interpret_logical_and<'source>(
     node: &AstNodeBinaryOperation<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     assert!(node.operation() == BinaryOperationKind::LogicalAnd);
 
     let lhs = interpret_expression(node.lhs(), state)?;
@@ -147,8 +163,9 @@ impl_bin_op!(interpret_exponentiation, exponentiation, Exponentiation);
 pub fn interpret_binary_operation<'source>(
     node: &AstNodeBinaryOperation<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match node.operation() {
+        BinaryOperationKind::NullCoalescing => interpret_null_coalescing(node, state),
         BinaryOperationKind::LogicalOr => interpret_logical_or(node, state),
         BinaryOperationKind::LogicalAnd => interpret_logical_and(node, state),
         BinaryOperationKind::BitwiseOr => interpret_bitwise_or(node, state),
@@ -1,5 +1,7 @@
+use std::{cell::RefCell, rc::Rc};
+
 use num_bigint::BigInt;
-use num_traits::{FromPrimitive, ToPrimitive};
+use num_traits::{FromPrimitive, Num, ToPrimitive, Zero};
 
 use super::{
     runtime_result::{NonLinearControlFlow, RuntimeResult},
@@ -10,7 +12,10 @@ use crate::{
     runtime_state::RuntimeState, value::Value,
 };
 
-pub fn cast(value: &Value, destination_type: IcelangType) -> Option<Value> {
+pub fn cast<'source>(
+    value: &Value<'source>,
+    destination_type: IcelangType,
+) -> Option<Value<'source>> {
     match (value, destination_type) {
         // Integer casts
         (Value::Int(value), IcelangType::Byte) => {
@@ -29,14 +34,32 @@ pub fn cast(value: &Value, destination_type: IcelangType) -> Option<Value> {
 
         // To-string casts
         (
-            Value::Int(_) | Value::Byte(_) | Value::Float(_) | Value::Bool(_),
+            Value::Int(_) | Value::Byte(_) | Value::Float(_) | Value::Bool(_) | Value::Range(_),
             IcelangType::String,
         ) => Some(Value::String(value.icelang_display().into())),
 
+        // Range casts
+        (Value::Range(range), IcelangType::List) => Some(match range.len().to_usize() {
+            Some(len) => {
+                let mut list = Vec::with_capacity(len);
+                list.extend(range.iter().map(Value::Int));
+                Value::List(Rc::new(RefCell::new(list.into())))
+            }
+            None => Value::Null,
+        }),
+
+        // Set casts
+        (Value::List(list), IcelangType::Set) => Some(Value::Set(Rc::new(RefCell::new(
+            list.borrow().iter().map(Value::reference_copy).collect(),
+        )))),
+        (Value::Set(set), IcelangType::List) => Some(Value::List(Rc::new(RefCell::new(
+            set.borrow().iter().map(Value::reference_copy).collect(),
+        )))),
+
         // From-string casts
-        (Value::String(_value), IcelangType::Int) => todo!(),
-        (Value::String(_value), IcelangType::Byte) => todo!(),
-        (Value::String(_value), IcelangType::Float) => todo!(),
+        (Value::String(value), IcelangType::Int | IcelangType::Byte | IcelangType::Float) => {
+            Some(parse_numeric_literal(value, destination_type).unwrap_or(Value::Null))
+        }
 
         // Same-type casts are not permitted
         (Value::Int(_), IcelangType::Int) => None,
@@ -46,53 +69,226 @@ pub fn cast(value: &Value, destination_type: IcelangType) -> Option<Value> {
         (Value::String(_), IcelangType::String) => None,
         (Value::List(_), IcelangType::List) => None,
         (Value::Dict(_), IcelangType::Dict) => None,
+        (Value::Set(_), IcelangType::Set) => None,
+        (Value::Function(_), IcelangType::Function) => None,
+        (Value::Record(_), IcelangType::Record) => None,
+        (Value::Range(_), IcelangType::Range) => None,
         (Value::Null, IcelangType::Null) => None,
 
         // Casts to or from null are not permitted
         (Value::Null, _) | (_, IcelangType::Null) => None,
 
+        // Record types can't be cast to or from
+        (Value::Record(_), _) | (_, IcelangType::Record) => None,
+
+        // Nothing can be cast to a range
+        (_, IcelangType::Range) => None,
+
+        // Generators can't be cast to or from
+        (Value::Generator(_), _) | (_, IcelangType::Generator) => None,
+
         // Unsupported/nonsense casts
         (Value::Int(_), IcelangType::Bool) => None,
         (Value::Int(_), IcelangType::List) => None,
         (Value::Int(_), IcelangType::Dict) => None,
+        (Value::Int(_), IcelangType::Set) => None,
+        (Value::Int(_), IcelangType::Function) => None,
         (Value::Byte(_), IcelangType::Bool) => None,
         (Value::Byte(_), IcelangType::List) => None,
         (Value::Byte(_), IcelangType::Dict) => None,
+        (Value::Byte(_), IcelangType::Set) => None,
+        (Value::Byte(_), IcelangType::Function) => None,
         (Value::Float(_), IcelangType::Byte) => None,
         (Value::Float(_), IcelangType::Bool) => None,
         (Value::Float(_), IcelangType::List) => None,
         (Value::Float(_), IcelangType::Dict) => None,
+        (Value::Float(_), IcelangType::Set) => None,
+        (Value::Float(_), IcelangType::Function) => None,
         (Value::Bool(_), IcelangType::Int) => None,
         (Value::Bool(_), IcelangType::Byte) => None,
         (Value::Bool(_), IcelangType::Float) => None,
         (Value::Bool(_), IcelangType::List) => None,
         (Value::Bool(_), IcelangType::Dict) => None,
+        (Value::Bool(_), IcelangType::Set) => None,
+        (Value::Bool(_), IcelangType::Function) => None,
         (Value::String(_), IcelangType::Bool) => None,
         (Value::String(_), IcelangType::List) => None,
         (Value::String(_), IcelangType::Dict) => None,
+        (Value::String(_), IcelangType::Set) => None,
+        (Value::String(_), IcelangType::Function) => None,
         (Value::List(_), IcelangType::Int) => None,
         (Value::List(_), IcelangType::Byte) => None,
         (Value::List(_), IcelangType::Float) => None,
         (Value::List(_), IcelangType::Bool) => None,
         (Value::List(_), IcelangType::String) => None,
         (Value::List(_), IcelangType::Dict) => None,
+        (Value::List(_), IcelangType::Function) => None,
         (Value::Dict(_), IcelangType::Int) => None,
         (Value::Dict(_), IcelangType::Byte) => None,
         (Value::Dict(_), IcelangType::Float) => None,
         (Value::Dict(_), IcelangType::Bool) => None,
         (Value::Dict(_), IcelangType::String) => None,
         (Value::Dict(_), IcelangType::List) => None,
+        (Value::Dict(_), IcelangType::Set) => None,
+        (Value::Dict(_), IcelangType::Function) => None,
+        (Value::Function(_), IcelangType::Int) => None,
+        (Value::Function(_), IcelangType::Byte) => None,
+        (Value::Function(_), IcelangType::Float) => None,
+        (Value::Function(_), IcelangType::Bool) => None,
+        (Value::Function(_), IcelangType::String) => None,
+        (Value::Function(_), IcelangType::List) => None,
+        (Value::Function(_), IcelangType::Dict) => None,
+        (Value::Function(_), IcelangType::Set) => None,
+        (Value::Range(_), IcelangType::Int) => None,
+        (Value::Range(_), IcelangType::Byte) => None,
+        (Value::Range(_), IcelangType::Float) => None,
+        (Value::Range(_), IcelangType::Bool) => None,
+        (Value::Range(_), IcelangType::Dict) => None,
+        (Value::Range(_), IcelangType::Function) => None,
+        (Value::Range(_), IcelangType::Set) => None,
+        (Value::Set(_), IcelangType::Int) => None,
+        (Value::Set(_), IcelangType::Byte) => None,
+        (Value::Set(_), IcelangType::Float) => None,
+        (Value::Set(_), IcelangType::Bool) => None,
+        (Value::Set(_), IcelangType::String) => None,
+        (Value::Set(_), IcelangType::Dict) => None,
+        (Value::Set(_), IcelangType::Function) => None,
     }
 }
 
-/// Interprets a list literal AstNodeLiteral
+/// The largest exponent allowed in an int literal being cast from a string
 ///
-/// # Panics
-/// - If the AstNodeListLiteral isn't a valid list literal
+/// Casts are often used on untrusted input, and an exponent like `1e999999999`
+/// would otherwise take practically forever (and all of the memory) to compute
+const MAX_CAST_INT_EXPONENT: u32 = 100_000;
+
+/// Parses a string as a numeric literal of the given type, following the same
+/// rules as the lexer
+///
+/// The literal may be preceded by a `-` sign (except for bytes), and may be
+/// `Infinity` or `NaN` if the literal type is float. Returns None if the string
+/// isn't a valid literal of the given type.
+fn parse_numeric_literal<'source>(
+    string: &str,
+    literal_type: IcelangType,
+) -> Option<Value<'source>> {
+    let (negative, unsigned) = match string.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, string),
+    };
+
+    match literal_type {
+        IcelangType::Int => parse_int_literal(unsigned)
+            .map(|value| Value::Int(if negative { -value } else { value })),
+        IcelangType::Byte if !negative => parse_byte_literal(unsigned).map(Value::Byte),
+        IcelangType::Float => parse_float_literal(unsigned)
+            .map(|value| Value::Float(if negative { -value } else { value })),
+        _ => None,
+    }
+}
+
+/// Parses an int literal (like `1_000`, `0x1F`, or `5e3`)
+fn parse_int_literal(literal: &str) -> Option<BigInt> {
+    let (radix, digits) = match literal.get(..2) {
+        Some("0x") => (16, &literal[2..]),
+        Some("0b") => (2, &literal[2..]),
+        Some("0o") => (8, &literal[2..]),
+        Some("0d") => (10, &literal[2..]),
+        _ if literal.starts_with(|c: char| c.is_ascii_digit()) => (10, literal),
+        _ => return None,
+    };
+
+    // Only decimal literals may have an exponent (hex literals may contain an
+    // 'e', but only as a digit)
+    let (mantissa, exponent) = match digits.split_once('e') {
+        Some((mantissa, exponent)) if radix == 10 => (mantissa, Some(exponent)),
+        _ => (digits, None),
+    };
+
+    let mantissa = BigInt::from_str_radix(&strip_underscores(mantissa, radix)?, radix).ok()?;
+    match exponent {
+        Some(exponent) => {
+            let exponent = strip_underscores(exponent, 10)?;
+            if mantissa.is_zero() {
+                return Some(mantissa);
+            }
+
+            // The exponent is checked before building the power of ten, so
+            // huge exponents are rejected quickly
+            let exponent = exponent
+                .parse::<u32>()
+                .ok()
+                .filter(|exponent| *exponent <= MAX_CAST_INT_EXPONENT)?;
+            Some(mantissa * BigInt::from(10).pow(exponent))
+        }
+        None => Some(mantissa),
+    }
+}
+
+/// Parses a byte literal (like `8xFF` or `8b1010`)
+fn parse_byte_literal(literal: &str) -> Option<u8> {
+    let radix = match literal.get(..2)? {
+        "8x" => 16,
+        "8b" => 2,
+        "8o" => 8,
+        "8d" => 10,
+        _ => return None,
+    };
+
+    u8::from_str_radix(&strip_underscores(&literal[2..], radix)?, radix).ok()
+}
+
+/// Parses a float literal (like `1.5`, `1_000.0`, or `2.5e-3`), or `Infinity`
+/// or `NaN`
+fn parse_float_literal(literal: &str) -> Option<f64> {
+    match literal {
+        "Infinity" => return Some(f64::INFINITY),
+        "NaN" => return Some(f64::NAN),
+        _ => {}
+    }
+
+    let (integer, rest) = literal.split_once('.')?;
+    if !integer.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    let (fraction, exponent) = match rest.split_once('e') {
+        Some((fraction, exponent)) => (fraction, Some(exponent)),
+        None => (rest, None),
+    };
+
+    let mut normalized = format!(
+        "{}.{}",
+        strip_underscores(integer, 10)?,
+        strip_underscores(fraction, 10)?
+    );
+    if let Some(exponent) = exponent {
+        let (sign, digits) = match exponent.split_at_checked(1) {
+            Some((sign @ ("+" | "-"), digits)) => (sign, digits),
+            _ => ("", exponent),
+        };
+        normalized += &format!("e{sign}{}", strip_underscores(digits, 10)?);
+    }
+
+    // Rust's float parser saturates huge exponents, so this is always quick
+    normalized.parse().ok()
+}
+
+/// Removes the underscores from a sequence of digits in the given radix, or
+/// returns None if it contains anything else, or no digits at all
+pub(crate) fn strip_underscores(digits: &str, radix: u32) -> Option<String> {
+    let stripped: String = digits.chars().filter(|c| *c != '_').collect();
+
+    (!stripped.is_empty() && stripped.chars().all(|c| c.is_digit(radix))).then_some(stripped)
+}
+
+/// Interprets an AstNodeTypeCast
+///
+/// A fallible cast which fails results in null, but a cast between types which
+/// can't be cast at all is a runtime error
 pub fn interpret_type_cast<'source>(
     node: &AstNodeTypeCast<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     let value = interpret_expression(node.body(), state)?;
     let destination_type = node.destination_type();
 
@@ -108,3 +304,147 @@ pub fn interpret_type_cast<'source>(
         ))
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cast_string(string: &str, destination_type: IcelangType) -> Value<'static> {
+        cast(&Value::String(string.into()), destination_type).unwrap()
+    }
+
+    #[test]
+    fn test_cast_string_to_int() {
+        let cases = [
+            ("0", Some(0)),
+            ("12", Some(12)),
+            ("-12", Some(-12)),
+            ("1_000", Some(1000)),
+            ("1__0_", Some(10)),
+            ("0x1F", Some(31)),
+            ("-0x_1f", Some(-31)),
+            ("0x1e3", Some(0x1e3)),
+            ("0b101", Some(5)),
+            ("0o17", Some(15)),
+            ("0d42", Some(42)),
+            ("1e3", Some(1000)),
+            ("0d5e2", Some(500)),
+            ("2e1_0", Some(20_000_000_000)),
+            ("0e999999999999", Some(0)),
+            ("", None),
+            ("-", None),
+            ("--1", None),
+            ("- 1", None),
+            (" 12", None),
+            ("12 ", None),
+            ("12//", None),
+            ("_12", None),
+            ("1.5", None),
+            ("1e", None),
+            ("1e-3", None),
+            ("0x", None),
+            ("0b2", None),
+            ("0xe3e", Some(0xe3e)),
+            ("8x2A", None),
+            ("true", None),
+            ("Infinity", None),
+        ];
+
+        for (string, expected) in cases {
+            let expected = expected.map_or(Value::Null, |expected: i64| {
+                Value::Int(BigInt::from(expected))
+            });
+            assert_eq!(
+                cast_string(string, IcelangType::Int),
+                expected,
+                "int({string:?})"
+            );
+        }
+    }
+
+    #[test]
+    fn test_cast_string_to_int_rejects_huge_exponents() {
+        let limit = format!("1e{MAX_CAST_INT_EXPONENT}");
+        assert_eq!(
+            cast_string(&limit, IcelangType::Int),
+            Value::Int(BigInt::from(10).pow(MAX_CAST_INT_EXPONENT))
+        );
+
+        for string in [
+            format!("1e{}", MAX_CAST_INT_EXPONENT + 1),
+            "1e100000000000".to_string(),
+            "1e999999999999999999999999999999".to_string(),
+        ] {
+            assert_eq!(
+                cast_string(&string, IcelangType::Int),
+                Value::Null,
+                "int({string:?})"
+            );
+        }
+    }
+
+    #[test]
+    fn test_cast_string_to_byte() {
+        let cases = [
+            ("8x2A", Some(0x2A)),
+            ("8xff", Some(0xFF)),
+            ("8b1010_1010", Some(0b1010_1010)),
+            ("8o17", Some(0o17)),
+            ("8d255", Some(255)),
+            ("8d256", None),
+            ("-8x01", None),
+            ("8x", None),
+            ("8x_", None),
+            ("2A", None),
+            ("42", None),
+            (" 8x2A", None),
+        ];
+
+        for (string, expected) in cases {
+            assert_eq!(
+                cast_string(string, IcelangType::Byte),
+                expected.map_or(Value::Null, Value::Byte),
+                "byte({string:?})"
+            );
+        }
+    }
+
+    #[test]
+    fn test_cast_string_to_float() {
+        let cases = [
+            ("1.5", Some(1.5)),
+            ("-1.5", Some(-1.5)),
+            ("1_000.0_1", Some(1000.01)),
+            ("1._5", Some(1.5)),
+            ("2.5e-3", Some(2.5e-3)),
+            ("2.5e+3", Some(2.5e3)),
+            ("2.5e_3", Some(2.5e3)),
+            ("1.0e999999999999", Some(f64::INFINITY)),
+            ("1.0e-999999999999", Some(0.0)),
+            ("Infinity", Some(f64::INFINITY)),
+            ("-Infinity", Some(f64::NEG_INFINITY)),
+            ("NaN", Some(f64::NAN)),
+            ("12", None),
+            ("1e3", None),
+            ("1.", None),
+            ("1.e5", None),
+            (".5", None),
+            ("_1.5", None),
+            ("1.5e", None),
+            ("1.5e+", None),
+            ("1.5e+-3", None),
+            ("1.5 ", None),
+            ("inf", None),
+            ("nan", None),
+            ("infinity", None),
+        ];
+
+        for (string, expected) in cases {
+            assert_eq!(
+                cast_string(string, IcelangType::Float),
+                expected.map_or(Value::Null, Value::Float),
+                "float({string:?})"
+            );
+        }
+    }
+}
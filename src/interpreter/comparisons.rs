@@ -20,7 +20,7 @@ use super::{
 pub fn interpret_comparison<'source>(
     node: &AstNodeComparison<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     // This is repeated often... or, *was* repeated often :)
     macro_rules! invalid_types {
         ($state: ident, $lhs: ident, $kind: ident, $rhs: ident) => {
@@ -49,6 +49,17 @@ pub fn interpret_comparison<'source>(
 
         // Perform the comparison
         let sub_comparison = match (&lhs, &rhs) {
+            // Membership tests depend on the type of the collection (the rhs)
+            // rather than on both operands having the same type
+            (lhs_value, rhs_value)
+                if matches!(kind, ComparisonKind::In | ComparisonKind::NotIn) =>
+            {
+                match icelang_contains(rhs_value, lhs_value) {
+                    Some(contained) => contained == (*kind == ComparisonKind::In),
+                    None => invalid_types!(state, lhs, kind, rhs),
+                }
+            }
+
             (Value::Int(lhs_value), Value::Int(rhs_value)) => match kind {
                 ComparisonKind::Equal => lhs_value == rhs_value,
                 ComparisonKind::NotEqual => lhs_value != rhs_value,
@@ -56,6 +67,9 @@ pub fn interpret_comparison<'source>(
                 ComparisonKind::GreaterThan => lhs_value > rhs_value,
                 ComparisonKind::LessThanOrEqual => lhs_value <= rhs_value,
                 ComparisonKind::GreaterThanOrEqual => lhs_value >= rhs_value,
+                ComparisonKind::In | ComparisonKind::NotIn => {
+                    unreachable!("membership tests are handled separately")
+                }
             },
 
             (Value::Byte(lhs_value), Value::Byte(rhs_value)) => match kind {
@@ -65,6 +79,9 @@ pub fn interpret_comparison<'source>(
                 ComparisonKind::GreaterThan => lhs_value > rhs_value,
                 ComparisonKind::LessThanOrEqual => lhs_value <= rhs_value,
                 ComparisonKind::GreaterThanOrEqual => lhs_value >= rhs_value,
+                ComparisonKind::In | ComparisonKind::NotIn => {
+                    unreachable!("membership tests are handled separately")
+                }
             },
 
             // TODO ensure these are doing exactly what I want them to
@@ -77,6 +94,9 @@ pub fn interpret_comparison<'source>(
                 ComparisonKind::GreaterThan => lhs_value > rhs_value,
                 ComparisonKind::LessThanOrEqual => lhs_value <= rhs_value,
                 ComparisonKind::GreaterThanOrEqual => lhs_value >= rhs_value,
+                ComparisonKind::In | ComparisonKind::NotIn => {
+                    unreachable!("membership tests are handled separately")
+                }
             },
 
             (Value::Bool(lhs_value), Value::Bool(rhs_value)) => match kind {
@@ -109,6 +129,56 @@ pub fn interpret_comparison<'source>(
                 kind => invalid_types!(state, lhs, kind, rhs),
             },
 
+            // Sets are compared by their elements, so that two sets are equal
+            // exactly when each is a subset of the other
+            (Value::Set(lhs_value), Value::Set(rhs_value)) => {
+                let (lhs_set, rhs_set) = (lhs_value.borrow(), rhs_value.borrow());
+                match kind {
+                    ComparisonKind::Equal => *lhs_set == *rhs_set,
+                    ComparisonKind::NotEqual => *lhs_set != *rhs_set,
+                    ComparisonKind::LessThan => {
+                        lhs_set.len() < rhs_set.len() && lhs_set.is_subset(&rhs_set)
+                    }
+                    ComparisonKind::GreaterThan => {
+                        lhs_set.len() > rhs_set.len() && lhs_set.is_superset(&rhs_set)
+                    }
+                    ComparisonKind::LessThanOrEqual => lhs_set.is_subset(&rhs_set),
+                    ComparisonKind::GreaterThanOrEqual => lhs_set.is_superset(&rhs_set),
+                    ComparisonKind::In | ComparisonKind::NotIn => {
+                        unreachable!("membership tests are handled separately")
+                    }
+                }
+            }
+
+            // Records are compared by reference equality
+            (Value::Record(lhs_value), Value::Record(rhs_value)) => match kind {
+                ComparisonKind::Equal => Rc::ptr_eq(lhs_value, rhs_value),
+                ComparisonKind::NotEqual => !Rc::ptr_eq(lhs_value, rhs_value),
+                kind => invalid_types!(state, lhs, kind, rhs),
+            },
+
+            // Functions are compared by reference equality
+            (Value::Function(lhs_value), Value::Function(rhs_value)) => match kind {
+                ComparisonKind::Equal => lhs_value == rhs_value,
+                ComparisonKind::NotEqual => lhs_value != rhs_value,
+                kind => invalid_types!(state, lhs, kind, rhs),
+            },
+
+            // Ranges are immutable, so are compared by the elements they
+            // contain
+            (Value::Range(lhs_value), Value::Range(rhs_value)) => match kind {
+                ComparisonKind::Equal => lhs_value == rhs_value,
+                ComparisonKind::NotEqual => lhs_value != rhs_value,
+                kind => invalid_types!(state, lhs, kind, rhs),
+            },
+
+            // Generators are compared by reference equality
+            (Value::Generator(lhs_value), Value::Generator(rhs_value)) => match kind {
+                ComparisonKind::Equal => Rc::ptr_eq(lhs_value, rhs_value),
+                ComparisonKind::NotEqual => !Rc::ptr_eq(lhs_value, rhs_value),
+                kind => invalid_types!(state, lhs, kind, rhs),
+            },
+
             // Null may be compared with anything
             (Value::Null, other) | (other, Value::Null) => match kind {
                 ComparisonKind::Equal => other.icelang_type() == IcelangType::Null,
@@ -132,3 +202,113 @@ pub fn interpret_comparison<'source>(
     // If no comparison was false, the whole thing is true
     Ok(Value::Bool(true))
 }
+
+/// Checks whether two values are equal, following the same rules as the `==`
+/// operator. Returns None if the values can't be compared with `==` (because
+/// they are of different types, neither of which is null)
+pub fn icelang_eq<'source>(lhs: &Value<'source>, rhs: &Value<'source>) -> Option<bool> {
+    Some(match (lhs, rhs) {
+        (Value::Int(lhs), Value::Int(rhs)) => lhs == rhs,
+        (Value::Byte(lhs), Value::Byte(rhs)) => lhs == rhs,
+        (Value::Float(lhs), Value::Float(rhs)) => lhs == rhs,
+        (Value::Bool(lhs), Value::Bool(rhs)) => lhs == rhs,
+        (Value::String(lhs), Value::String(rhs)) => lhs == rhs,
+        (Value::List(lhs), Value::List(rhs)) => Rc::ptr_eq(lhs, rhs),
+        (Value::Dict(lhs), Value::Dict(rhs)) => Rc::ptr_eq(lhs, rhs),
+        (Value::Set(lhs), Value::Set(rhs)) => *lhs.borrow() == *rhs.borrow(),
+        (Value::Record(lhs), Value::Record(rhs)) => Rc::ptr_eq(lhs, rhs),
+        (Value::Function(lhs), Value::Function(rhs)) => lhs == rhs,
+        (Value::Range(lhs), Value::Range(rhs)) => lhs == rhs,
+        (Value::Generator(lhs), Value::Generator(rhs)) => Rc::ptr_eq(lhs, rhs),
+        (Value::Null, other) | (other, Value::Null) => other.icelang_type() == IcelangType::Null,
+        _ => return None,
+    })
+}
+
+/// Checks whether a collection contains a value, following the same rules as
+/// the `in` operator:
+/// - A list contains each of its elements
+/// - A dict contains each of its keys
+/// - A set contains each of its elements
+/// - A string contains each of its substrings
+/// - A range contains each int it would iterate over
+///
+/// Lists, dicts, and sets all compare values by their contents (like the keys
+/// of a dict), rather than following the rules of `==`, so that `[1] in [[1]]`
+/// and `[1] in {[1]}` agree
+///
+/// Returns None if the value can't be tested for membership in the collection
+/// (because the collection isn't a list, dict, set, string, or range, or
+/// because it's a string or range and the value isn't a string or int
+/// respectively)
+pub fn icelang_contains<'source>(
+    collection: &Value<'source>,
+    value: &Value<'source>,
+) -> Option<bool> {
+    Some(match (collection, value) {
+        (Value::List(list), value) => list.borrow().contains(value),
+        (Value::Dict(dict), value) => dict.borrow().contains_key(value),
+        (Value::Set(set), value) => set.borrow().contains(value),
+        (Value::String(string), Value::String(substring)) => string.contains(&**substring),
+        (Value::Range(range), Value::Int(value)) => range.contains(value),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::run_icelang;
+
+    #[test]
+    fn test_set_equality() {
+        run_icelang(
+            r#"
+            let a = {1, 2, 3};
+            assert(a == a);
+            assert(a == {3, 2, 1});
+            assert(a != {1, 2});
+            assert(a != {1, 2, 4});
+            assert({[1], {"a": 2}} == {{"a": 2}, [1]});
+            "#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_set_subset_operators() {
+        run_icelang(
+            r#"
+            let a = {1, 2};
+            let b = {2, 1};
+            assert(a <= b && a >= b && a == b);
+            assert(!(a < b) && !(a > b));
+
+            assert({1} < a && {1} <= a && a > {1} && a >= {1});
+            assert(!({1, 3} <= a) && !({1, 3} >= a) && {1, 3} != a);
+            "#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_membership_of_nested_collections() {
+        run_icelang(
+            r#"
+            let inner = [1];
+            assert(inner in [inner]);
+            assert([1] in [[1], [2]]);
+            assert([1] in {[1]});
+            assert([1] in {[1]: 0});
+            assert([3] not in [[1], [2]] && [3] not in {[1]} && [3] not in {[1]: 0});
+
+            assert({"a": [1]} in [{"a": [1]}]);
+            assert({"a": [1]} in {{"a": [1]}});
+            assert({2, 1} in [{1, 2}] && {2, 1} in {{1, 2}});
+
+            assert(1 not in [1.0] && 1 not in {1.0});
+            assert(NaN in [NaN] && NaN in {NaN});
+            "#,
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,175 @@
+use std::{cell::RefCell, rc::Rc};
+
+use indexmap::IndexMap;
+
+use crate::{
+    ast::{AstNodeDictComprehension, AstNodeListComprehension, ComprehensionClause},
+    error::runtime_error::RuntimeError,
+    runtime_state::RuntimeState,
+    value::Value,
+};
+
+use super::{
+    control_flow::{for_loop_iterator, next_for_loop_value},
+    core::interpret_expression,
+    destructuring::declare_pattern,
+    runtime_result::{NonLinearControlFlow, RuntimeResult},
+};
+
+/// Interprets an AstNodeListComprehension
+pub fn interpret_list_comprehension<'source>(
+    node: &AstNodeListComprehension<'source>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, Value<'source>> {
+    let mut list = Vec::new();
+
+    interpret_comprehension_clauses(node.clauses(), state, &mut |state| {
+        list.push(interpret_expression(node.element(), state)?);
+
+        Ok(())
+    })?;
+
+    Ok(Value::List(Rc::new(RefCell::new(list.into()))))
+}
+
+/// Interprets an AstNodeDictComprehension
+pub fn interpret_dict_comprehension<'source>(
+    node: &AstNodeDictComprehension<'source>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, Value<'source>> {
+    let mut dict = IndexMap::new();
+
+    // Later entries replace any earlier entries with the same key
+    interpret_comprehension_clauses(node.clauses(), state, &mut |state| {
+        let key = interpret_expression(node.key(), state)?;
+        let value = interpret_expression(node.value(), state)?;
+        dict.insert(key, value);
+
+        Ok(())
+    })?;
+
+    Ok(Value::Dict(Rc::new(RefCell::new(dict.into()))))
+}
+
+/// Interprets the clauses of a comprehension, calling `produce` (to evaluate
+/// the comprehension's element, or key and value) once for each combination
+/// of loop-control variables which passes every `if` clause
+///
+/// Each iteration of a `for` clause gets its own scope, so the loop-control
+/// variables don't leak out of the comprehension
+fn interpret_comprehension_clauses<'source>(
+    clauses: &[ComprehensionClause<'source>],
+    state: &mut RuntimeState<'source>,
+    produce: &mut dyn FnMut(&mut RuntimeState<'source>) -> RuntimeResult<'source, ()>,
+) -> RuntimeResult<'source, ()> {
+    let Some((clause, rest)) = clauses.split_first() else {
+        return produce(state);
+    };
+
+    match clause {
+        // `for` clauses iterate in the same way as for loops
+        ComprehensionClause::For {
+            pattern,
+            multiple_variables: _,
+            iterable,
+            pos,
+        } => {
+            let mut iterator = for_loop_iterator(iterable, pattern, pos, state)?;
+
+            while let Some(value) = next_for_loop_value(&mut iterator, pos, state)? {
+                let depth = state.call_stack_depth();
+
+                state.push_scope();
+                let result = declare_pattern(pattern, value, false, state)
+                    .and_then(|()| interpret_comprehension_clauses(rest, state, produce));
+
+                // However the iteration ended, the scope it pushed is gone now
+                state.unwind_call_stack(depth);
+
+                result?;
+            }
+
+            Ok(())
+        }
+
+        ComprehensionClause::If { condition, pos: _ } => {
+            match interpret_expression(condition, state)? {
+                Value::Bool(true) => interpret_comprehension_clauses(rest, state, produce),
+                Value::Bool(false) => Ok(()),
+                condition_value => Err(NonLinearControlFlow::RuntimeError(
+                    RuntimeError::new_type_error(
+                        condition.pos().clone(),
+                        state.scope_display_name().to_string(),
+                        format!(
+                            "expected bool, got value of type {}",
+                            condition_value.icelang_type()
+                        ),
+                    ),
+                )),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::run_with_error_of;
+
+    #[test]
+    fn test_list_comprehensions() {
+        run_with_error_of(
+            r#"
+            assert(f"{[x * y for x in 1..=3 for y in 1..=x if x != y]}" == "[2, 3, 6]");
+            assert(f"{[a + b for [a, b] in [[1, 2], [3, 4]]]}" == "[3, 7]");
+            assert(f"{[c for c in "hé"]}" == "[h, é]");
+
+            // Each iteration gets its own scope, so closures capture different
+            // variables
+            let closures = [fn() { return i; } for i in 0..3];
+            assert(f"{[closure() for closure in closures]}" == "[0, 1, 2]");
+
+            assert(error_of(fn() { [1 for v in [1] if 1]; }) == "type: expected bool, got value of type int");
+            assert(error_of(fn() { [1 for v in 5]; }) == "type: expected string, list, dict, set, range, or generator, got value of type int");
+            "#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_dict_comprehensions() {
+        run_with_error_of(
+            r#"
+            let doubled = {k: v * 2 for k, v in {"a": 1}};
+            assert(f"{doubled}" == "{a: 2}");
+
+            // Later entries replace earlier entries with the same key
+            let by_parity = {x % 2: x for x in 0..5};
+            assert(f"{by_parity}" == "{0: 4, 1: 3}");
+            "#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_comprehension_scoping() {
+        run_with_error_of(
+            r#"
+            let x = "outer";
+            let numbers = [x for x in [1, 2]];
+            assert(x == "outer" && f"{numbers}" == "[1, 2]");
+            assert(error_of(fn() { [y for y in [1] if false]; y; }) == "undefined_reference: identifier \"y\" is not defined");
+
+            // Like a for loop, a comprehension iterates over a snapshot of a
+            // list
+            let items = [1, 2, 3];
+            fn grow(value) {
+                push(items, value);
+                return value;
+            };
+            assert(f"{[grow(item) for item in items]}" == "[1, 2, 3]");
+            assert(f"{items}" == "[1, 2, 3, 1, 2, 3]");
+            "#,
+        )
+        .unwrap();
+    }
+}
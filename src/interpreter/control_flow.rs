@@ -1,230 +1,381 @@
+use std::{cell::RefCell, rc::Rc};
+
+use num_bigint::BigInt;
 use num_traits::Signed;
 
 use crate::{
     ast::{
-        AstNodeForLoop, AstNodeIfElseStatement, AstNodeMatchStatement, AstNodeSimpleLoop,
-        AstNodeWhileLoop, JumpStatementKind,
+        AstNode, AstNodeForLoop, AstNodeIfElseStatement, AstNodeMatchStatement, AstNodeSimpleLoop,
+        AstNodeWhileLoop, DestructuringPattern, JumpStatementKind,
     },
     error::runtime_error::RuntimeError,
+    generator::{ForLoopIterator, ResumePoint},
     runtime_state::RuntimeState,
+    source_range::SourceRange,
     value::Value,
 };
 
 use super::{
-    core::{interpret_expression, interpret_statement},
+    core::{interpret_code_block, interpret_code_block_statements, interpret_expression},
+    destructuring::declare_pattern,
+    generators::{push_resumable_scope, resume_generator},
+    pattern_matching::matches_pattern,
     runtime_result::{NonLinearControlFlow, RuntimeResult},
 };
 
-/// Interprets an AstNodeSimpleLoop
+/// What a loop should do after an iteration of its body
+enum LoopControl<'source> {
+    /// Keep looping (the body finished, or hit a `continue`)
+    Next,
+
+    /// Stop looping, and evaluate to the given value (the body hit a `break`)
+    Break(Value<'source>),
+}
+
+/// Interprets a single iteration of a loop body in a new scope. Before the
+/// body, `declare` is called to declare any per-iteration variables in the
+/// new scope (unless a generator is resuming in the middle of the iteration).
+/// `label` is the label of the loop (if any), which determines which labeled
+/// `break`s and `continue`s target this loop
+fn interpret_loop_iteration<'source>(
+    body: &[AstNode<'source>],
+    label: Option<&str>,
+    state: &mut RuntimeState<'source>,
+    declare: impl FnOnce(&mut RuntimeState<'source>) -> RuntimeResult<'source, ()>,
+) -> RuntimeResult<'source, LoopControl<'source>> {
+    let depth = state.call_stack_depth();
+
+    let resuming = push_resumable_scope(state);
+    let result = if resuming { Ok(()) } else { declare(state) }
+        .and_then(|()| interpret_code_block_statements(body, state))
+        .map_err(|err| err.resume_at(|| ResumePoint::Scope(state.innermost_scope())));
+
+    // However the iteration ended, the scope it pushed is gone now
+    state.unwind_call_stack(depth);
+
+    match result {
+        Ok(_) => Ok(LoopControl::Next),
+        // Jump statements targeting a different (outer) loop keep bubbling up
+        Err(NonLinearControlFlow::JumpStatement(jump_statement))
+            if jump_statement
+                .label()
+                .is_some_and(|target| Some(target) != label) =>
+        {
+            Err(NonLinearControlFlow::JumpStatement(jump_statement))
+        }
+        Err(NonLinearControlFlow::JumpStatement(jump_statement)) => match jump_statement.kind() {
+            JumpStatementKind::Break => Ok(LoopControl::Break(
+                jump_statement.into_value().unwrap_or(Value::Null),
+            )),
+            JumpStatementKind::Continue => Ok(LoopControl::Next),
+            JumpStatementKind::Return => Err(NonLinearControlFlow::JumpStatement(jump_statement)),
+        },
+        Err(err) => Err(err),
+    }
+}
+
+/// Interprets an AstNodeSimpleLoop, returning the value it breaks with (or
+/// null, if it finishes without breaking)
 pub fn interpret_simple_loop<'source>(
     simple_loop: &AstNodeSimpleLoop<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, ()> {
-    match simple_loop.condition() {
-        Some(condition) => match interpret_expression(condition, state)? {
-            // TODO DRY this up (and all the loop bodies in general - there's a
-            // lot of repeated code)
-            Value::Int(iterations) => {
-                // TODO what should even happen here?
-                // Hi from future me: this should *definitely* be a RuntimeError
-                if iterations.is_negative() {
-                    todo!();
-                }
-
-                let mut iterations_remaining = iterations;
-                'icelang_loop: while iterations_remaining.is_positive() {
-                    state.push_scope();
-
-                    for statement in simple_loop.body() {
-                        match interpret_statement(statement, state) {
-                            Ok(()) => {}
-                            Err(NonLinearControlFlow::JumpStatement(jump_statement)) => {
-                                match jump_statement.kind() {
-                                    JumpStatementKind::Break => break 'icelang_loop,
-                                    JumpStatementKind::Continue => break,
-                                    JumpStatementKind::Return => {
-                                        return Err(NonLinearControlFlow::JumpStatement(
-                                            jump_statement,
-                                        ));
-                                    }
-                                }
-                            }
-                            Err(NonLinearControlFlow::RuntimeError(err)) => {
-                                return Err(NonLinearControlFlow::RuntimeError(err))
-                            }
+) -> RuntimeResult<'source, Value<'source>> {
+    // A generator which suspended in the middle of an iteration resumes
+    // straight into that iteration. `iterations_remaining` is None if the loop
+    // is infinite
+    let (mut iterations_remaining, mut resuming) = match state.take_resume_point() {
+        Some(ResumePoint::Loop(iterations_remaining)) => (iterations_remaining, true),
+        Some(resume_point) => panic!("expected to resume in a loop, got {resume_point:?}"),
+        None => {
+            let iterations = match simple_loop.condition() {
+                Some(condition) => match interpret_expression(condition, state)? {
+                    Value::Int(iterations) => {
+                        // TODO what should even happen here?
+                        // Hi from future me: this should *definitely* be a RuntimeError
+                        if iterations.is_negative() {
+                            todo!();
                         }
-                    }
 
-                    state.pop_scope();
-                    iterations_remaining -= 1;
-                }
-            }
-            Value::Byte(iterations) => {
-                'icelang_loop: for _ in 0..iterations {
-                    state.push_scope();
-
-                    for statement in simple_loop.body() {
-                        match interpret_statement(statement, state) {
-                            Ok(()) => {}
-                            Err(NonLinearControlFlow::JumpStatement(jump_statement)) => {
-                                match jump_statement.kind() {
-                                    JumpStatementKind::Break => break 'icelang_loop,
-                                    JumpStatementKind::Continue => break,
-                                    JumpStatementKind::Return => {
-                                        return Err(NonLinearControlFlow::JumpStatement(
-                                            jump_statement,
-                                        ));
-                                    }
-                                }
-                            }
-                            Err(NonLinearControlFlow::RuntimeError(err)) => {
-                                return Err(NonLinearControlFlow::RuntimeError(err))
-                            }
-                        }
+                        Some(iterations)
                     }
+                    Value::Byte(iterations) => Some(BigInt::from(iterations)),
+                    _ => todo!(),
+                },
+                None => None,
+            };
 
-                    state.pop_scope();
-                }
-            }
-            _ => todo!(),
-        },
-        None => 'icelang_loop: loop {
-            state.push_scope();
-
-            for statement in simple_loop.body() {
-                match interpret_statement(statement, state) {
-                    Ok(()) => {}
-                    Err(NonLinearControlFlow::JumpStatement(jump_statement)) => {
-                        match jump_statement.kind() {
-                            JumpStatementKind::Break => break 'icelang_loop,
-                            JumpStatementKind::Continue => break,
-                            JumpStatementKind::Return => {
-                                return Err(NonLinearControlFlow::JumpStatement(jump_statement));
-                            }
-                        }
-                    }
-                    Err(NonLinearControlFlow::RuntimeError(err)) => {
-                        return Err(NonLinearControlFlow::RuntimeError(err))
-                    }
-                }
-            }
+            (iterations, false)
+        }
+    };
 
-            state.pop_scope();
-        },
+    loop {
+        if !resuming
+            && iterations_remaining
+                .as_ref()
+                .is_some_and(|iterations_remaining| !iterations_remaining.is_positive())
+        {
+            break;
+        }
+        resuming = false;
+
+        let control =
+            interpret_loop_iteration(simple_loop.body(), simple_loop.label(), state, |_| Ok(()))
+                .map_err(|err| err.resume_at(|| ResumePoint::Loop(iterations_remaining.clone())))?;
+        if let LoopControl::Break(value) = control {
+            return Ok(value);
+        }
+
+        if let Some(iterations_remaining) = &mut iterations_remaining {
+            *iterations_remaining -= 1;
+        }
     }
 
-    Ok(())
+    Ok(Value::Null)
 }
 
-/// Interprets an AstNodeWhileLoop
+/// Interprets an AstNodeWhileLoop, returning the value it breaks with (or
+/// null, if it finishes without breaking)
 pub fn interpret_while_loop<'source>(
     while_loop: &AstNodeWhileLoop<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, ()> {
-    'icelang_loop: loop {
-        match interpret_expression(while_loop.condition(), state)? {
-            Value::Bool(condition_value) => {
-                if !condition_value {
-                    break;
-                }
+) -> RuntimeResult<'source, Value<'source>> {
+    // A generator which suspended in the middle of an iteration resumes
+    // straight into that iteration, without checking the condition again
+    let mut resuming = match state.take_resume_point() {
+        Some(ResumePoint::Loop(None)) => true,
+        Some(resume_point) => panic!("expected to resume in a loop, got {resume_point:?}"),
+        None => false,
+    };
 
-                state.push_scope();
-
-                for statement in while_loop.body() {
-                    match interpret_statement(statement, state) {
-                        Ok(()) => {}
-                        Err(NonLinearControlFlow::JumpStatement(jump_statement)) => {
-                            match jump_statement.kind() {
-                                JumpStatementKind::Break => break 'icelang_loop,
-                                JumpStatementKind::Continue => break,
-                                JumpStatementKind::Return => {
-                                    return Err(NonLinearControlFlow::JumpStatement(
-                                        jump_statement,
-                                    ));
-                                }
-                            }
-                        }
-                        Err(NonLinearControlFlow::RuntimeError(err)) => {
-                            return Err(NonLinearControlFlow::RuntimeError(err))
-                        }
+    loop {
+        if !resuming {
+            match interpret_expression(while_loop.condition(), state)? {
+                Value::Bool(condition_value) => {
+                    if !condition_value {
+                        break;
                     }
                 }
-
-                state.pop_scope();
+                _ => todo!(),
             }
-            _ => todo!(),
+        }
+        resuming = false;
+
+        let control =
+            interpret_loop_iteration(while_loop.body(), while_loop.label(), state, |_| Ok(()))
+                .map_err(|err| err.resume_at(|| ResumePoint::Loop(None)))?;
+        if let LoopControl::Break(value) = control {
+            return Ok(value);
         }
     }
 
-    Ok(())
+    Ok(Value::Null)
 }
 
-/// Interprets an AstNodeForLoop
+/// Interprets an AstNodeForLoop, returning the value it breaks with (or null,
+/// if it finishes without breaking)
 pub fn interpret_for_loop<'source>(
     for_loop: &AstNodeForLoop<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, ()> {
-    let iterable: Vec<Value> = match interpret_expression(for_loop.iterable(), state)? {
+) -> RuntimeResult<'source, Value<'source>> {
+    // A generator which suspended in the middle of an iteration resumes
+    // straight into that iteration, with the rest of the loop's values
+    let (mut iterator, mut resuming) = match state.take_resume_point() {
+        Some(ResumePoint::ForLoop(iterator)) => (iterator, true),
+        Some(resume_point) => panic!("expected to resume in a for loop, got {resume_point:?}"),
+        None => {
+            let iterator = for_loop_iterator(
+                for_loop.iterable(),
+                for_loop.pattern(),
+                for_loop.pos(),
+                state,
+            )?;
+            (iterator, false)
+        }
+    };
+
+    loop {
+        // The value of a resumed iteration was already declared
+        let value = if resuming {
+            Value::Null
+        } else {
+            match next_for_loop_value(&mut iterator, for_loop.pos(), state)? {
+                Some(value) => value,
+                None => break,
+            }
+        };
+        resuming = false;
+
+        let result = interpret_loop_iteration(for_loop.body(), for_loop.label(), state, |state| {
+            declare_pattern(for_loop.pattern(), value, false, state)
+        });
+        let control = match result {
+            Ok(control) => control,
+            Err(err @ NonLinearControlFlow::Yield(_)) => {
+                return Err(err.resume_at(|| ResumePoint::ForLoop(iterator)));
+            }
+            Err(err) => return Err(err),
+        };
+        if let LoopControl::Break(value) = control {
+            return Ok(value);
+        }
+    }
+
+    Ok(Value::Null)
+}
+
+/// Evaluates the iterable of a for loop (or of a `for` clause in a
+/// comprehension), and returns an iterator over the values the loop should
+/// iterate over. `pattern` is the loop-control variable pattern of the loop
+/// (which decides how a dict is iterated), and `pos` is the position of the
+/// loop
+pub fn for_loop_iterator<'source>(
+    iterable: &AstNode<'source>,
+    pattern: &DestructuringPattern<'source>,
+    pos: &SourceRange<'source>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, ForLoopIterator<'source>> {
+    let iterable = interpret_expression(iterable, state)?;
+    let entries = matches!(pattern, DestructuringPattern::List { .. });
+
+    iterate_value(iterable, entries, pos, state)
+}
+
+/// Returns an iterator over the values a for loop iterates over when given
+/// `iterable`. A dict is iterated by its entries (as `[key, value]` lists) if
+/// `entries` is true, and by its keys otherwise
+pub fn iterate_value<'source>(
+    iterable: Value<'source>,
+    entries: bool,
+    pos: &SourceRange<'source>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, ForLoopIterator<'source>> {
+    Ok(ForLoopIterator::Values(match iterable {
         // Iterating through a string visits each character
-        Value::String(string) => string
-            .chars()
-            .map(|c| Value::String(c.to_string().into()))
-            .collect(),
+        Value::String(string) => Box::new(
+            string
+                .chars()
+                .map(|c| Value::String(c.to_string().into()))
+                .collect::<Vec<_>>()
+                .into_iter(),
+        ),
 
         // If we're iterating over a list, take a snapshot of the list as it is
         // at the start of the loop - mutations of the iterated list shouldn't
         // be reflected in the for loop's iterations
-        Value::List(list) => list.borrow().iter().map(Value::reference_copy).collect(),
+        Value::List(list) => Box::new(
+            list.borrow()
+                .iter()
+                .map(Value::reference_copy)
+                .collect::<Vec<_>>()
+                .into_iter(),
+        ),
+
+        // Iterating through a dict visits each of its keys, or each of its
+        // entries (as `[key, value]` lists) if the loop-control variables
+        // are a list pattern, either written out (like `for [k, v] in ...`)
+        // or from several comma-separated variables (like `for k, v in
+        // ...`). Like lists, dicts are snapshotted at the start of the loop
+        Value::Dict(dict) => {
+            let dict = dict.borrow();
+            let values: Vec<_> = match entries {
+                true => dict
+                    .iter()
+                    .map(|(key, value)| {
+                        Value::List(Rc::new(RefCell::new(
+                            vec![key.reference_copy(), value.reference_copy()].into(),
+                        )))
+                    })
+                    .collect(),
+                false => dict.keys().map(Value::reference_copy).collect(),
+            };
+
+            Box::new(values.into_iter())
+        }
+
+        // Iterating through a set visits each of its elements. Like lists,
+        // sets are snapshotted at the start of the loop
+        Value::Set(set) => Box::new(
+            set.borrow()
+                .iter()
+                .map(Value::reference_copy)
+                .collect::<Vec<_>>()
+                .into_iter(),
+        ),
+
+        // Ranges are iterated lazily, so that even huge ranges don't need to
+        // be stored in memory
+        Value::Range(range) => Box::new(range.iter().map(Value::Int)),
+
+        // Generators are resumed once per iteration
+        Value::Generator(generator) => return Ok(ForLoopIterator::Generator(generator)),
 
         value => {
             return Err(NonLinearControlFlow::RuntimeError(
                 RuntimeError::new_type_error(
-                    for_loop.pos().clone(),
+                    pos.clone(),
                     state.scope_display_name().to_string(),
                     format!(
-                        "expected string or list, got value of type {}",
-                        value.icelang_type()
-                    ),
+                    "expected string, list, dict, set, range, or generator, got value of type {}",
+                    value.icelang_type()
+                ),
                 ),
             ))
         }
-    };
+    }))
+}
 
-    'icelang_loop: for value in iterable {
-        state.push_scope();
+/// Gets the next value for a for loop to iterate over, or None if there are no
+/// more values
+pub fn next_for_loop_value<'source>(
+    iterator: &mut ForLoopIterator<'source>,
+    pos: &SourceRange<'source>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, Option<Value<'source>>> {
+    match iterator {
+        ForLoopIterator::Values(values) => Ok(values.next()),
+        ForLoopIterator::Generator(generator) => resume_generator(generator, pos, state),
+    }
+}
 
-        state.declare_variable(for_loop.ident().to_string(), value);
-
-        for statement in for_loop.body() {
-            match interpret_statement(statement, state) {
-                Ok(()) => {}
-                Err(NonLinearControlFlow::JumpStatement(jump_statement)) => {
-                    match jump_statement.kind() {
-                        JumpStatementKind::Break => break 'icelang_loop,
-                        JumpStatementKind::Continue => break,
-                        JumpStatementKind::Return => {
-                            return Err(NonLinearControlFlow::JumpStatement(jump_statement));
-                        }
-                    }
-                }
-                Err(NonLinearControlFlow::RuntimeError(err)) => {
-                    return Err(NonLinearControlFlow::RuntimeError(err))
-                }
-            }
-        }
+/// Interprets an AstNodeIfElseStatement, returning the value of the branch
+/// that ran (or null, if no branch ran)
+pub fn interpret_if_else_statement<'source>(
+    if_else_statement: &AstNodeIfElseStatement<'source>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, Value<'source>> {
+    let conditional_branches = if_else_statement.conditional_branches();
+
+    // A generator which suspended in one of the branches resumes straight
+    // into that branch, without evaluating any conditions again. The else
+    // branch comes after all of the conditional branches
+    let branch_index = match state.take_resume_point() {
+        Some(ResumePoint::Branch(index)) => Some(index),
+        Some(resume_point) => panic!("expected to resume in a branch, got {resume_point:?}"),
+        None => find_if_else_branch(if_else_statement, state)?,
+    };
 
-        state.pop_scope();
-    }
+    let Some(branch_index) = branch_index else {
+        return Ok(Value::Null);
+    };
+    let body = match conditional_branches.get(branch_index) {
+        Some((_, body)) => body,
+        None => if_else_statement
+            .else_branch()
+            .expect("the else branch should exist"),
+    };
 
-    Ok(())
+    interpret_code_block(body, state)
+        .map_err(|err| err.resume_at(|| ResumePoint::Branch(branch_index)))
 }
 
-/// Interprets an AstNodeIfElseStatement
-pub fn interpret_if_else_statement<'source>(
+/// Evaluates the conditions of an AstNodeIfElseStatement, returning the index
+/// of the branch which should run (where the else branch comes after all of
+/// the conditional branches), or None if no branch should run
+fn find_if_else_branch<'source>(
     if_else_statement: &AstNodeIfElseStatement<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, ()> {
-    for (condition, body) in if_else_statement.conditional_branches() {
+) -> RuntimeResult<'source, Option<usize>> {
+    for (index, (condition, _)) in if_else_statement.conditional_branches().iter().enumerate() {
         // Evaluate the condition
         let condition_value = interpret_expression(condition, state)?;
 
@@ -237,49 +388,180 @@ pub fn interpret_if_else_statement<'source>(
         };
 
         if condition_value {
-            state.push_scope();
-
-            for statement in body {
-                interpret_statement(statement, state)?;
-            }
-
-            state.pop_scope();
-
-            return Ok(());
+            return Ok(Some(index));
         }
     }
 
-    if let Some(else_branch_body) = if_else_statement.else_branch() {
-        for statement in else_branch_body {
-            interpret_statement(statement, state)?;
-        }
-    }
-
-    Ok(())
+    Ok(if_else_statement
+        .else_branch()
+        .map(|_| if_else_statement.conditional_branches().len()))
 }
 
-/// Interprets an AstNodeWhileLoop
+/// Interprets an AstNodeMatchStatement, returning the value of the arm that
+/// ran (or null, if no arm matched)
 pub fn interpret_match_statement<'source>(
     match_statement: &AstNodeMatchStatement<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, ()> {
+) -> RuntimeResult<'source, Value<'source>> {
+    // A generator which suspended in one of the arms resumes straight into
+    // that arm, without matching anything again
+    match state.take_resume_point() {
+        Some(ResumePoint::Branch(index)) => {
+            let depth = state.call_stack_depth();
+
+            push_resumable_scope(state);
+            let result = interpret_match_arm_body(match_statement, index, state);
+            state.unwind_call_stack(depth);
+
+            return result;
+        }
+        Some(resume_point) => panic!("expected to resume in a match arm, got {resume_point:?}"),
+        None => {}
+    }
+
     let matched_value = interpret_expression(match_statement.matched_expression(), state)?;
 
-    for arm in match_statement.arms() {
-        let pattern_value = interpret_expression(arm.pattern(), state)?;
-        // TODO use icelang eq instead of Rust eq
-        if matched_value == pattern_value {
-            state.push_scope();
+    for (index, arm) in match_statement.arms().iter().enumerate() {
+        let mut bindings = Vec::new();
+        if !matches_pattern(arm.pattern(), &matched_value, state, &mut bindings)? {
+            continue;
+        }
+
+        let depth = state.call_stack_depth();
+        state.push_scope();
 
-            for statement in arm.body() {
-                interpret_statement(statement, state)?;
+        for (ident, value) in bindings {
+            state.declare_variable(ident.to_string(), value);
+        }
+
+        // The guard is evaluated with the pattern's bindings in scope
+        let guard_passed = match arm.guard() {
+            Some(guard) => {
+                interpret_expression(guard, state).and_then(|guard_value| match guard_value {
+                    Value::Bool(guard_value) => Ok(guard_value),
+                    guard_value => Err(NonLinearControlFlow::RuntimeError(
+                        RuntimeError::new_type_error(
+                            guard.pos().clone(),
+                            state.scope_display_name().to_string(),
+                            format!(
+                                "expected bool, got value of type {}",
+                                guard_value.icelang_type()
+                            ),
+                        ),
+                    )),
+                })
             }
+            None => Ok(true),
+        };
+        let result = match guard_passed {
+            Ok(true) => interpret_match_arm_body(match_statement, index, state).map(Some),
+            Ok(false) => Ok(None),
+            Err(err) => Err(err),
+        };
 
-            state.pop_scope();
+        // However the arm ended, the scope it pushed is gone now
+        state.unwind_call_stack(depth);
 
-            break;
+        if let Some(value) = result? {
+            return Ok(value);
         }
     }
 
-    Ok(())
+    Ok(Value::Null)
+}
+
+/// Interprets the body of the match arm with the given index, in the arm's
+/// (already pushed) scope
+fn interpret_match_arm_body<'source>(
+    match_statement: &AstNodeMatchStatement<'source>,
+    index: usize,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, Value<'source>> {
+    interpret_code_block_statements(match_statement.arms()[index].body(), state).map_err(|err| {
+        err.resume_at(|| ResumePoint::Scope(state.innermost_scope()))
+            .resume_at(|| ResumePoint::Branch(index))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{run_icelang, run_with_error_of};
+
+    #[test]
+    fn test_if_else_expressions() {
+        run_icelang(
+            r#"
+            let chosen = if 1 > 2 { "a" } else if true { "b" } else { "c" };
+            assert(chosen == "b");
+            assert((if false { 1 }) == null);
+            assert((if true { let unused = 1; }) == null);
+            assert((if true { }) == null);
+            assert(1 + (if true { 1 } else { 2 }) == 2);
+            "#,
+        )
+        .unwrap();
+
+        let err = run_icelang("let a = 1 + if true { 1 } else { 2 };").unwrap_err();
+        assert!(err.contains("expected expression"));
+    }
+
+    #[test]
+    fn test_match_expressions() {
+        run_with_error_of(
+            r#"
+            let description = match 5 { 0..5 => { "low" }, n => { f"high {n}" } };
+            assert(description == "high 5");
+            assert((match 1 { 2 => { "two" } }) == null);
+
+            // A return inside a control flow expression returns from the
+            // function
+            fn sign(x) {
+                let sign = match x {
+                    int => { if x > 0 { "positive" } else { return "not positive"; } },
+                    _ => { "not an int" },
+                };
+                return sign;
+            };
+            assert(sign(1) == "positive" && sign(-1) == "not positive" && sign("1") == "not an int");
+
+            // But not from the body of another jump statement
+            fn nested(x) {
+                return if x { return 1; } else { 2 };
+            };
+            assert(error_of(fn() { nested(true); }) == "invalid_jump_statement: cannot return from the body of another jump statement");
+            assert(nested(false) == 2);
+            "#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_loop_expressions() {
+        run_icelang(
+            r#"
+            assert((for x in [3, 8, 12] { if x > 5 { break x; }; }) == 8);
+            assert((for x in [1] { }) == null);
+            assert((loop 2 { break; }) == null);
+
+            let count = 0;
+            assert((while true { count += 1; if count == 3 { break count * 10; }; }) == 30);
+
+            let pair = 'outer: for i in 0..3 {
+                for j in 0..3 {
+                    if i * j == 2 {
+                        break 'outer f"{i}, {j}";
+                    };
+                };
+            };
+            assert(pair == "1, 2");
+
+            let nested = 'outer: loop {
+                let inner = loop { break 7; };
+                break 'outer inner + 1;
+            };
+            assert(nested == 8);
+            "#,
+        )
+        .unwrap();
+    }
 }
@@ -1,14 +1,24 @@
 use super::{
     assignments::interpret_assignment,
     comparisons::interpret_comparison,
+    comprehensions::{interpret_dict_comprehension, interpret_list_comprehension},
     control_flow::{
         interpret_for_loop, interpret_if_else_statement, interpret_match_statement,
         interpret_simple_loop, interpret_while_loop,
     },
-    functions::{interpret_function_call, interpret_function_declaration},
+    destructuring::interpret_destructuring_assignment,
+    error_handling::{interpret_throw, interpret_try_catch},
+    functions::{
+        interpret_anonymous_function, interpret_function_call, interpret_function_declaration,
+    },
+    generators::{interpret_yield, push_resumable_scope},
+    imports::interpret_import,
     inline_conditionals::interpret_inline_conditional,
     jump_statement::JumpStatement,
-    member_access::{interpret_computed_member_access, interpret_dot_member_access},
+    member_access::{
+        interpret_computed_member_access, interpret_dot_member_access, interpret_slice,
+    },
+    records::{interpret_record_declaration, interpret_record_literal},
     runtime_result::RuntimeResult,
     unary_operations::interpret_unary_operation,
     variables::{interpret_variable_access, interpret_variable_declaration},
@@ -18,6 +28,7 @@ use super::{
 use crate::{
     ast::{Ast, AstNode},
     error::runtime_error::RuntimeError,
+    generator::ResumePoint,
     runtime_state::RuntimeState,
     value::Value,
 };
@@ -29,22 +40,35 @@ use crate::{
 pub fn interpret_expression<'source>(
     expression: &AstNode<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match expression {
         AstNode::VariableAccess(node) => interpret_variable_access(node, state),
+        AstNode::AnonymousFunction(node) => Ok(interpret_anonymous_function(node, state)),
         AstNode::Literal(node) => Ok(interpret_literal(node)),
         AstNode::ListLiteral(node) => interpret_literal_list(node, state),
+        AstNode::ListComprehension(node) => interpret_list_comprehension(node, state),
         AstNode::FormattedStringLiteral(node) => interpret_formatted_string_literal(node, state),
         AstNode::DictLiteral(node) => interpret_literal_dict(node, state),
+        AstNode::DictComprehension(node) => interpret_dict_comprehension(node, state),
+        AstNode::SetLiteral(node) => interpret_literal_set(node, state),
+        AstNode::RecordLiteral(node) => interpret_record_literal(node, state),
+        AstNode::RangeLiteral(node) => interpret_literal_range(node, state),
         AstNode::TypeCast(node) => interpret_type_cast(node, state),
-        AstNode::DotMemberAccess(node) => interpret_dot_member_access(node, state),
-        AstNode::ComputedMemberAccess(node) => interpret_computed_member_access(node, state),
+        AstNode::DotMemberAccess(node) => interpret_dot_member_access(node, false, state),
+        AstNode::ComputedMemberAccess(node) => interpret_computed_member_access(node, false, state),
+        AstNode::Slice(node) => interpret_slice(node, state),
         AstNode::FunctionCall(node) => interpret_function_call(node, state),
         AstNode::BinaryOperation(node) => interpret_binary_operation(node, state),
         AstNode::UnaryOperation(node) => interpret_unary_operation(node, state),
         AstNode::Comparison(node) => interpret_comparison(node, state),
         AstNode::InlineConditional(node) => interpret_inline_conditional(node, state),
         AstNode::Assignment(node) => interpret_assignment(node, state),
+        AstNode::DestructuringAssignment(node) => interpret_destructuring_assignment(node, state),
+        AstNode::SimpleLoop(node) => interpret_simple_loop(node, state),
+        AstNode::WhileLoop(node) => interpret_while_loop(node, state),
+        AstNode::ForLoop(node) => interpret_for_loop(node, state),
+        AstNode::MatchStatement(node) => interpret_match_statement(node, state),
+        AstNode::IfElseStatement(node) => interpret_if_else_statement(node, state),
         _ => panic!("expected expression"),
     }
 }
@@ -64,20 +88,35 @@ pub fn interpret_statement<'source>(
         AstNode::VariableDeclaration(variable_declaration) => {
             interpret_variable_declaration(variable_declaration, state)
         }
+        AstNode::Import(node) => interpret_import(node, state),
+        AstNode::RecordDeclaration(node) => interpret_record_declaration(node, state),
         AstNode::VariableAccess(_)
+        | AstNode::AnonymousFunction(_)
         | AstNode::Literal(_)
         | AstNode::ListLiteral(_)
+        | AstNode::ListComprehension(_)
         | AstNode::FormattedStringLiteral(_)
         | AstNode::DictLiteral(_)
+        | AstNode::DictComprehension(_)
+        | AstNode::SetLiteral(_)
+        | AstNode::RecordLiteral(_)
+        | AstNode::RangeLiteral(_)
         | AstNode::TypeCast(_)
         | AstNode::DotMemberAccess(_)
         | AstNode::ComputedMemberAccess(_)
+        | AstNode::Slice(_)
         | AstNode::FunctionCall(_)
         | AstNode::BinaryOperation(_)
         | AstNode::UnaryOperation(_)
         | AstNode::Comparison(_)
         | AstNode::InlineConditional(_)
-        | AstNode::Assignment(_) => {
+        | AstNode::Assignment(_)
+        | AstNode::DestructuringAssignment(_)
+        | AstNode::SimpleLoop(_)
+        | AstNode::WhileLoop(_)
+        | AstNode::ForLoop(_)
+        | AstNode::MatchStatement(_)
+        | AstNode::IfElseStatement(_) => {
             let value = interpret_expression(statement, state)?;
 
             state.update_most_recent_value(value);
@@ -101,21 +140,152 @@ pub fn interpret_statement<'source>(
                     Err(NonLinearControlFlow::RuntimeError(err)) => {
                         return Err(NonLinearControlFlow::RuntimeError(err));
                     }
+                    Err(NonLinearControlFlow::Throw(thrown_value)) => {
+                        return Err(NonLinearControlFlow::Throw(thrown_value));
+                    }
+                    Err(NonLinearControlFlow::Yield(suspension)) => {
+                        return Err(NonLinearControlFlow::Yield(suspension));
+                    }
                 },
                 None => None,
             };
-            let jump_statement = JumpStatement::new(node.jump_kind(), body, node.pos().clone());
+            let jump_statement = JumpStatement::new(
+                node.jump_kind(),
+                body,
+                node.label().map(str::to_string),
+                node.pos().clone(),
+            );
 
             Err(NonLinearControlFlow::JumpStatement(jump_statement))
         }
-        AstNode::SimpleLoop(node) => interpret_simple_loop(node, state),
-        AstNode::WhileLoop(node) => interpret_while_loop(node, state),
-        AstNode::ForLoop(node) => interpret_for_loop(node, state),
-        AstNode::MatchStatement(node) => interpret_match_statement(node, state),
-        AstNode::IfElseStatement(node) => interpret_if_else_statement(node, state),
+        AstNode::TryCatch(node) => interpret_try_catch(node, state),
+        AstNode::Throw(node) => interpret_throw(node, state),
+        AstNode::Yield(node) => interpret_yield(node, state),
+        AstNode::Spread(_) => unreachable!(
+            "spreads should only be used in list literals, dict literals, and function calls"
+        ),
+    }
+}
+
+/// Returns whether or not an AstNode is an expression (and so has a value)
+fn is_expression(node: &AstNode) -> bool {
+    match node {
+        AstNode::VariableAccess(_)
+        | AstNode::AnonymousFunction(_)
+        | AstNode::Literal(_)
+        | AstNode::ListLiteral(_)
+        | AstNode::ListComprehension(_)
+        | AstNode::FormattedStringLiteral(_)
+        | AstNode::DictLiteral(_)
+        | AstNode::DictComprehension(_)
+        | AstNode::SetLiteral(_)
+        | AstNode::RecordLiteral(_)
+        | AstNode::RangeLiteral(_)
+        | AstNode::TypeCast(_)
+        | AstNode::DotMemberAccess(_)
+        | AstNode::ComputedMemberAccess(_)
+        | AstNode::Slice(_)
+        | AstNode::FunctionCall(_)
+        | AstNode::BinaryOperation(_)
+        | AstNode::UnaryOperation(_)
+        | AstNode::Comparison(_)
+        | AstNode::InlineConditional(_)
+        | AstNode::Assignment(_)
+        | AstNode::DestructuringAssignment(_)
+        | AstNode::SimpleLoop(_)
+        | AstNode::WhileLoop(_)
+        | AstNode::ForLoop(_)
+        | AstNode::MatchStatement(_)
+        | AstNode::IfElseStatement(_) => true,
+        AstNode::FunctionDeclaration(_)
+        | AstNode::VariableDeclaration(_)
+        | AstNode::Import(_)
+        | AstNode::RecordDeclaration(_)
+        | AstNode::JumpStatement(_)
+        | AstNode::TryCatch(_)
+        | AstNode::Throw(_)
+        | AstNode::Yield(_)
+        | AstNode::Spread(_) => false,
     }
 }
 
+/// Interprets the statements of a code block in the current scope, returning
+/// the value of the block: the value of its last statement if that is an
+/// expression, or null otherwise
+///
+/// # Panics
+/// - If any of the statements are invalid in any way
+pub fn interpret_code_block_statements<'source>(
+    body: &[AstNode<'source>],
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, Value<'source>> {
+    interpret_resumable_statements(body, true, state)
+}
+
+/// Interprets a list of statements in the current scope
+///
+/// # Panics
+/// - If any of the statements are invalid in any way
+pub fn interpret_statements<'source>(
+    body: &[AstNode<'source>],
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, ()> {
+    interpret_resumable_statements(body, false, state).map(|_| ())
+}
+
+/// Interprets a list of statements in the current scope, starting from the
+/// statement a resuming generator suspended in (if any). If `use_last_value` is
+/// true, the value of the last statement is returned if it's an expression.
+/// Otherwise, null is returned
+///
+/// # Panics
+/// - If any of the statements are invalid in any way
+fn interpret_resumable_statements<'source>(
+    body: &[AstNode<'source>],
+    use_last_value: bool,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, Value<'source>> {
+    let start = match state.take_resume_point() {
+        Some(ResumePoint::Statement(index)) => index,
+        Some(resume_point) => panic!("expected to resume at a statement, got {resume_point:?}"),
+        None => 0,
+    };
+
+    let mut value = Value::Null;
+    for (index, statement) in body.iter().enumerate().skip(start) {
+        let result = if use_last_value && index == body.len() - 1 && is_expression(statement) {
+            interpret_expression(statement, state)
+        } else {
+            interpret_statement(statement, state).map(|()| Value::Null)
+        };
+
+        value = result.map_err(|err| err.resume_at(|| ResumePoint::Statement(index)))?;
+    }
+
+    Ok(value)
+}
+
+/// Interprets a code block in a new scope, returning the value of the block
+/// (see `interpret_code_block_statements`)
+///
+/// # Panics
+/// - If any of the statements are invalid in any way
+pub fn interpret_code_block<'source>(
+    body: &[AstNode<'source>],
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, Value<'source>> {
+    let depth = state.call_stack_depth();
+
+    push_resumable_scope(state);
+    let result = interpret_code_block_statements(body, state)
+        .map_err(|err| err.resume_at(|| ResumePoint::Scope(state.innermost_scope())));
+
+    // However the block ended, the scope it pushed is gone now
+    state.unwind_call_stack(depth);
+
+    result
+}
+
 /// Interprets an AST with the given runtime state
 ///
 /// # Panics
@@ -138,6 +308,12 @@ pub fn interpret_with_runtime_state<'source>(
                 ))
             }
             Err(NonLinearControlFlow::RuntimeError(err)) => return Err(err),
+            Err(NonLinearControlFlow::Throw(thrown_value)) => {
+                return Err(thrown_value.into_runtime_error())
+            }
+            Err(NonLinearControlFlow::Yield(_)) => {
+                unreachable!("`yield` should only be used in functions")
+            }
         }
     }
 
@@ -0,0 +1,242 @@
+use std::{cell::RefCell, rc::Rc};
+
+use indexmap::IndexMap;
+
+use crate::{
+    ast::{AstNode, AstNodeDestructuringAssignment, DestructuringPattern},
+    error::runtime_error::RuntimeError,
+    runtime_state::RuntimeState,
+    value::Value,
+};
+
+use super::{
+    assignments::assign_to_lvalue,
+    core::interpret_expression,
+    runtime_result::{NonLinearControlFlow, RuntimeResult},
+};
+
+/// Destructures a value according to a pattern, calling `bind` with each
+/// target of the pattern and the part of the value it receives
+///
+/// The parts of the value are all found before any of them are bound, so
+/// binding one target can't change what another target receives
+pub fn destructure<'source>(
+    pattern: &DestructuringPattern<'source>,
+    value: Value<'source>,
+    state: &mut RuntimeState<'source>,
+    bind: &mut impl FnMut(
+        &AstNode<'source>,
+        Value<'source>,
+        &mut RuntimeState<'source>,
+    ) -> RuntimeResult<'source, ()>,
+) -> RuntimeResult<'source, ()> {
+    let mut parts = Vec::new();
+    split_by_pattern(pattern, value, state, &mut parts)?;
+
+    parts
+        .into_iter()
+        .try_for_each(|(target, value)| bind(target, value, state))
+}
+
+/// Splits a value into the parts received by each target of a pattern, in the
+/// order the targets appear in the pattern
+fn split_by_pattern<'a, 'source>(
+    pattern: &'a DestructuringPattern<'source>,
+    value: Value<'source>,
+    state: &mut RuntimeState<'source>,
+    parts: &mut Vec<(&'a AstNode<'source>, Value<'source>)>,
+) -> RuntimeResult<'source, ()> {
+    match pattern {
+        DestructuringPattern::Target(target) => parts.push((target, value)),
+        DestructuringPattern::List {
+            elements,
+            rest,
+            pos,
+        } => {
+            let Value::List(list) = value else {
+                return Err(NonLinearControlFlow::RuntimeError(
+                    RuntimeError::new_type_error(
+                        pos.clone(),
+                        state.scope_display_name().to_string(),
+                        format!(
+                            "cannot destructure value of type {} with a list pattern",
+                            value.icelang_type()
+                        ),
+                    ),
+                ));
+            };
+            let mut values: Vec<Value<'source>> =
+                list.borrow().iter().map(Value::reference_copy).collect();
+
+            match rest {
+                None => {
+                    if values.len() != elements.len() {
+                        return Err(NonLinearControlFlow::RuntimeError(
+                            RuntimeError::new_destructuring_error(
+                                pos.clone(),
+                                state.scope_display_name().to_string(),
+                                format!(
+                                    "list pattern expects {} element{}, but the list has {}",
+                                    elements.len(),
+                                    match elements.len() {
+                                        1 => "",
+                                        _ => "s",
+                                    },
+                                    values.len()
+                                ),
+                            ),
+                        ));
+                    }
+
+                    for (element, value) in elements.iter().zip(values) {
+                        split_by_pattern(element, value, state, parts)?;
+                    }
+                }
+                Some((rest_index, rest)) => {
+                    if values.len() < elements.len() {
+                        return Err(NonLinearControlFlow::RuntimeError(
+                            RuntimeError::new_destructuring_error(
+                                pos.clone(),
+                                state.scope_display_name().to_string(),
+                                format!(
+                                    "list pattern expects at least {} element{}, but the list has {}",
+                                    elements.len(),
+                                    match elements.len() {
+                                        1 => "",
+                                        _ => "s",
+                                    },
+                                    values.len()
+                                ),
+                            ),
+                        ));
+                    }
+
+                    // The rest element receives everything between the
+                    // elements before it and the elements after it
+                    let after_values =
+                        values.split_off(values.len() - (elements.len() - rest_index));
+                    let rest_values = values.split_off(*rest_index);
+                    let before_values = values;
+
+                    for (element, value) in elements[..*rest_index].iter().zip(before_values) {
+                        split_by_pattern(element, value, state, parts)?;
+                    }
+                    split_by_pattern(
+                        rest,
+                        Value::List(Rc::new(RefCell::new(rest_values.into()))),
+                        state,
+                        parts,
+                    )?;
+                    for (element, value) in elements[*rest_index..].iter().zip(after_values) {
+                        split_by_pattern(element, value, state, parts)?;
+                    }
+                }
+            }
+        }
+        DestructuringPattern::Dict { entries, rest, pos } => {
+            let Value::Dict(dict) = value else {
+                return Err(NonLinearControlFlow::RuntimeError(
+                    RuntimeError::new_type_error(
+                        pos.clone(),
+                        state.scope_display_name().to_string(),
+                        format!(
+                            "cannot destructure value of type {} with a dict pattern",
+                            value.icelang_type()
+                        ),
+                    ),
+                ));
+            };
+
+            // Look up every entry before destructuring any of them
+            let mut entry_values = Vec::with_capacity(entries.len());
+            for (key_node, entry_pattern) in entries {
+                let key = interpret_expression(key_node, state)?;
+                let Some(value) = dict.borrow().get(&key).map(Value::reference_copy) else {
+                    return Err(NonLinearControlFlow::RuntimeError(
+                        RuntimeError::new_destructuring_error(
+                            key_node.pos().clone(),
+                            state.scope_display_name().to_string(),
+                            format!("key {} does not exist", key.icelang_debug()),
+                        ),
+                    ));
+                };
+                entry_values.push((key, entry_pattern, value));
+            }
+
+            // The rest element receives every entry that wasn't looked up
+            let rest_value = rest.as_ref().map(|rest| {
+                let remaining: IndexMap<_, _> = dict
+                    .borrow()
+                    .iter()
+                    .filter(|(key, _)| {
+                        !entry_values
+                            .iter()
+                            .any(|(looked_up, _, _)| looked_up == *key)
+                    })
+                    .map(|(key, value)| (key.reference_copy(), value.reference_copy()))
+                    .collect();
+                (rest, Value::Dict(Rc::new(RefCell::new(remaining.into()))))
+            });
+
+            for (_, entry_pattern, value) in entry_values {
+                split_by_pattern(entry_pattern, value, state, parts)?;
+            }
+            if let Some((rest, rest_value)) = rest_value {
+                split_by_pattern(rest, rest_value, state, parts)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Declares the variables (or constants, if `is_constant` is true) named by a
+/// pattern (in a variable declaration or for loop), destructuring the value
+/// into them
+pub fn declare_pattern<'source>(
+    pattern: &DestructuringPattern<'source>,
+    value: Value<'source>,
+    is_constant: bool,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, ()> {
+    destructure(pattern, value, state, &mut |target, value, state| {
+        let AstNode::VariableAccess(node) = target else {
+            unreachable!("declaration patterns only contain identifiers");
+        };
+
+        if state.lookup_local_variable(node.ident()).is_some() {
+            return Err(NonLinearControlFlow::RuntimeError(
+                RuntimeError::new_identifier_already_declared_error(
+                    node.pos().clone(),
+                    state.scope_display_name().to_string(),
+                    node.ident().to_string(),
+                ),
+            ));
+        }
+
+        if is_constant {
+            state.declare_constant(node.ident().to_string(), value);
+        } else {
+            state.declare_variable(node.ident().to_string(), value);
+        }
+
+        Ok(())
+    })
+}
+
+/// Interprets an AstNodeDestructuringAssignment
+pub fn interpret_destructuring_assignment<'source>(
+    node: &AstNodeDestructuringAssignment<'source>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, Value<'source>> {
+    let value = interpret_expression(node.rhs(), state)?;
+
+    destructure(
+        node.pattern(),
+        value.reference_copy(),
+        state,
+        &mut |target, value, state| assign_to_lvalue(target, value, state),
+    )?;
+
+    Ok(value)
+}
@@ -0,0 +1,115 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    ast::{AstNodeThrow, AstNodeTryCatch},
+    error::runtime_error::RuntimeError,
+    generator::ResumePoint,
+    runtime_state::RuntimeState,
+    value::Value,
+};
+
+use super::{
+    core::{interpret_expression, interpret_statements},
+    generators::push_resumable_scope,
+    runtime_result::{NonLinearControlFlow, RuntimeResult},
+    thrown_value::ThrownValue,
+};
+
+/// Interprets an AstNodeTryCatch
+pub fn interpret_try_catch<'source>(
+    node: &AstNodeTryCatch<'source>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, ()> {
+    let depth = state.call_stack_depth();
+
+    // A generator which suspended in the catch block resumes straight there
+    let resumed_in_catch_block = match state.take_resume_point() {
+        Some(ResumePoint::TryCatch { in_catch_block }) => Some(in_catch_block),
+        Some(resume_point) => panic!("expected to resume in a try-catch, got {resume_point:?}"),
+        None => None,
+    };
+
+    if resumed_in_catch_block != Some(true) {
+        push_resumable_scope(state);
+        let result = interpret_statements(node.try_body(), state).map_err(|err| {
+            err.resume_at(|| ResumePoint::Scope(state.innermost_scope()))
+                .resume_at(|| ResumePoint::TryCatch {
+                    in_catch_block: false,
+                })
+        });
+
+        // Whatever happened in the try block, any scopes it left behind are
+        // gone now
+        state.unwind_call_stack(depth);
+
+        let caught_value = match result {
+            Ok(()) => return Ok(()),
+            Err(NonLinearControlFlow::RuntimeError(err)) => runtime_error_to_value(&err),
+            Err(NonLinearControlFlow::Throw(thrown_value)) => thrown_value.into_value(),
+            Err(NonLinearControlFlow::JumpStatement(jump_statement)) => {
+                return Err(NonLinearControlFlow::JumpStatement(jump_statement));
+            }
+            Err(NonLinearControlFlow::Yield(suspension)) => {
+                return Err(NonLinearControlFlow::Yield(suspension));
+            }
+        };
+
+        state.push_scope();
+        state.declare_variable(node.error_ident().to_string(), caught_value);
+    } else {
+        push_resumable_scope(state);
+    }
+
+    let result = interpret_statements(node.catch_body(), state).map_err(|err| {
+        err.resume_at(|| ResumePoint::Scope(state.innermost_scope()))
+            .resume_at(|| ResumePoint::TryCatch {
+                in_catch_block: true,
+            })
+    });
+    state.unwind_call_stack(depth);
+
+    result
+}
+
+/// Interprets an AstNodeThrow
+pub fn interpret_throw<'source>(
+    node: &AstNodeThrow<'source>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, ()> {
+    let value = interpret_expression(node.body(), state)?;
+
+    Err(NonLinearControlFlow::Throw(ThrownValue::new(
+        value,
+        node.pos().clone(),
+        state.scope_display_name().to_string(),
+    )))
+}
+
+/// Converts a caught RuntimeError into an icelang dict describing the error
+fn runtime_error_to_value<'source>(err: &RuntimeError<'source>) -> Value<'source> {
+    let stack_trace = err
+        .stack_trace()
+        .frames()
+        .map(|(scope_display_name, pos)| {
+            Value::String(format!("{scope_display_name} {pos}").into())
+        })
+        .collect();
+
+    let fields = [
+        ("kind", Value::String(err.kind_name().into())),
+        ("message", Value::String(err.description().into())),
+        ("file", Value::String(err.pos().source_file_name().into())),
+        ("line", Value::Int(err.pos().start_line().into())),
+        (
+            "stack_trace",
+            Value::List(Rc::new(RefCell::new(stack_trace))),
+        ),
+    ];
+
+    Value::Dict(Rc::new(RefCell::new(
+        fields
+            .into_iter()
+            .map(|(key, value)| (Value::String(key.into()), value))
+            .collect(),
+    )))
+}
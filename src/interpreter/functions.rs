@@ -1,18 +1,26 @@
 use std::{cell::RefCell, rc::Rc};
 
 use crate::{
-    ast::{AstNode, AstNodeFunctionCall, AstNodeFunctionDeclaration, JumpStatementKind},
+    ast::{
+        AstNodeAnonymousFunction, AstNodeFunctionCall, AstNodeFunctionDeclaration,
+        JumpStatementKind,
+    },
     error::runtime_error::RuntimeError,
-    function::FunctionParameters,
+    function::{Function, FunctionGroup, FunctionParameters, FunctionValue},
+    generator::Generator,
     icelang_std_lib::StdLibFunction,
     interpreter::core::interpret_expression,
+    record::Record,
     runtime_state::RuntimeState,
+    source_range::SourceRange,
     value::Value,
 };
 
 use super::{
     core::interpret_statement,
+    member_access::interpret_chain_root,
     runtime_result::{NonLinearControlFlow, RuntimeResult},
+    spreads::interpret_spreadable_expressions,
 };
 
 /// Interprets an AstNodeFunctionDeclaration
@@ -26,14 +34,10 @@ pub fn interpret_function_declaration<'source>(
     let pos = function_declaration.pos().clone();
 
     if let Some(function_group) = state.lookup_local_function(&identifier) {
-        if match &parameters {
-            FunctionParameters::Variadic { parameter_name: _ } => {
-                function_group.get_variadic_overload().is_some()
-            }
-            FunctionParameters::Polyadic { parameters } => function_group
-                .get_polyadic_overload(parameters.len())
-                .is_some(),
-        } {
+        if function_group
+            .get_conflicting_overload(&parameters)
+            .is_some()
+        {
             return Err(NonLinearControlFlow::RuntimeError(
                 RuntimeError::new_identifier_already_declared_error(
                     pos,
@@ -45,13 +49,26 @@ pub fn interpret_function_declaration<'source>(
     }
 
     // Ensure none of the parameter names are the same
-    if let FunctionParameters::Polyadic { parameters } = function_declaration.parameters() {
+    if let FunctionParameters::Polyadic {
+        parameters,
+        rest_parameter,
+    } = function_declaration.parameters()
+    {
+        let parameters: Vec<_> = parameters
+            .iter()
+            .map(|(parameter_name, _, parameter_pos)| (parameter_name, parameter_pos))
+            .chain(
+                rest_parameter
+                    .iter()
+                    .map(|(parameter_name, parameter_pos)| (parameter_name, parameter_pos)),
+            )
+            .collect();
         for (i, (parameter_1_name, parameter_1_pos)) in parameters.iter().enumerate() {
             for (parameter_2_name, _) in parameters.iter().take(i) {
                 if parameter_1_name == parameter_2_name {
                     return Err(NonLinearControlFlow::RuntimeError(
                         RuntimeError::new_identifier_already_declared_error(
-                            parameter_1_pos.clone(),
+                            (*parameter_1_pos).clone(),
                             state.scope_display_name().to_string(),
                             parameter_1_name.to_string(),
                         ),
@@ -66,12 +83,63 @@ pub fn interpret_function_declaration<'source>(
     Ok(())
 }
 
+/// Interprets an AstNodeAnonymousFunction, capturing the current environment
+pub fn interpret_anonymous_function<'source>(
+    anonymous_function: &AstNodeAnonymousFunction<'source>,
+    state: &mut RuntimeState<'source>,
+) -> Value<'source> {
+    let mut function_group = FunctionGroup::new();
+    function_group.add_overload(Function::new(
+        anonymous_function.parameters().clone(),
+        anonymous_function.body().clone(),
+        state.capture_environment(),
+        anonymous_function.pos().clone(),
+    ));
+
+    Value::Function(FunctionValue::UserDefined {
+        name: None,
+        function_group: Rc::new(function_group),
+    })
+}
+
+/// Looks up a function by name, returning it as a function value. Standard
+/// library functions take precedence over user-defined functions
+pub fn lookup_function_value<'source>(
+    identifier: &str,
+    state: &RuntimeState<'source>,
+) -> Option<FunctionValue<'source>> {
+    if let Some(std_lib_function) = StdLibFunction::from_identifier(identifier) {
+        return Some(FunctionValue::StdLib(std_lib_function));
+    }
+
+    state
+        .lookup_function(identifier)
+        .map(|function_group| FunctionValue::UserDefined {
+            name: Some(identifier.into()),
+            function_group: function_group.clone(),
+        })
+}
+
 /// Interprets an AstNodeFunctionCall
 pub fn interpret_function_call<'source>(
     function_call_node: &AstNodeFunctionCall<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
-    let AstNode::VariableAccess(ident_node) = function_call_node.root() else {
+) -> RuntimeResult<'source, Value<'source>> {
+    // Determine which function is being called. Calling a function by name
+    // resolves the name just like any other variable access
+    match interpret_chain_root(function_call_node.root(), state)? {
+        Some(callee) => call_function_value(function_call_node, callee, state),
+        None => Ok(Value::Null),
+    }
+}
+
+/// Calls an already evaluated callee (see `interpret_function_call`)
+pub fn call_function_value<'source>(
+    function_call_node: &AstNodeFunctionCall<'source>,
+    callee: Value<'source>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, Value<'source>> {
+    let Value::Function(function) = callee else {
         return Err(NonLinearControlFlow::RuntimeError(
             RuntimeError::new_called_non_function_error(
                 function_call_node.pos().clone(),
@@ -79,151 +147,396 @@ pub fn interpret_function_call<'source>(
             )
         ));
     };
-    let function_name = ident_node.ident();
-
-    // If the function is a standard library function, intercept the function
-    // call and handle that as a special case
-    if let Some(std_lib_function) = StdLibFunction::from_identifier(function_name) {
-        // Evaluate the arguments
-        let arguments = function_call_node
-            .arguments()
+
+    // Evaluate the arguments
+    let arguments = interpret_spreadable_expressions(function_call_node.arguments(), state)?;
+    let named_arguments: Vec<(String, Value<'source>)> = function_call_node
+        .named_arguments()
+        .iter()
+        .map(|(name, argument_node, _)| {
+            interpret_expression(argument_node, state).map(|value| (name.clone(), value))
+        })
+        .collect::<Result<_, _>>()?;
+
+    call_function(
+        &function,
+        arguments,
+        named_arguments,
+        function_call_node.pos(),
+        state,
+    )
+}
+
+/// Matches positional and named arguments to the parameters with the given
+/// names. Returns the argument given for each parameter (or None if no
+/// argument was given for it) along with any extra positional arguments, or
+/// an explanation of why the arguments don't fit the parameters
+///
+/// Extra positional arguments are only allowed if `has_rest_parameter` is
+/// true
+fn match_arguments<'source>(
+    parameter_names: &[&str],
+    has_rest_parameter: bool,
+    arguments: Vec<Value<'source>>,
+    named_arguments: Vec<(String, Value<'source>)>,
+) -> Result<(Vec<Option<Value<'source>>>, Vec<Value<'source>>), String> {
+    if arguments.len() > parameter_names.len() && !has_rest_parameter {
+        return Err(format!(
+            "takes at most {} positional arguments",
+            parameter_names.len()
+        ));
+    }
+
+    // Match the positional arguments in order
+    let mut arguments = arguments.into_iter();
+    let mut matched_arguments: Vec<Option<Value<'source>>> =
+        parameter_names.iter().map(|_| arguments.next()).collect();
+    let extra_arguments = arguments.collect();
+
+    // Match the named arguments by name
+    for (name, value) in named_arguments {
+        let Some(index) = parameter_names
             .iter()
-            .map(|node| interpret_expression(node, state))
-            .collect::<Result<_, _>>()?;
+            .position(|parameter_name| *parameter_name == name)
+        else {
+            return Err(format!("has no parameter named \"{name}\""));
+        };
+        if matched_arguments[index].is_some() {
+            return Err(format!(
+                "received more than one argument for parameter \"{name}\""
+            ));
+        }
+        matched_arguments[index] = Some(value);
+    }
 
-        // Push a new stack frame
-        state.push_stack_frame(format!("{function_name}(...)"));
+    Ok((matched_arguments, extra_arguments))
+}
+
+/// Binds matched arguments to the local variables for a function's
+/// parameters, in the stack frame of the function. Parameters without an
+/// argument are bound to their default value, which is evaluated in the stack
+/// frame of the function (so it may use the parameters before it)
+fn bind_arguments<'source>(
+    parameters: &FunctionParameters<'source>,
+    matched_arguments: Vec<Option<Value<'source>>>,
+    extra_arguments: Vec<Value<'source>>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, ()> {
+    match parameters {
+        FunctionParameters::Variadic { parameter_name } => {
+            state.declare_variable(
+                parameter_name.0.to_string(),
+                Value::List(Rc::new(RefCell::new(extra_arguments.into()))),
+            );
+        }
+        FunctionParameters::Polyadic {
+            parameters,
+            rest_parameter,
+        } => {
+            assert_eq!(matched_arguments.len(), parameters.len());
 
-        // Call the function
-        let callable = std_lib_function.as_fn_pointer();
-        let return_value = match callable(arguments, function_call_node.pos(), state) {
-            Ok(value) => Ok(value),
-            Err(NonLinearControlFlow::JumpStatement(jump_statement)) => match jump_statement.kind()
+            for ((parameter_name, default_value, _), argument_value) in
+                parameters.iter().zip(matched_arguments)
             {
-                JumpStatementKind::Return => Ok(jump_statement.into_value().unwrap_or(Value::Null)),
-                jump_kind => {
-                    let mut err = RuntimeError::new_invalid_jump_statement_error(
-                        jump_statement.pos().clone(),
-                        state.scope_display_name().to_string(),
-                        jump_kind,
-                        "a function".to_string(),
-                    );
-                    state.pop_stack_frame();
-                    err.stack_trace_mut().add_bottom(
-                        state.scope_display_name().to_string(),
-                        function_call_node.pos().clone(),
-                    );
-                    return Err(NonLinearControlFlow::RuntimeError(err));
-                }
-            },
-            Err(NonLinearControlFlow::RuntimeError(mut err)) => {
-                state.pop_stack_frame();
-                err.stack_trace_mut().add_bottom(
-                    state.scope_display_name().to_string(),
-                    function_call_node.pos().clone(),
-                );
-                return Err(NonLinearControlFlow::RuntimeError(err));
+                let argument_value = match (argument_value, default_value) {
+                    (Some(argument_value), _) => argument_value,
+                    (None, Some(default_value)) => interpret_expression(default_value, state)?,
+                    (None, None) => unreachable!("every parameter should have a value"),
+                };
+                state.declare_variable(parameter_name.clone(), argument_value);
             }
-        };
 
-        // Pop the stack frame
-        state.pop_stack_frame();
-
-        return return_value;
+            match rest_parameter {
+                Some((parameter_name, _)) => state.declare_variable(
+                    parameter_name.clone(),
+                    Value::List(Rc::new(RefCell::new(extra_arguments.into()))),
+                ),
+                None => assert!(extra_arguments.is_empty()),
+            }
+        }
     }
 
-    let Some(function_group) = state.lookup_function(function_name) else {
-        return Err(NonLinearControlFlow::RuntimeError(RuntimeError::new_undefined_reference_error(
-            function_call_node.pos().clone(),
+    Ok(())
+}
+
+/// Calls a function value with the given (already evaluated) positional and
+/// named arguments
+pub fn call_function<'source>(
+    function: &FunctionValue<'source>,
+    arguments: Vec<Value<'source>>,
+    named_arguments: Vec<(String, Value<'source>)>,
+    pos: &SourceRange<'source>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, Value<'source>> {
+    let argument_count = arguments.len() + named_arguments.len();
+    let invalid_overload = |function_name: &str, why: String, state: &RuntimeState<'source>| {
+        NonLinearControlFlow::RuntimeError(RuntimeError::new_invalid_overload_error_with_reason(
+            pos.clone(),
             state.scope_display_name().to_string(),
             function_name.to_string(),
-        )));
+            argument_count,
+            why,
+        ))
     };
 
-    let function = function_group
-        .get_polyadic_overload(function_call_node.arguments().len())
-        .or_else(|| function_group.get_variadic_overload())
-        .ok_or_else(|| {
-            NonLinearControlFlow::RuntimeError(RuntimeError::new_invalid_overload_error(
-                function_call_node.pos().clone(),
-                state.scope_display_name().to_string(),
-                function_name.to_string(),
-                function_call_node.arguments().len(),
-            ))
-        })?
-        // TODO look into ways to avoid this clone - FWIW, I don't think it is
-        // avoidable. It is right now (once declared, a function overload can't
-        // be modified) but this is likely to change once first-class functions
-        // are supported, and then they will be values which probably *have* to
-        // be cloned
-        .clone();
-
-    // Evaluate the arguments
-    let arguments: Vec<Value> = function_call_node
-        .arguments()
-        .iter()
-        .map(|argument_node| interpret_expression(argument_node, state))
-        .collect::<Result<_, _>>()?;
+    let result = match function {
+        // Record constructors don't run any code, so there's no need for a
+        // stack frame
+        FunctionValue::RecordConstructor(record_type) => {
+            if argument_count != record_type.field_names().len() {
+                return Err(NonLinearControlFlow::RuntimeError(
+                    RuntimeError::new_invalid_overload_error(
+                        pos.clone(),
+                        state.scope_display_name().to_string(),
+                        record_type.name().to_string(),
+                        argument_count,
+                    ),
+                ));
+            }
 
-    // Push a new stack frame
-    state.push_stack_frame(format!("{function_name}({})", function.parameters()));
+            // Fields may be given by name, just like parameters
+            let field_names: Vec<&str> = record_type
+                .field_names()
+                .iter()
+                .map(|name| name.as_str())
+                .collect();
+            let field_values =
+                match match_arguments(&field_names, false, arguments, named_arguments) {
+                    Ok((field_values, _)) => field_values,
+                    Err(why) => {
+                        return Err(invalid_overload(
+                            record_type.name(),
+                            format!("{}({}) {why}", record_type.name(), field_names.join(", ")),
+                            state,
+                        ));
+                    }
+                };
 
-    let mut return_value = Value::Null;
+            return Ok(Value::Record(Rc::new(RefCell::new(
+                Record::new(
+                    record_type.clone(),
+                    field_values
+                        .into_iter()
+                        .map(|value| value.expect("every field should have a value"))
+                        .collect(),
+                )
+                .into(),
+            ))));
+        }
+        FunctionValue::StdLib(std_lib_function) => {
+            if !named_arguments.is_empty() {
+                return Err(invalid_overload(
+                    std_lib_function.identifier(),
+                    "standard library functions don't accept named arguments".to_string(),
+                    state,
+                ));
+            }
 
-    // Bind the arguments to local variables
-    match function.parameters() {
-        FunctionParameters::Variadic { parameter_name } => {
-            state.declare_variable(
-                parameter_name.0.to_string(),
-                Value::List(Rc::new(RefCell::new(arguments))),
+            // Push a new stack frame
+            state.push_stack_frame(
+                format!("{}(...)", std_lib_function.identifier()),
+                Vec::new(),
             );
-        }
-        FunctionParameters::Polyadic { parameters } => {
-            assert_eq!(arguments.len(), parameters.len());
 
-            for (parameter_name, argument_value) in parameters.iter().zip(arguments.into_iter()) {
-                state.declare_variable(parameter_name.0.clone(), argument_value);
-            }
+            // Call the function
+            let callable = std_lib_function.as_fn_pointer();
+            callable(arguments, pos, state)
         }
-    }
+        FunctionValue::UserDefined {
+            name: function_name,
+            function_group,
+        } => {
+            let name = function_name.as_deref().unwrap_or("<anonymous>");
 
-    for statement in function.body() {
-        match interpret_statement(statement, state) {
-            Ok(()) => {}
-            Err(NonLinearControlFlow::JumpStatement(jump_statement)) => match jump_statement.kind()
-            {
-                JumpStatementKind::Return => {
-                    return_value = jump_statement.into_value().unwrap_or(Value::Null);
-                    break;
+            // Find the overload which accepts this many arguments
+            let Some(function) = function_group
+                .get_polyadic_overload(argument_count)
+                .or_else(|| function_group.get_variadic_overload())
+            else {
+                let signatures: Vec<String> = function_group
+                    .overloads()
+                    .map(|overload| format!("{name}({})", overload.parameters()))
+                    .collect();
+                return Err(invalid_overload(
+                    name,
+                    format!("available overloads are {}", signatures.join(", ")),
+                    state,
+                ));
+            };
+            let signature = format!("{name}({})", function.parameters());
+
+            // Match the arguments to the parameters
+            let (matched_arguments, extra_arguments) = match function.parameters() {
+                FunctionParameters::Variadic { parameter_name: _ } => {
+                    if !named_arguments.is_empty() {
+                        return Err(invalid_overload(
+                            name,
+                            format!("{signature} doesn't accept named arguments"),
+                            state,
+                        ));
+                    }
+
+                    (Vec::new(), arguments)
                 }
-                jump_kind => {
-                    let mut err = RuntimeError::new_invalid_jump_statement_error(
-                        jump_statement.pos().clone(),
-                        state.scope_display_name().to_string(),
-                        jump_kind,
-                        "a function".to_string(),
-                    );
-                    state.pop_stack_frame();
-                    err.stack_trace_mut().add_bottom(
-                        state.scope_display_name().to_string(),
-                        function_call_node.pos().clone(),
+                FunctionParameters::Polyadic {
+                    parameters,
+                    rest_parameter,
+                } => {
+                    let parameter_names: Vec<&str> = parameters
+                        .iter()
+                        .map(|(parameter_name, _, _)| parameter_name.as_str())
+                        .collect();
+                    let (matched_arguments, extra_arguments) = match_arguments(
+                        &parameter_names,
+                        rest_parameter.is_some(),
+                        arguments,
+                        named_arguments,
+                    )
+                    .map_err(|why| invalid_overload(name, format!("{signature} {why}"), state))?;
+
+                    // Every parameter without a default value needs an
+                    // argument
+                    for ((parameter_name, default_value, _), argument) in
+                        parameters.iter().zip(&matched_arguments)
+                    {
+                        if argument.is_none() && default_value.is_none() {
+                            return Err(invalid_overload(
+                                name,
+                                format!(
+                                    "{signature} is missing an argument for parameter \"{parameter_name}\""
+                                ),
+                                state,
+                            ));
+                        }
+                    }
+
+                    (matched_arguments, extra_arguments)
+                }
+            };
+
+            // Push a new stack frame
+            state.push_stack_frame(signature, function.environment().clone());
+
+            // Bind the arguments to local variables, and run the body of the
+            // function
+            match bind_arguments(
+                function.parameters(),
+                matched_arguments,
+                extra_arguments,
+                state,
+            ) {
+                // Calling a generator function doesn't run its body. Instead,
+                // the new stack frame is saved in a generator, which runs the
+                // body each time it's resumed
+                Ok(()) if function.is_generator() => {
+                    let generator = Generator::new(
+                        function_name.clone(),
+                        function.body().clone(),
+                        state.take_stack_frame(),
                     );
-                    return Err(NonLinearControlFlow::RuntimeError(err));
+                    return Ok(Value::Generator(Rc::new(RefCell::new(generator))));
                 }
-            },
-            Err(NonLinearControlFlow::RuntimeError(mut err)) => {
-                state.pop_stack_frame();
-                err.stack_trace_mut().add_bottom(
+                Ok(()) => function
+                    .body()
+                    .iter()
+                    .try_for_each(|statement| interpret_statement(statement, state))
+                    .map(|()| Value::Null),
+                Err(err) => Err(err),
+            }
+        }
+    };
+
+    let return_value = match result {
+        Ok(value) => value,
+        Err(NonLinearControlFlow::JumpStatement(jump_statement)) => match jump_statement.kind() {
+            JumpStatementKind::Return => jump_statement.into_value().unwrap_or(Value::Null),
+            jump_kind => {
+                let mut err = RuntimeError::new_invalid_jump_statement_error(
+                    jump_statement.pos().clone(),
                     state.scope_display_name().to_string(),
-                    function_call_node.pos().clone(),
+                    jump_kind,
+                    "a function".to_string(),
                 );
+                state.pop_stack_frame();
+                err.stack_trace_mut()
+                    .add_bottom(state.scope_display_name().to_string(), pos.clone());
                 return Err(NonLinearControlFlow::RuntimeError(err));
             }
+        },
+        Err(NonLinearControlFlow::RuntimeError(mut err)) => {
+            state.pop_stack_frame();
+            err.stack_trace_mut()
+                .add_bottom(state.scope_display_name().to_string(), pos.clone());
+            return Err(NonLinearControlFlow::RuntimeError(err));
         }
-    }
+        Err(NonLinearControlFlow::Throw(mut thrown_value)) => {
+            state.pop_stack_frame();
+            thrown_value
+                .stack_trace_mut()
+                .add_bottom(state.scope_display_name().to_string(), pos.clone());
+            return Err(NonLinearControlFlow::Throw(thrown_value));
+        }
+        Err(NonLinearControlFlow::Yield(_)) => {
+            unreachable!("only generator functions can yield, and they don't run when called")
+        }
+    };
 
     // Pop the stack frame
     state.pop_stack_frame();
 
     Ok(return_value)
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::run_icelang;
+
+    #[test]
+    fn test_call_callback_shadowing_function() {
+        run_icelang(
+            r#"
+            fn apply(f, x) { return f(x); };
+            fn f(x) { return "global"; };
+
+            assert(apply(fn(x) { return "callback"; }, 1) == "callback");
+            assert(f(1) == "global");
+            "#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_call_parameter_shadowing_std_lib_function() {
+        run_icelang(
+            r#"
+            fn run(print) { return print("x"); };
+
+            assert(run(fn(s) { return s + "!"; }) == "x!");
+            "#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_call_innermost_binding() {
+        run_icelang(
+            r#"
+            fn g() { return "function"; };
+            fn call_local_variable() {
+                let g = fn() { return "variable"; };
+                return g();
+            };
+            assert(call_local_variable() == "variable");
+
+            let h = fn() { return "variable"; };
+            fn call_local_function() {
+                fn h() { return "function"; };
+                return h();
+            };
+            assert(call_local_function() == "function");
+            assert(h() == "variable");
+            "#,
+        )
+        .unwrap();
+    }
+}
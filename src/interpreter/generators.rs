@@ -0,0 +1,366 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    ast::{AstNodeYield, JumpStatementKind},
+    error::runtime_error::RuntimeError,
+    generator::{Generator, GeneratorStatus, ResumePoint},
+    runtime_state::RuntimeState,
+    source_range::SourceRange,
+    value::Value,
+};
+
+use super::{
+    control_flow::next_for_loop_value,
+    core::{interpret_expression, interpret_statements},
+    runtime_result::{NonLinearControlFlow, RuntimeResult},
+};
+
+/// A generator suspending at a `yield`, which is bubbling up to the code that
+/// resumed the generator
+#[derive(Debug)]
+pub struct Suspension<'source> {
+    value: Value<'source>,
+    resume_points: Vec<ResumePoint<'source>>,
+}
+
+impl<'source> Suspension<'source> {
+    /// Constructs a new Suspension which yields the given value
+    fn new(value: Value<'source>) -> Self {
+        Self {
+            value,
+            resume_points: vec![ResumePoint::Yield],
+        }
+    }
+
+    /// Records where the next construct the suspension bubbles up through
+    /// should resume
+    pub(crate) fn add_resume_point(&mut self, resume_point: ResumePoint<'source>) {
+        self.resume_points.push(resume_point);
+    }
+}
+
+/// Interprets an AstNodeYield
+pub fn interpret_yield<'source>(
+    node: &AstNodeYield<'source>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, ()> {
+    // A generator resuming from this yield just carries on after it
+    if let Some(resume_point) = state.take_resume_point() {
+        assert!(matches!(resume_point, ResumePoint::Yield));
+
+        return Ok(());
+    }
+
+    let value = match node.body() {
+        Some(body) => interpret_expression(body, state)?,
+        None => Value::Null,
+    };
+
+    Err(NonLinearControlFlow::Yield(Suspension::new(value)))
+}
+
+/// Pushes a new scope, or if a construct is resuming, the scope it suspended
+/// in. Returns whether or not the construct is resuming
+///
+/// # Panics
+/// - If the construct is resuming, but it didn't suspend in a scope
+pub fn push_resumable_scope(state: &mut RuntimeState) -> bool {
+    match state.take_resume_point() {
+        Some(ResumePoint::Scope(scope)) => {
+            state.push_existing_scope(scope);
+            true
+        }
+        Some(resume_point) => panic!("expected to resume in a scope, got {resume_point:?}"),
+        None => {
+            state.push_scope();
+            false
+        }
+    }
+}
+
+/// Resumes a generator, running it until it yields a value (which is returned)
+/// or finishes (in which case None is returned). `pos` is the position of the
+/// code which resumed the generator
+pub fn resume_generator<'source>(
+    generator: &Rc<RefCell<Generator<'source>>>,
+    pos: &SourceRange<'source>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, Option<Value<'source>>> {
+    let status = generator
+        .borrow_mut()
+        .replace_status(GeneratorStatus::Running);
+    let (frame, resume_points) = match status {
+        GeneratorStatus::Suspended {
+            frame,
+            resume_points,
+        } => (frame, resume_points),
+        GeneratorStatus::Enumerating {
+            mut values,
+            next_index,
+        } => {
+            return match next_for_loop_value(&mut values, pos, state) {
+                Ok(Some(value)) => {
+                    generator
+                        .borrow_mut()
+                        .replace_status(GeneratorStatus::Enumerating {
+                            values,
+                            next_index: &next_index + 1,
+                        });
+
+                    Ok(Some(Value::List(Rc::new(RefCell::new(
+                        vec![Value::Int(next_index), value].into(),
+                    )))))
+                }
+                result => {
+                    generator
+                        .borrow_mut()
+                        .replace_status(GeneratorStatus::Finished);
+
+                    result
+                }
+            };
+        }
+        GeneratorStatus::Running => {
+            return Err(NonLinearControlFlow::RuntimeError(
+                RuntimeError::new_generator_error(
+                    pos.clone(),
+                    state.scope_display_name().to_string(),
+                    "a generator can't be resumed while it's running".to_string(),
+                ),
+            ));
+        }
+        GeneratorStatus::Finished => {
+            generator
+                .borrow_mut()
+                .replace_status(GeneratorStatus::Finished);
+            return Ok(None);
+        }
+    };
+    let body = generator.borrow().body().clone();
+
+    // Run the body in the generator's own stack frame, picking up where it
+    // left off
+    state.push_existing_stack_frame(frame);
+    state.start_resuming(resume_points);
+    let result = interpret_statements(&body, state);
+
+    if let Err(NonLinearControlFlow::Yield(suspension)) = result {
+        generator
+            .borrow_mut()
+            .replace_status(GeneratorStatus::Suspended {
+                frame: state.take_stack_frame(),
+                resume_points: suspension.resume_points,
+            });
+
+        return Ok(Some(suspension.value));
+    }
+
+    // Otherwise, the generator is finished
+    generator
+        .borrow_mut()
+        .replace_status(GeneratorStatus::Finished);
+    match result {
+        Ok(()) => {}
+        Err(NonLinearControlFlow::JumpStatement(jump_statement)) => match jump_statement.kind() {
+            // The value of a `return` in a generator is ignored
+            JumpStatementKind::Return => {}
+            jump_kind => {
+                let mut err = RuntimeError::new_invalid_jump_statement_error(
+                    jump_statement.pos().clone(),
+                    state.scope_display_name().to_string(),
+                    jump_kind,
+                    "a function".to_string(),
+                );
+                state.pop_stack_frame();
+                err.stack_trace_mut()
+                    .add_bottom(state.scope_display_name().to_string(), pos.clone());
+                return Err(NonLinearControlFlow::RuntimeError(err));
+            }
+        },
+        Err(NonLinearControlFlow::RuntimeError(mut err)) => {
+            state.pop_stack_frame();
+            err.stack_trace_mut()
+                .add_bottom(state.scope_display_name().to_string(), pos.clone());
+            return Err(NonLinearControlFlow::RuntimeError(err));
+        }
+        Err(NonLinearControlFlow::Throw(mut thrown_value)) => {
+            state.pop_stack_frame();
+            thrown_value
+                .stack_trace_mut()
+                .add_bottom(state.scope_display_name().to_string(), pos.clone());
+            return Err(NonLinearControlFlow::Throw(thrown_value));
+        }
+        Err(NonLinearControlFlow::Yield(_)) => unreachable!(),
+    }
+    state.pop_stack_frame();
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::run_icelang;
+
+    /// Runs a snippet after defining `collect(generator)`, which resumes a
+    /// generator until it finishes and returns the values it yielded, joined
+    /// into a string
+    fn run_with_collect(source_code: &str) -> Result<(), String> {
+        run_icelang(&format!(
+            r#"
+            fn collect(generator) {{
+                let values = "";
+                for value in generator {{
+                    values += f"{{value}} ";
+                }};
+                return values;
+            }};
+            {source_code}
+            "#
+        ))
+    }
+
+    #[test]
+    fn test_resume_in_nested_loops() {
+        run_with_collect(
+            r#"
+            fn nested() {
+                for i in 0..2 {
+                    let j = 0;
+                    while j < 2 {
+                        loop 2 {
+                            yield f"{i}{j}";
+                        };
+                        j += 1;
+                    };
+                };
+            };
+
+            assert(collect(nested()) == "00 00 01 01 10 10 11 11 ");
+            "#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_resume_in_try_catch() {
+        run_with_collect(
+            r#"
+            fn guarded() {
+                try {
+                    yield "try";
+                    throw "oops";
+                } catch message {
+                    yield message;
+                    yield "catch";
+                };
+                yield "after";
+            };
+
+            assert(collect(guarded()) == "try oops catch after ");
+            "#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_resume_in_match_and_if_else() {
+        run_with_collect(
+            r#"
+            fn describe(values) {
+                for value in values {
+                    match value {
+                        int(n) if n < 0 => {
+                            yield "negative";
+                            yield -n;
+                        },
+                        [first, ...rest] => {
+                            yield first;
+                            if len(rest) > 0 {
+                                yield "rest";
+                            } else {
+                                yield "no rest";
+                            };
+                        },
+                        _ => {
+                            yield "other";
+                        },
+                    };
+                };
+            };
+
+            assert(collect(describe([-2, [1], [3, 4], "x"])) == "negative 2 1 no rest 3 rest other ");
+            "#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_resume_in_labeled_loops() {
+        run_with_collect(
+            r#"
+            fn labeled() {
+                'outer: for i in 0..3 {
+                    for j in 0..3 {
+                        if j == 2 {
+                            continue 'outer;
+                        };
+                        if i == 2 {
+                            break 'outer;
+                        };
+                        yield f"{i}{j}";
+                    };
+                };
+                yield "done";
+            };
+
+            assert(collect(labeled()) == "00 01 10 11 done ");
+            "#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_recursive_generator() {
+        run_with_collect(
+            r#"
+            fn flatten(value) {
+                match value {
+                    list(values) => {
+                        for element in values {
+                            for inner in flatten(element) {
+                                yield inner;
+                            };
+                        };
+                    },
+                    _ => {
+                        yield value;
+                    },
+                };
+            };
+
+            assert(collect(flatten([1, [2, [3, [4]]], [], 5])) == "1 2 3 4 5 ");
+            "#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_resume_running_generator() {
+        let err = run_icelang(
+            r#"
+            let generator = null;
+            fn resume_self() {
+                yield next(generator);
+            };
+            generator = resume_self();
+            next(generator);
+            "#,
+        )
+        .unwrap_err();
+
+        assert!(
+            err.contains("a generator can't be resumed while it's running"),
+            "{err}"
+        );
+    }
+}
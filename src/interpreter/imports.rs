@@ -0,0 +1,193 @@
+use std::{collections::VecDeque, fs, path::Path};
+
+use crate::{
+    ast::AstNodeImport, error::runtime_error::RuntimeError, lexer, parser,
+    runtime_state::RuntimeState,
+};
+
+use super::{
+    core::interpret_statement,
+    runtime_result::{NonLinearControlFlow, RuntimeResult},
+};
+
+/// Interprets an AstNodeImport
+pub fn interpret_import<'source>(
+    node: &AstNodeImport<'source>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, ()> {
+    let import_error = |why: String, state: &RuntimeState<'source>| {
+        NonLinearControlFlow::RuntimeError(RuntimeError::new_import_error(
+            node.pos().clone(),
+            state.scope_display_name().to_string(),
+            why,
+        ))
+    };
+
+    // Module paths are relative to the directory of the importing file
+    let importer_directory = Path::new(node.pos().source_file_name())
+        .parent()
+        .unwrap_or(Path::new(""));
+    let module_path = importer_directory.join(node.path());
+
+    // The same module may be imported through different relative paths, so
+    // modules are identified by their canonical path
+    let canonical_path = fs::canonicalize(&module_path).map_err(|err| {
+        import_error(
+            format!(
+                "failed to read module \"{}\" ({err})",
+                module_path.display()
+            ),
+            state,
+        )
+    })?;
+
+    let namespace = match state.lookup_module(&canonical_path) {
+        Some(namespace) => namespace,
+        None if state.is_module_running(&canonical_path) => {
+            return Err(import_error(
+                format!("circular import of module \"{}\"", module_path.display()),
+                state,
+            ));
+        }
+        None => {
+            let source_code = fs::read_to_string(&canonical_path).map_err(|err| {
+                import_error(
+                    format!(
+                        "failed to read module \"{}\" ({err})",
+                        module_path.display()
+                    ),
+                    state,
+                )
+            })?;
+
+            // Values (and errors) from the module borrow from its source code
+            // and file name, and may outlive this import statement, so both
+            // are leaked to live as long as the importing source code. Each
+            // module is only ever run once, so this is leaked at most once per
+            // module
+            let source_code: &'source str = Box::leak(source_code.into_boxed_str());
+            let file_name: &'source str =
+                Box::leak(module_path.display().to_string().into_boxed_str());
+
+            state.enter_module(canonical_path);
+            match run_module(source_code, file_name, state) {
+                Ok(()) => state.exit_module(),
+                Err(NonLinearControlFlow::RuntimeError(mut err)) => {
+                    state.abort_module();
+
+                    err.stack_trace_mut()
+                        .add_bottom(state.scope_display_name().to_string(), node.pos().clone());
+                    return Err(NonLinearControlFlow::RuntimeError(err));
+                }
+                Err(NonLinearControlFlow::Throw(mut thrown_value)) => {
+                    state.abort_module();
+
+                    thrown_value
+                        .stack_trace_mut()
+                        .add_bottom(state.scope_display_name().to_string(), node.pos().clone());
+                    return Err(NonLinearControlFlow::Throw(thrown_value));
+                }
+                Err(NonLinearControlFlow::JumpStatement(_) | NonLinearControlFlow::Yield(_)) => {
+                    unreachable!("jump statements and yields can't escape a module")
+                }
+            }
+        }
+    };
+
+    if state.lookup_local_variable(node.namespace()).is_some() {
+        return Err(NonLinearControlFlow::RuntimeError(
+            RuntimeError::new_identifier_already_declared_error(
+                node.pos().clone(),
+                state.scope_display_name().to_string(),
+                node.namespace().to_string(),
+            ),
+        ));
+    }
+
+    state.declare_variable(node.namespace().to_string(), namespace);
+
+    Ok(())
+}
+
+/// Lexes, parses, and runs the source code of a module in the current (module)
+/// global scope
+fn run_module<'source>(
+    source_code: &'source str,
+    file_name: &'source str,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, ()> {
+    // Syntax errors in the module are reported as import errors, so that they
+    // can point into the module's source code while still having a stack trace
+    // leading back to the import
+    let syntax_error = |pos, description: String, state: &RuntimeState<'source>| {
+        NonLinearControlFlow::RuntimeError(RuntimeError::new_import_error(
+            pos,
+            state.scope_display_name().to_string(),
+            format!("syntax error in module \"{file_name}\": {description}"),
+        ))
+    };
+
+    let tokens = lexer::tokenize(source_code, file_name)
+        .map_err(|err| syntax_error(err.pos().clone(), err.description(), state))?;
+    let ast = parser::parse(tokens.iter().collect::<VecDeque<_>>())
+        .map_err(|err| syntax_error(err.pos().clone(), err.description(), state))?;
+
+    for statement in &ast.statements {
+        match interpret_statement(statement, state) {
+            Ok(()) => {}
+            Err(NonLinearControlFlow::JumpStatement(jump_statement)) => {
+                return Err(NonLinearControlFlow::RuntimeError(
+                    RuntimeError::new_invalid_jump_statement_error(
+                        jump_statement.pos().clone(),
+                        state.scope_display_name().to_string(),
+                        jump_statement.kind(),
+                        "the global scope".to_string(),
+                    ),
+                ))
+            }
+            Err(err) => return Err(err),
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::test_utils::run_icelang;
+
+    #[test]
+    fn test_import_namespace_is_snapshot() {
+        let directory = std::env::temp_dir().join(format!(
+            "icelang_test_import_namespace_{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&directory).unwrap();
+        let module_path = directory.join("counter.ice");
+        fs::write(
+            &module_path,
+            "let count = 0; fn increment() { count += 1; }; fn current() { return count; };",
+        )
+        .unwrap();
+
+        let result = run_icelang(&format!(
+            r#"
+            import "{}";
+
+            counter.increment();
+            counter.increment();
+            assert(counter.count == 0);
+            assert(counter.current() == 2);
+
+            counter.count = 10;
+            assert(counter.current() == 2);
+            "#,
+            module_path.display()
+        ));
+        fs::remove_dir_all(&directory).unwrap();
+
+        result.unwrap();
+    }
+}
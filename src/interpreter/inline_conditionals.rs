@@ -15,7 +15,7 @@ use super::{
 pub fn interpret_inline_conditional<'source>(
     node: &AstNodeInlineConditional<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     let condition = interpret_expression(node.condition(), state)?;
 
     if let Value::Bool(condition) = condition {
@@ -3,15 +3,26 @@ use crate::{ast::JumpStatementKind, source_range::SourceRange, value::Value};
 #[derive(Debug)]
 pub struct JumpStatement<'source> {
     kind: JumpStatementKind,
-    value: Option<Value>,
+    value: Option<Value<'source>>,
+    label: Option<String>,
     pos: SourceRange<'source>,
 }
 
 impl<'source> JumpStatement<'source> {
-    /// Constructs a new JumpStatement with the given kind, optional value, and
-    /// pos
-    pub fn new(kind: JumpStatementKind, value: Option<Value>, pos: SourceRange<'source>) -> Self {
-        Self { kind, value, pos }
+    /// Constructs a new JumpStatement with the given kind, optional value,
+    /// optional label, and pos
+    pub fn new(
+        kind: JumpStatementKind,
+        value: Option<Value<'source>>,
+        label: Option<String>,
+        pos: SourceRange<'source>,
+    ) -> Self {
+        Self {
+            kind,
+            value,
+            label,
+            pos,
+        }
     }
 
     /// Gets the kind of the JumpStatement
@@ -19,8 +30,13 @@ impl<'source> JumpStatement<'source> {
         self.kind
     }
 
+    /// Gets the label of the loop targeted by the JumpStatement, if it has one
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
     /// Gets the stored value, consuming `self`
-    pub fn into_value(self) -> Option<Value> {
+    pub fn into_value(self) -> Option<Value<'source>> {
         self.value
     }
 
@@ -35,6 +51,7 @@ impl Clone for JumpStatement<'_> {
         Self {
             kind: self.kind,
             value: self.value.as_ref().map(Value::deep_copy),
+            label: self.label.clone(),
             pos: self.pos.clone(),
         }
     }
@@ -1,9 +1,16 @@
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use std::{cell::RefCell, rc::Rc};
 
-use super::{runtime_result::RuntimeResult, *};
+use indexmap::{IndexMap, IndexSet};
+
+use super::{runtime_result::RuntimeResult, spreads::interpret_spreadable_expressions, *};
 
 use crate::{
-    ast::{AstNodeDictLiteral, AstNodeFormattedStringLiteral, AstNodeListLiteral, AstNodeLiteral},
+    ast::{
+        AstNodeDictLiteral, AstNodeFormattedStringLiteral, AstNodeListLiteral, AstNodeLiteral,
+        AstNodeRangeLiteral, AstNodeSetLiteral, DictLiteralEntry,
+    },
+    error::runtime_error::RuntimeError,
+    range::Range,
     runtime_state::RuntimeState,
     value::Value,
 };
@@ -15,14 +22,10 @@ use crate::{
 pub fn interpret_literal_list<'source>(
     node: &AstNodeListLiteral<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
-    let mut list = Vec::with_capacity(node.elements().len());
-
-    for element_node in node.elements() {
-        list.push(interpret_expression(element_node, state)?);
-    }
+) -> RuntimeResult<'source, Value<'source>> {
+    let list = interpret_spreadable_expressions(node.elements(), state)?;
 
-    Ok(Value::List(Rc::new(RefCell::new(list))))
+    Ok(Value::List(Rc::new(RefCell::new(list.into()))))
 }
 
 /// Interprets a dict literal AstNodeLiteral
@@ -32,20 +35,92 @@ pub fn interpret_literal_list<'source>(
 pub fn interpret_literal_dict<'source>(
     node: &AstNodeDictLiteral<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
-    #[expect(
-        clippy::mutable_key_type,
-        reason = "current intended behavior is to allow mutable keys - likely needs design work to determine how to handle correctly"
-    )]
-    let mut dict = HashMap::with_capacity(node.entries().len());
-
-    for (key_node, value_node) in node.entries() {
-        let key = interpret_expression(key_node, state)?;
-        let value = interpret_expression(value_node, state)?;
-        dict.insert(key, value);
+) -> RuntimeResult<'source, Value<'source>> {
+    let mut dict = IndexMap::with_capacity(node.entries().len());
+
+    for entry in node.entries() {
+        match entry {
+            DictLiteralEntry::KeyValue(key_node, value_node) => {
+                let key = interpret_expression(key_node, state)?;
+                let value = interpret_expression(value_node, state)?;
+                dict.insert(key, value);
+            }
+            // Entries from a spread dict replace any earlier entries with the
+            // same keys, and are replaced by any later ones
+            DictLiteralEntry::Spread(spread) => match interpret_expression(spread.body(), state)? {
+                Value::Dict(other) => dict.extend(
+                    other
+                        .borrow()
+                        .iter()
+                        .map(|(key, value)| (key.reference_copy(), value.reference_copy())),
+                ),
+                value => {
+                    return Err(NonLinearControlFlow::RuntimeError(
+                        RuntimeError::new_type_error(
+                            spread.pos().clone(),
+                            state.scope_display_name().to_string(),
+                            format!(
+                                "expected dict to spread, got value of type {}",
+                                value.icelang_type()
+                            ),
+                        ),
+                    ));
+                }
+            },
+        }
     }
 
-    Ok(Value::Dict(Rc::new(RefCell::new(dict))))
+    Ok(Value::Dict(Rc::new(RefCell::new(dict.into()))))
+}
+
+/// Interprets a set literal AstNodeSetLiteral
+///
+/// # Panics
+/// - If the AstNodeSetLiteral isn't a valid set literal
+pub fn interpret_literal_set<'source>(
+    node: &AstNodeSetLiteral<'source>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, Value<'source>> {
+    let mut set = IndexSet::with_capacity(node.elements().len());
+
+    for element in node.elements() {
+        set.insert(interpret_expression(element, state)?);
+    }
+
+    Ok(Value::Set(Rc::new(RefCell::new(set.into()))))
+}
+
+/// Interprets a range literal AstNodeRangeLiteral
+///
+/// # Panics
+/// - If the AstNodeRangeLiteral isn't a valid range literal
+pub fn interpret_literal_range<'source>(
+    node: &AstNodeRangeLiteral<'source>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, Value<'source>> {
+    let start = interpret_expression(node.start(), state)?;
+    let end = interpret_expression(node.end(), state)?;
+
+    let (Value::Int(start), Value::Int(end)) = (&start, &end) else {
+        return Err(NonLinearControlFlow::RuntimeError(
+            RuntimeError::new_type_error(
+                node.pos().clone(),
+                state.scope_display_name().to_string(),
+                format!(
+                    "the bounds of a range must be ints, but got {} and {}",
+                    start.icelang_type(),
+                    end.icelang_type()
+                ),
+            ),
+        ));
+    };
+
+    let range = match node.is_inclusive() {
+        true => Range::new_inclusive(start.clone(), end.clone()),
+        false => Range::new(start.clone(), end.clone(), 1.into()),
+    };
+
+    Ok(Value::Range(Rc::new(range)))
 }
 
 /// Interprets a formatted string literal AstNodeLiteral
@@ -55,14 +130,33 @@ pub fn interpret_literal_dict<'source>(
 pub fn interpret_formatted_string_literal<'source>(
     node: &AstNodeFormattedStringLiteral<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     let mut buffer = String::new();
 
-    for (string_part, replacement_field) in
-        std::iter::once(node.start()).chain(node.continuations().into_iter())
+    for (string_part, replacement_field, format_spec) in
+        std::iter::once(node.start()).chain(node.continuations())
     {
         buffer += string_part;
-        buffer += &interpret_expression(replacement_field, state)?.icelang_display();
+
+        let value = interpret_expression(replacement_field, state)?;
+        match format_spec {
+            Some(format_spec) => match format_spec.apply(&value) {
+                Some(formatted) => buffer += &formatted,
+                None => {
+                    return Err(NonLinearControlFlow::RuntimeError(
+                        RuntimeError::new_type_error(
+                            replacement_field.pos().clone(),
+                            state.scope_display_name().to_string(),
+                            format!(
+                                "format spec `{format_spec}` can't be applied to a value of type {}",
+                                value.icelang_type()
+                            ),
+                        ),
+                    ));
+                }
+            },
+            None => buffer += &value.icelang_display(),
+        }
     }
     buffer += node.end();
 
@@ -73,6 +167,6 @@ pub fn interpret_formatted_string_literal<'source>(
 ///
 /// # Panics
 /// - If the literal is invalid
-pub fn interpret_literal(node: &AstNodeLiteral) -> Value {
+pub fn interpret_literal<'source>(node: &AstNodeLiteral<'source>) -> Value<'source> {
     node.value().deep_copy()
 }
@@ -1,34 +1,60 @@
-use num_traits::{Signed, ToPrimitive};
+use std::{cell::RefCell, rc::Rc};
+
+use num_bigint::BigInt;
+use num_traits::{One, Signed, ToPrimitive, Zero};
 
 use crate::{
-    ast::{AstNodeComputedMemberAccess, AstNodeDotMemberAccess},
+    ast::{AstNode, AstNodeComputedMemberAccess, AstNodeDotMemberAccess, AstNodeSlice},
     error::runtime_error::RuntimeError,
+    range::Range,
     runtime_state::RuntimeState,
     value::Value,
 };
 
 use super::{
     core::interpret_expression,
+    functions::call_function_value,
     runtime_result::{NonLinearControlFlow, RuntimeResult},
 };
 
 /// Interprets an AstNodeDotMemberAccess
 ///
+/// If `missing_key_is_null` is true, accessing a key which isn't in a dict
+/// results in null instead of an error (like the lhs of a `??=` assignment,
+/// which inserts the key)
+///
 /// # Panics
 /// - If the AstNodeDotMemberAccess is invalid in any way
 pub fn interpret_dot_member_access<'source>(
     node: &AstNodeDotMemberAccess<'source>,
+    missing_key_is_null: bool,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, Value<'source>> {
+    match interpret_chain_root(node.root(), state)? {
+        Some(root) => dot_member_access(node, root, missing_key_is_null, state),
+        None => Ok(Value::Null),
+    }
+}
+
+/// Accesses a member of an already evaluated root (see
+/// `interpret_dot_member_access`)
+fn dot_member_access<'source>(
+    node: &AstNodeDotMemberAccess<'source>,
+    root: Value<'source>,
+    missing_key_is_null: bool,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     // TODO Can we avoid the to_string() here? If the lookup fails, we don't
     // need the mutable borrow anymore... I think?
     let scope_display_name = state.scope_display_name().to_string();
-    let root = interpret_expression(node.root(), state)?;
     match root {
+        // An optional member access on null is null
+        Value::Null if node.is_optional() => Ok(Value::Null),
         Value::Dict(dict) => {
             let key = Value::String(node.member().into());
             match dict.borrow().get(&key) {
                 Some(value) => Ok(value.reference_copy()),
+                None if node.is_optional() || missing_key_is_null => Ok(Value::Null),
                 None => Err(NonLinearControlFlow::RuntimeError(
                     RuntimeError::new_invalid_member_access_error(
                         node.pos().clone(),
@@ -38,6 +64,24 @@ pub fn interpret_dot_member_access<'source>(
                 )),
             }
         }
+        Value::Record(record) => {
+            let record = record.borrow();
+            match record.get(node.member()) {
+                Some(value) => Ok(value.reference_copy()),
+                None if node.is_optional() => Ok(Value::Null),
+                None => Err(NonLinearControlFlow::RuntimeError(
+                    RuntimeError::new_invalid_member_access_error(
+                        node.pos().clone(),
+                        scope_display_name,
+                        format!(
+                            "record type {} has no field \"{}\"",
+                            record.record_type().name(),
+                            node.member()
+                        ),
+                    ),
+                )),
+            }
+        }
         root => Err(NonLinearControlFlow::RuntimeError(
             RuntimeError::new_invalid_member_access_error(
                 node.pos().clone(),
@@ -50,41 +94,47 @@ pub fn interpret_dot_member_access<'source>(
 
 /// Interprets an AstNodeComputedMemberAccess
 ///
+/// If `missing_key_is_null` is true, accessing a key which isn't in a dict
+/// results in null instead of an error (like the lhs of a `??=` assignment,
+/// which inserts the key)
+///
 /// # Panics
 /// - If the AstNodeComputedMemberAccess is invalid in any way
 pub fn interpret_computed_member_access<'source>(
     node: &AstNodeComputedMemberAccess<'source>,
+    missing_key_is_null: bool,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
+    match interpret_chain_root(node.root(), state)? {
+        Some(root) => computed_member_access(node, root, missing_key_is_null, state),
+        None => Ok(Value::Null),
+    }
+}
+
+/// Accesses a member of an already evaluated root (see
+/// `interpret_computed_member_access`)
+fn computed_member_access<'source>(
+    node: &AstNodeComputedMemberAccess<'source>,
+    root: Value<'source>,
+    missing_key_is_null: bool,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, Value<'source>> {
     // TODO Can we avoid the to_string() here? If the lookup fails, we don't
     // need the mutable borrow anymore... I think?
     let scope_display_name = state.scope_display_name().to_string();
-    let root = interpret_expression(node.root(), state)?;
+
+    // An optional member access on null is null, without evaluating the member
+    if node.is_optional() && matches!(root, Value::Null) {
+        return Ok(Value::Null);
+    }
+
     let member = interpret_expression(node.member_node(), state)?;
     match root {
         Value::List(list) => {
             let list = list.borrow();
-            let index: usize = match member {
-                Value::Int(index) => {
-                    if index.is_negative() {
-                        return Err(NonLinearControlFlow::RuntimeError(
-                            RuntimeError::new_invalid_member_access_error(
-                                node.pos().clone(),
-                                scope_display_name,
-                                format!(
-                                    "index out of bounds (index {}, length {})",
-                                    index,
-                                    list.len(),
-                                ),
-                            ),
-                        ));
-                    }
-                    match index.to_usize() {
-                        Some(index) => index,
-                        None => todo!(),
-                    }
-                }
-                Value::Byte(byte) => byte as usize,
+            let index = match member {
+                Value::Int(index) => index,
+                Value::Byte(byte) => BigInt::from(byte),
                 member => {
                     return Err(NonLinearControlFlow::RuntimeError(
                         RuntimeError::new_invalid_member_access_error(
@@ -100,7 +150,10 @@ pub fn interpret_computed_member_access<'source>(
             };
 
             // Ensure the index is in-bounds
-            if index >= list.len() {
+            let Some(resolved_index) = resolve_index(&index, list.len()) else {
+                if node.is_optional() {
+                    return Ok(Value::Null);
+                }
                 return Err(NonLinearControlFlow::RuntimeError(
                     RuntimeError::new_invalid_member_access_error(
                         node.pos().clone(),
@@ -112,12 +165,13 @@ pub fn interpret_computed_member_access<'source>(
                         ),
                     ),
                 ));
-            }
+            };
 
-            Ok(list[index].reference_copy())
+            Ok(list[resolved_index].reference_copy())
         }
         Value::Dict(dict) => match dict.borrow().get(&member) {
             Some(value) => Ok(value.reference_copy()),
+            None if node.is_optional() || missing_key_is_null => Ok(Value::Null),
             None => Err(NonLinearControlFlow::RuntimeError(
                 RuntimeError::new_invalid_member_access_error(
                     node.pos().clone(),
@@ -127,34 +181,16 @@ pub fn interpret_computed_member_access<'source>(
             )),
         },
         Value::String(string) => {
-            let index: usize = match member {
-                Value::Int(index) => {
-                    if index.is_negative() {
-                        return Err(NonLinearControlFlow::RuntimeError(
-                            RuntimeError::new_invalid_member_access_error(
-                                node.pos().clone(),
-                                scope_display_name,
-                                format!(
-                                    "index out of bounds (index {}, length {})",
-                                    index,
-                                    string.len(),
-                                ),
-                            ),
-                        ));
-                    }
-                    match index.to_usize() {
-                        Some(index) => index,
-                        None => todo!(),
-                    }
-                }
-                Value::Byte(byte) => byte as usize,
+            let index = match member {
+                Value::Int(index) => index,
+                Value::Byte(byte) => BigInt::from(byte),
                 member => {
                     return Err(NonLinearControlFlow::RuntimeError(
                         RuntimeError::new_invalid_member_access_error(
                             node.pos().clone(),
                             scope_display_name,
                             format!(
-                                "cannot index a list with a value of type {}",
+                                "cannot index a string with a value of type {}",
                                 member.icelang_type()
                             ),
                         ),
@@ -163,30 +199,379 @@ pub fn interpret_computed_member_access<'source>(
             };
 
             // Ensure the index is in-bounds
-            if index >= string.len() {
+            let length = string.chars().count();
+            let Some(resolved_index) = resolve_index(&index, length) else {
+                if node.is_optional() {
+                    return Ok(Value::Null);
+                }
                 return Err(NonLinearControlFlow::RuntimeError(
+                    RuntimeError::new_invalid_member_access_error(
+                        node.pos().clone(),
+                        scope_display_name,
+                        format!("index out of bounds (index {}, length {})", index, length),
+                    ),
+                ));
+            };
+
+            Ok(Value::String(
+                string
+                    .chars()
+                    .nth(resolved_index)
+                    .unwrap()
+                    .to_string()
+                    .into(),
+            ))
+        }
+        Value::Range(range) => {
+            let index = match member {
+                Value::Int(index) => index,
+                Value::Byte(byte) => BigInt::from(byte),
+                member => {
+                    return Err(NonLinearControlFlow::RuntimeError(
+                        RuntimeError::new_invalid_member_access_error(
+                            node.pos().clone(),
+                            scope_display_name,
+                            format!(
+                                "cannot index a range with a value of type {}",
+                                member.icelang_type()
+                            ),
+                        ),
+                    ));
+                }
+            };
+
+            // Negative indices count back from the end, just like for lists
+            let resolved_index = match index.is_negative() {
+                true => &index + range.len(),
+                false => index.clone(),
+            };
+
+            match range.get(&resolved_index) {
+                Some(element) => Ok(Value::Int(element)),
+                None if node.is_optional() => Ok(Value::Null),
+                None => Err(NonLinearControlFlow::RuntimeError(
                     RuntimeError::new_invalid_member_access_error(
                         node.pos().clone(),
                         scope_display_name,
                         format!(
                             "index out of bounds (index {}, length {})",
                             index,
-                            string.len(),
+                            range.len(),
                         ),
                     ),
-                ));
+                )),
             }
+        }
+        root => Err(NonLinearControlFlow::RuntimeError(
+            RuntimeError::new_invalid_member_access_error(
+                node.pos().clone(),
+                scope_display_name,
+                format!("cannot index value of type {}", root.icelang_type()),
+            ),
+        )),
+    }
+}
+
+/// Interprets an AstNodeSlice
+///
+/// # Panics
+/// - If the AstNodeSlice is invalid in any way
+pub fn interpret_slice<'source>(
+    node: &AstNodeSlice<'source>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, Value<'source>> {
+    match interpret_chain_root(node.root(), state)? {
+        Some(root) => slice(node, root, state),
+        None => Ok(Value::Null),
+    }
+}
+
+/// Slices an already evaluated root (see `interpret_slice`)
+fn slice<'source>(
+    node: &AstNodeSlice<'source>,
+    root: Value<'source>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, Value<'source>> {
+    // An optional slice of null is null, without evaluating the bounds
+    if node.is_optional() && matches!(root, Value::Null) {
+        return Ok(Value::Null);
+    }
+
+    let bounds = interpret_slice_bounds(node, state)?;
+    match root {
+        Value::List(list) => {
+            let list = list.borrow();
+            let indices = bounds.indices(list.len());
+
+            Ok(Value::List(Rc::new(RefCell::new(
+                indices
+                    .iter()
+                    .map(|index| list[index.to_usize().unwrap()].reference_copy())
+                    .collect(),
+            ))))
+        }
+        Value::String(string) => {
+            let chars: Vec<char> = string.chars().collect();
+            let indices = bounds.indices(chars.len());
 
             Ok(Value::String(
-                string.chars().nth(index).unwrap().to_string().into(),
+                indices
+                    .iter()
+                    .map(|index| chars[index.to_usize().unwrap()])
+                    .collect::<String>()
+                    .into(),
             ))
         }
         root => Err(NonLinearControlFlow::RuntimeError(
             RuntimeError::new_invalid_member_access_error(
                 node.pos().clone(),
-                scope_display_name,
-                format!("cannot index value of type {}", root.icelang_type()),
+                state.scope_display_name().to_string(),
+                format!("cannot slice value of type {}", root.icelang_type()),
             ),
         )),
     }
 }
+
+/// Interprets the root of a member access, slice, or function call. Returns
+/// None if the root is part of an optional chain which short-circuited
+/// (because an optional member access in it accessed null), in which case the
+/// rest of the chain is skipped and evaluates to null
+///
+/// For example, in `a?.b.c[0]`, if `a` is null then `.c` and `[0]` are
+/// skipped rather than trying to access a member of null
+pub fn interpret_chain_root<'source>(
+    root: &AstNode<'source>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, Option<Value<'source>>> {
+    let (inner_root, optional) = match root {
+        AstNode::DotMemberAccess(node) => (node.root(), node.is_optional()),
+        AstNode::ComputedMemberAccess(node) => (node.root(), node.is_optional()),
+        AstNode::Slice(node) => (node.root(), node.is_optional()),
+        AstNode::FunctionCall(node) => (node.root(), false),
+        root => return interpret_expression(root, state).map(Some),
+    };
+
+    let Some(inner_root) = interpret_chain_root(inner_root, state)? else {
+        return Ok(None);
+    };
+    if optional && matches!(inner_root, Value::Null) {
+        return Ok(None);
+    }
+
+    match root {
+        AstNode::DotMemberAccess(node) => dot_member_access(node, inner_root, false, state),
+        AstNode::ComputedMemberAccess(node) => {
+            computed_member_access(node, inner_root, false, state)
+        }
+        AstNode::Slice(node) => slice(node, inner_root, state),
+        AstNode::FunctionCall(node) => call_function_value(node, inner_root, state),
+        _ => unreachable!(),
+    }
+    .map(Some)
+}
+
+/// The evaluated start, end, and step of a slice, before they're resolved
+/// against the length of the value being sliced
+#[derive(Debug)]
+pub struct SliceBounds {
+    start: Option<BigInt>,
+    end: Option<BigInt>,
+    step: BigInt,
+}
+
+impl SliceBounds {
+    /// Returns the step of the slice
+    pub fn step(&self) -> &BigInt {
+        &self.step
+    }
+
+    /// Returns the indices the slice selects from a list or string of the given
+    /// length, in order
+    ///
+    /// Negative bounds count back from the end, and bounds which are out of
+    /// bounds are clamped, so the slice only ever selects valid indices
+    pub fn indices(&self, length: usize) -> Range {
+        let length = BigInt::from(length);
+
+        // With a negative step, the slice runs backwards from the last element
+        // to just before the first one
+        let (lowest, highest) = match self.step.is_negative() {
+            false => (BigInt::zero(), length.clone()),
+            true => (-BigInt::one(), &length - 1),
+        };
+        let clamp = |bound: &BigInt| {
+            let bound = match bound.is_negative() {
+                true => bound + &length,
+                false => bound.clone(),
+            };
+            bound.clamp(lowest.clone(), highest.clone())
+        };
+
+        let (default_start, default_end) = match self.step.is_negative() {
+            false => (lowest.clone(), highest.clone()),
+            true => (highest.clone(), lowest.clone()),
+        };
+        let start = self.start.as_ref().map_or(default_start, clamp);
+        let end = self.end.as_ref().map_or(default_end, clamp);
+
+        Range::new(start, end, self.step.clone())
+    }
+}
+
+/// Evaluates the start, end, and step of a slice
+pub fn interpret_slice_bounds<'source>(
+    node: &AstNodeSlice<'source>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, SliceBounds> {
+    let mut interpret_bound = |bound: Option<&AstNode<'source>>| match bound {
+        Some(bound) => match interpret_expression(bound, state)? {
+            Value::Int(value) => Ok(Some(value)),
+            Value::Byte(value) => Ok(Some(BigInt::from(value))),
+            value => Err(NonLinearControlFlow::RuntimeError(
+                RuntimeError::new_invalid_member_access_error(
+                    bound.pos().clone(),
+                    state.scope_display_name().to_string(),
+                    format!("cannot slice with a value of type {}", value.icelang_type()),
+                ),
+            )),
+        },
+        None => Ok(None),
+    };
+
+    let start = interpret_bound(node.start())?;
+    let end = interpret_bound(node.end())?;
+    let step = interpret_bound(node.step())?.unwrap_or_else(BigInt::one);
+
+    if step.is_zero() {
+        return Err(NonLinearControlFlow::RuntimeError(
+            RuntimeError::new_invalid_member_access_error(
+                node.pos().clone(),
+                state.scope_display_name().to_string(),
+                "the step of a slice can't be zero".to_string(),
+            ),
+        ));
+    }
+
+    Ok(SliceBounds { start, end, step })
+}
+
+/// Resolves an index into a list or string of the given length, where negative
+/// indices count back from the end. Returns None if the index is out of bounds
+pub fn resolve_index(index: &BigInt, length: usize) -> Option<usize> {
+    let index = match index.is_negative() {
+        true => index + length,
+        false => index.clone(),
+    };
+
+    index.to_usize().filter(|index| *index < length)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{run_icelang, run_with_error_of};
+
+    #[test]
+    fn test_optional_chain_short_circuits() {
+        run_icelang(
+            r#"
+            let calls = 0;
+            fn count() { calls += 1; return 0; };
+            let nothing = null;
+
+            assert(nothing?.a.b == null);
+            assert(nothing?[0].b[count()] == null);
+            assert(nothing?.a.b() == null);
+            assert(nothing?[1:2][0] == null);
+            assert(calls == 0);
+
+            let data = {"a": null, "f": fn() { return {"x": 1}; }};
+            assert(data?.f().x == 1);
+            assert(data.a?.b.c == null);
+            "#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_member_access_on_null_without_optional_chain() {
+        let err = run_icelang(r#"let data = {"a": null}; data.a.b;"#).unwrap_err();
+
+        assert!(err.contains("cannot index value of type null"), "{err}");
+    }
+
+    #[test]
+    fn test_negative_indices() {
+        run_with_error_of(
+            r#"
+            let numbers = [0, 1, 2, 3];
+            assert(numbers[-1] == 3 && numbers[-4] == 0);
+            assert("héllo"[-4] == "é");
+            assert((0..10)[-1] == 9 && range(10, 0, -3)[-1] == 1);
+            assert(numbers?[-5] == null);
+
+            numbers[-1] = "three";
+            assert(numbers[3] == "three");
+
+            let out_of_bounds = "invalid_member_access: index out of bounds (index -5, length 4)";
+            assert(error_of(fn() { numbers[-5]; }) == out_of_bounds);
+            assert(error_of(fn() { numbers[-5] = 0; }) == out_of_bounds);
+            "#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_slices() {
+        run_with_error_of(
+            r#"
+            let numbers = [0, 1, 2, 3, 4, 5];
+            assert(f"{numbers[1:3]}" == "[1, 2]");
+            assert(f"{numbers[-2:]}" == "[4, 5]");
+            assert(f"{numbers[:-4]}" == "[0, 1]");
+            assert(f"{numbers[::2]}" == "[0, 2, 4]");
+            assert(f"{numbers[::-2]}" == "[5, 3, 1]");
+            assert(f"{numbers[5:1:-2]}" == "[5, 3]");
+            assert(f"{numbers[-1:-4:-1]}" == "[5, 4, 3]");
+            assert(f"{numbers[-100:2]}" == "[0, 1]" && f"{numbers[4:100]}" == "[4, 5]");
+            assert(f"{numbers[3:1]}" == "[]");
+            assert(f"{numbers[8x01:8x03]}" == "[1, 2]");
+            assert("héllo"[1:4] == "éll" && "abc"[::-1] == "cba");
+
+            // A slice is a new list
+            let copied = numbers[:];
+            copied[0] = 10;
+            assert(numbers[0] == 0);
+
+            assert(error_of(fn() { numbers[::0]; }) == "invalid_member_access: the step of a slice can't be zero");
+            assert(error_of(fn() { numbers[1.5:]; }) == "invalid_member_access: cannot slice with a value of type float");
+            assert(error_of(fn() { 5[1:2]; }) == "invalid_member_access: cannot slice value of type int");
+            "#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_slice_assignment() {
+        run_with_error_of(
+            r#"
+            let numbers = [0, 1, 2, 3, 4, 5];
+            numbers[1:4] = ["one"];
+            assert(f"{numbers}" == "[0, one, 4, 5]");
+            numbers[::2] = [null, null];
+            assert(f"{numbers}" == "[null, one, null, 5]");
+            numbers[1:1] = [7, 8];
+            numbers[-1:] = [];
+            assert(f"{numbers}" == "[null, 7, 8, one, null]");
+
+            let letters = [0, 1, 2, 3, 4, 5];
+            letters[::-2] = ["a", "b", "c"];
+            assert(f"{letters}" == "[0, c, 2, b, 4, a]");
+
+            assert(error_of(fn() { letters[::2] = [1]; }) == "invalid_member_access: cannot assign 1 element to a slice of length 3 with a step of 2");
+            assert(error_of(fn() { letters[::2] = [1, 2]; }) == "invalid_member_access: cannot assign 2 elements to a slice of length 3 with a step of 2");
+            assert(error_of(fn() { letters[0:1] = "x"; }) == "type: cannot assign a value of type string to a slice of a list");
+            "#,
+        )
+        .unwrap();
+    }
+}
@@ -4,15 +4,24 @@ mod assignments;
 mod binary_operations;
 mod casting;
 mod comparisons;
+mod comprehensions;
 mod control_flow;
 mod core;
+mod destructuring;
+mod error_handling;
 mod functions;
+mod generators;
+mod imports;
 mod inline_conditionals;
 mod jump_statement;
 mod literals;
 mod member_access;
 mod operations;
+mod pattern_matching;
+mod records;
 mod runtime_result;
+mod spreads;
+mod thrown_value;
 mod unary_operations;
 mod variables;
 
@@ -20,5 +29,8 @@ use self::core::interpret_expression;
 pub use self::core::{interpret, interpret_with_runtime_state};
 pub use self::runtime_result::{NonLinearControlFlow, RuntimeResult};
 use binary_operations::*;
+pub(crate) use casting::strip_underscores;
 use casting::*;
+pub(crate) use control_flow::iterate_value;
+pub(crate) use generators::resume_generator;
 use literals::*;
@@ -1,3 +1,5 @@
+use std::{cell::RefCell, rc::Rc};
+
 use num_bigint::BigInt;
 use num_traits::{ToPrimitive, Zero};
 
@@ -54,10 +56,10 @@ macro_rules! impl_simple_bin_op {
             $rhs_type: ident => $result: expr
         ),+$(,)?}
     ) => {
-        pub fn $func_name(
-            $lhs: Value,
-            $rhs: Value,
-        ) -> Result<Value, OperationError> {
+        pub fn $func_name<'source>(
+            $lhs: Value<'source>,
+            $rhs: Value<'source>,
+        ) -> Result<Value<'source>, OperationError> {
             match ($lhs, $rhs) {
                 $(
                     (Value::$lhs_type($lhs), Value::$rhs_type($rhs)) => $result,
@@ -82,14 +84,22 @@ impl_simple_bin_op!(bitwise_xor, lhs, rhs, BitwiseXor, {
     Byte, Byte => Ok(Value::Byte(lhs ^ rhs)),
 });
 
-impl_simple_bin_op!(bitwise_or, lhs, rhs, BitwiseXor, {
+impl_simple_bin_op!(bitwise_or, lhs, rhs, BitwiseOr, {
     Int, Int => Ok(Value::Int(lhs | rhs)),
     Byte, Byte => Ok(Value::Byte(lhs | rhs)),
+    // The union of two sets
+    Set, Set => Ok(Value::Set(Rc::new(RefCell::new(
+        lhs.borrow().union(&rhs.borrow()).map(Value::reference_copy).collect()
+    )))),
 });
 
 impl_simple_bin_op!(bitwise_and, lhs, rhs, BitwiseAnd, {
     Int, Int => Ok(Value::Int(lhs & rhs)),
     Byte, Byte => Ok(Value::Byte(lhs & rhs)),
+    // The intersection of two sets
+    Set, Set => Ok(Value::Set(Rc::new(RefCell::new(
+        lhs.borrow().intersection(&rhs.borrow()).map(Value::reference_copy).collect()
+    )))),
 });
 
 impl_simple_bin_op!(shift_left, lhs, rhs, ShiftLeft, {
@@ -147,6 +157,10 @@ impl_simple_bin_op!(subtraction, lhs, rhs, Subtraction, {
     Int, Int => Ok(Value::Int(lhs - rhs)),
     Byte, Byte => Ok(Value::Byte(lhs.wrapping_sub(rhs))),
     Float, Float => Ok(Value::Float(lhs - rhs)),
+    // The difference of two sets (the elements of lhs which aren't in rhs)
+    Set, Set => Ok(Value::Set(Rc::new(RefCell::new(
+        lhs.borrow().difference(&rhs.borrow()).map(Value::reference_copy).collect()
+    )))),
 });
 
 impl_simple_bin_op!(multiplication, lhs, rhs, Multiplication, {
@@ -172,13 +186,11 @@ impl_simple_bin_op!(division, lhs, rhs, Division, {
     } else {
         Ok(Value::Int(lhs / rhs))
     },
-    Byte, Byte => if rhs == 0 {
-        Err(OperationError::Mathematical{
+    Byte, Byte => lhs.checked_div(rhs).map(Value::Byte).ok_or_else(|| {
+        OperationError::Mathematical{
             why: "division by zero".to_string()
-        })
-    } else {
-        Ok(Value::Byte(lhs / rhs))
-    },
+        }
+    }),
     Float, Float => Ok(Value::Float(lhs / rhs)),
 });
 
@@ -0,0 +1,174 @@
+use std::{cell::RefCell, rc::Rc};
+
+use indexmap::IndexMap;
+
+use crate::{ast::MatchPattern, runtime_state::RuntimeState, value::Value};
+
+use super::{
+    comparisons::{icelang_contains, icelang_eq},
+    core::interpret_expression,
+    literals::interpret_literal_range,
+    runtime_result::RuntimeResult,
+};
+
+/// Checks whether a value matches a match pattern. If it does, the values of
+/// the pattern's bindings are added to `bindings`. If it doesn't, `bindings`
+/// is left unchanged
+///
+/// Evaluating the expressions in a pattern (such as the keys in a dict
+/// pattern) may cause an error, in which case the error is returned
+pub fn matches_pattern<'source, 'a>(
+    pattern: &'a MatchPattern<'source>,
+    value: &Value<'source>,
+    state: &mut RuntimeState<'source>,
+    bindings: &mut Vec<(&'a str, Value<'source>)>,
+) -> RuntimeResult<'source, bool> {
+    let bindings_before = bindings.len();
+    let matched = match_pattern(pattern, value, state, bindings)?;
+    if !matched {
+        bindings.truncate(bindings_before);
+    }
+
+    Ok(matched)
+}
+
+/// Does the work of `matches_pattern`, but may leave partial bindings behind
+/// if the value doesn't match
+fn match_pattern<'source, 'a>(
+    pattern: &'a MatchPattern<'source>,
+    value: &Value<'source>,
+    state: &mut RuntimeState<'source>,
+    bindings: &mut Vec<(&'a str, Value<'source>)>,
+) -> RuntimeResult<'source, bool> {
+    match pattern {
+        MatchPattern::Wildcard { pos: _ } => Ok(true),
+        MatchPattern::Binding { ident, pos: _ } => {
+            bindings.push((ident, value.reference_copy()));
+
+            Ok(true)
+        }
+        MatchPattern::Value(expected) => {
+            let expected = interpret_expression(expected, state)?;
+
+            // Values of types that can't be compared simply don't match
+            Ok(icelang_eq(value, &expected).unwrap_or(false))
+        }
+        MatchPattern::Range(range) => {
+            let range = interpret_literal_range(range, state)?;
+
+            // Only ints can be contained in a range
+            Ok(icelang_contains(&range, value).unwrap_or(false))
+        }
+        MatchPattern::Type {
+            icelang_type,
+            inner,
+            pos: _,
+        } => {
+            if value.icelang_type() != *icelang_type {
+                return Ok(false);
+            }
+
+            match inner {
+                Some(inner) => match_pattern(inner, value, state, bindings),
+                None => Ok(true),
+            }
+        }
+        MatchPattern::List {
+            elements,
+            rest,
+            pos: _,
+        } => {
+            let Value::List(list) = value else {
+                return Ok(false);
+            };
+            let list: Vec<_> = list.borrow().iter().map(Value::reference_copy).collect();
+
+            // Check the length first
+            let length_matches = match rest {
+                Some(_) => list.len() >= elements.len(),
+                None => list.len() == elements.len(),
+            };
+            if !length_matches {
+                return Ok(false);
+            }
+
+            // Match the elements before and after the rest element
+            let rest_index = rest.as_ref().map_or(elements.len(), |(index, _)| *index);
+            let after_rest_count = elements.len() - rest_index;
+            let after_rest_start = list.len() - after_rest_count;
+            for (element_pattern, element) in elements[..rest_index].iter().zip(&list) {
+                if !match_pattern(element_pattern, element, state, bindings)? {
+                    return Ok(false);
+                }
+            }
+            for (element_pattern, element) in
+                elements[rest_index..].iter().zip(&list[after_rest_start..])
+            {
+                if !match_pattern(element_pattern, element, state, bindings)? {
+                    return Ok(false);
+                }
+            }
+
+            // Match the rest element against the remaining elements
+            match rest {
+                Some((_, rest)) => {
+                    let remaining: Vec<_> = list[rest_index..after_rest_start]
+                        .iter()
+                        .map(Value::reference_copy)
+                        .collect();
+                    let remaining = Value::List(Rc::new(RefCell::new(remaining.into())));
+                    match_pattern(rest, &remaining, state, bindings)
+                }
+                None => Ok(true),
+            }
+        }
+        MatchPattern::Dict {
+            entries,
+            rest,
+            pos: _,
+        } => {
+            let Value::Dict(dict) = value else {
+                return Ok(false);
+            };
+
+            let mut looked_up_keys = Vec::with_capacity(entries.len());
+            for (key_node, entry_pattern) in entries {
+                let key = interpret_expression(key_node, state)?;
+                let Some(entry) = dict.borrow().get(&key).map(Value::reference_copy) else {
+                    return Ok(false);
+                };
+                if !match_pattern(entry_pattern, &entry, state, bindings)? {
+                    return Ok(false);
+                }
+                looked_up_keys.push(key);
+            }
+
+            // Match the rest element against the remaining entries
+            match rest {
+                Some(rest) => {
+                    let remaining: IndexMap<_, _> = dict
+                        .borrow()
+                        .iter()
+                        .filter(|(key, _)| !looked_up_keys.contains(key))
+                        .map(|(key, value)| (key.reference_copy(), value.reference_copy()))
+                        .collect();
+                    let remaining = Value::Dict(Rc::new(RefCell::new(remaining.into())));
+                    match_pattern(rest, &remaining, state, bindings)
+                }
+                None => Ok(true),
+            }
+        }
+        MatchPattern::Alternatives {
+            alternatives,
+            pos: _,
+        } => {
+            for alternative in alternatives {
+                if matches_pattern(alternative, value, state, bindings)? {
+                    return Ok(true);
+                }
+            }
+
+            Ok(false)
+        }
+    }
+}
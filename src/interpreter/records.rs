@@ -0,0 +1,137 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    ast::{AstNodeRecordDeclaration, AstNodeRecordLiteral},
+    error::runtime_error::RuntimeError,
+    function::FunctionValue,
+    record::{Record, RecordType},
+    runtime_state::RuntimeState,
+    value::Value,
+};
+
+use super::{
+    core::interpret_expression,
+    runtime_result::{NonLinearControlFlow, RuntimeResult},
+};
+
+/// Interprets an AstNodeRecordDeclaration
+///
+/// Declaring a record type binds its constructor to a variable with the same
+/// name as the record type
+pub fn interpret_record_declaration<'source>(
+    record_declaration: &AstNodeRecordDeclaration<'source>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, ()> {
+    // Ensure none of the field names are the same
+    let fields = record_declaration.fields();
+    for (i, (field_1_name, field_1_pos)) in fields.iter().enumerate() {
+        for (field_2_name, _) in fields.iter().take(i) {
+            if field_1_name == field_2_name {
+                return Err(NonLinearControlFlow::RuntimeError(
+                    RuntimeError::new_identifier_already_declared_error(
+                        field_1_pos.clone(),
+                        state.scope_display_name().to_string(),
+                        field_1_name.to_string(),
+                    ),
+                ));
+            }
+        }
+    }
+
+    let name = record_declaration.name();
+    if state.lookup_local_variable(name).is_some() {
+        return Err(NonLinearControlFlow::RuntimeError(
+            RuntimeError::new_identifier_already_declared_error(
+                record_declaration.pos().clone(),
+                state.scope_display_name().to_string(),
+                name.to_string(),
+            ),
+        ));
+    }
+
+    let record_type = RecordType::new(
+        name.to_string(),
+        fields.iter().map(|(field, _)| field.clone()).collect(),
+    );
+    state.declare_variable(
+        name.to_string(),
+        Value::Function(FunctionValue::RecordConstructor(Rc::new(record_type))),
+    );
+
+    Ok(())
+}
+
+/// Interprets an AstNodeRecordLiteral
+pub fn interpret_record_literal<'source>(
+    record_literal: &AstNodeRecordLiteral<'source>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, Value<'source>> {
+    let record_type = match interpret_expression(record_literal.record_type(), state)? {
+        Value::Function(FunctionValue::RecordConstructor(record_type)) => record_type,
+        value => {
+            return Err(NonLinearControlFlow::RuntimeError(
+                RuntimeError::new_type_error(
+                    record_literal.record_type().pos().clone(),
+                    state.scope_display_name().to_string(),
+                    format!(
+                        "expected a record type, got value of type {}",
+                        value.icelang_type()
+                    ),
+                ),
+            ));
+        }
+    };
+
+    // Evaluate the fields in source order, slotting each into its declared
+    // position
+    let mut fields: Vec<Option<Value<'source>>> = Vec::new();
+    fields.resize_with(record_type.field_names().len(), || None);
+    for (field_name, value_node, field_pos) in record_literal.fields() {
+        let Some(index) = record_type.field_index(field_name) else {
+            return Err(NonLinearControlFlow::RuntimeError(
+                RuntimeError::new_invalid_member_access_error(
+                    field_pos.clone(),
+                    state.scope_display_name().to_string(),
+                    format!(
+                        "record type {} has no field \"{field_name}\"",
+                        record_type.name()
+                    ),
+                ),
+            ));
+        };
+
+        if fields[index].is_some() {
+            return Err(NonLinearControlFlow::RuntimeError(
+                RuntimeError::new_invalid_member_access_error(
+                    field_pos.clone(),
+                    state.scope_display_name().to_string(),
+                    format!("field \"{field_name}\" is specified more than once"),
+                ),
+            ));
+        }
+
+        fields[index] = Some(interpret_expression(value_node, state)?);
+    }
+
+    // Every field must be given a value
+    let fields = fields
+        .into_iter()
+        .zip(record_type.field_names())
+        .map(|(value, field_name)| {
+            value.ok_or_else(|| {
+                NonLinearControlFlow::RuntimeError(RuntimeError::new_invalid_member_access_error(
+                    record_literal.pos().clone(),
+                    state.scope_display_name().to_string(),
+                    format!(
+                        "missing field \"{field_name}\" in {} literal",
+                        record_type.name()
+                    ),
+                ))
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Value::Record(Rc::new(RefCell::new(
+        Record::new(record_type, fields).into(),
+    ))))
+}
@@ -1,9 +1,10 @@
-use crate::error::runtime_error::RuntimeError;
+use crate::{error::runtime_error::RuntimeError, generator::ResumePoint};
 
-use super::jump_statement::JumpStatement;
+use super::{generators::Suspension, jump_statement::JumpStatement, thrown_value::ThrownValue};
 
-/// Represents some non-linear control flow, like a bubbling runtime error or
-/// a jump statement like `break`, `continue`, or `return`
+/// Represents some non-linear control flow, like a bubbling runtime error, a
+/// thrown value, a jump statement like `break`, `continue`, or `return`, or a
+/// generator suspending at a `yield`
 #[derive(Debug)]
 pub enum NonLinearControlFlow<'source> {
     /// A jump statement like `break`, `continue`, or `return`
@@ -11,6 +12,25 @@ pub enum NonLinearControlFlow<'source> {
 
     /// A runtime error that is bubbling up the call stack
     RuntimeError(RuntimeError<'source>),
+
+    /// A thrown value that is bubbling up the call stack
+    Throw(ThrownValue<'source>),
+
+    /// A generator suspending at a `yield`, which is bubbling up to the code
+    /// that resumed the generator
+    Yield(Suspension<'source>),
+}
+
+impl<'source> NonLinearControlFlow<'source> {
+    /// If this is a generator suspending, records where the construct it's
+    /// bubbling up through should resume (see `ResumePoint`)
+    pub(crate) fn resume_at(mut self, resume_point: impl FnOnce() -> ResumePoint<'source>) -> Self {
+        if let Self::Yield(suspension) = &mut self {
+            suspension.add_resume_point(resume_point());
+        }
+
+        self
+    }
 }
 
 /// A result containing either T or some non-linear control flow
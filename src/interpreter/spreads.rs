@@ -0,0 +1,137 @@
+use crate::{
+    ast::{AstNode, AstNodeSpread},
+    error::runtime_error::RuntimeError,
+    runtime_state::RuntimeState,
+    value::Value,
+};
+
+use super::{
+    core::interpret_expression,
+    generators::resume_generator,
+    runtime_result::{NonLinearControlFlow, RuntimeResult},
+};
+
+/// Interprets a list of expressions (the elements of a list literal, or the
+/// positional arguments of a function call) in order, expanding any spreads
+/// into the values they contain
+pub fn interpret_spreadable_expressions<'source>(
+    nodes: &[AstNode<'source>],
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, Vec<Value<'source>>> {
+    let mut values = Vec::with_capacity(nodes.len());
+
+    for node in nodes {
+        match node {
+            AstNode::Spread(spread) => interpret_spread(spread, &mut values, state)?,
+            node => values.push(interpret_expression(node, state)?),
+        }
+    }
+
+    Ok(values)
+}
+
+/// Interprets an AstNodeSpread, adding each of the values it contains to
+/// `values`
+///
+/// Strings are spread into their characters, lists, sets, and ranges into their
+/// elements, and generators into the rest of the values they produce
+fn interpret_spread<'source>(
+    node: &AstNodeSpread<'source>,
+    values: &mut Vec<Value<'source>>,
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, ()> {
+    match interpret_expression(node.body(), state)? {
+        Value::String(string) => {
+            values.extend(string.chars().map(|c| Value::String(c.to_string().into())));
+        }
+        Value::List(list) => values.extend(list.borrow().iter().map(Value::reference_copy)),
+        Value::Set(set) => values.extend(set.borrow().iter().map(Value::reference_copy)),
+        Value::Range(range) => values.extend(range.iter().map(Value::Int)),
+        Value::Generator(generator) => {
+            while let Some(value) = resume_generator(&generator, node.pos(), state)? {
+                values.push(value);
+            }
+        }
+        value => {
+            return Err(NonLinearControlFlow::RuntimeError(
+                RuntimeError::new_type_error(
+                    node.pos().clone(),
+                    state.scope_display_name().to_string(),
+                    format!(
+                        "expected string, list, set, range, or generator to spread, got value of type {}",
+                        value.icelang_type()
+                    ),
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::run_with_error_of;
+
+    #[test]
+    fn test_spread_arguments() {
+        run_with_error_of(
+            r#"
+            fn arity(a) { return "one"; };
+            fn arity(a, b) { return "two"; };
+            fn arity(a, b, c) { return "three"; };
+            fn rest(first, [others]) { return [first, others]; };
+
+            assert(arity(...[1, 2]) == "two");
+            assert(arity(1, ...[2, 3]) == "three");
+            assert(arity(...[], 1) == "one");
+            assert(arity(..."ab") == "two" && arity(...1..4) == "three");
+            assert(f"{rest(...[1, 2, 3])}" == "[1, [2, 3]]");
+
+            assert(error_of(fn() { arity(...[1, 2, 3, 4]); }) == "invalid_overload: no overload for function \"arity\" that takes 4 arguments: available overloads are arity(a), arity(a, b), arity(a, b, c)");
+            assert(error_of(fn() { arity(...{"a": 1}); }) == "type: expected string, list, set, range, or generator to spread, got value of type dict");
+            "#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_spread_list_elements() {
+        run_with_error_of(
+            r#"
+            fn numbers() {
+                yield 1;
+                yield 2;
+            };
+            let generator = numbers();
+            assert(f"{[0, ...generator, ...generator]}" == "[0, 1, 2]");
+            assert(f"{[..."héy", ...{3}, ...4..=5]}" == "[h, é, y, 3, 4, 5]");
+
+            // Spreading copies the elements, not the values they refer to
+            let inner = [1];
+            let outer = [...[inner]];
+            inner[0] = 2;
+            assert(f"{outer}" == "[[2]]");
+
+            assert(error_of(fn() { [...5]; }) == "type: expected string, list, set, range, or generator to spread, got value of type int");
+            "#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_spread_dict_entries() {
+        run_with_error_of(
+            r#"
+            let defaults = {"a": 1};
+            let merged = {...defaults, "b": 2, ...{"a": 3}};
+            assert(len(keys(merged)) == 2 && merged["a"] == 3 && merged["b"] == 2);
+            assert(len(keys(defaults)) == 1 && defaults["a"] == 1);
+            assert({"a": 0, ...defaults}["a"] == 1);
+
+            assert(error_of(fn() { {...[1]}; }) == "type: expected dict to spread, got value of type list");
+            "#,
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{
+    error::{runtime_error::RuntimeError, StackTrace},
+    source_range::SourceRange,
+    value::Value,
+};
+
+/// A value thrown by a `throw` statement, which is bubbling up the call stack
+#[derive(Debug)]
+pub struct ThrownValue<'source> {
+    value: Value<'source>,
+    pos: SourceRange<'source>,
+    stack_trace: StackTrace<'source>,
+}
+
+impl<'source> ThrownValue<'source> {
+    /// Constructs a new ThrownValue with the given value and pos
+    pub fn new(
+        value: Value<'source>,
+        pos: SourceRange<'source>,
+        scope_display_name: String,
+    ) -> Self {
+        let mut stack_trace = StackTrace::new();
+        stack_trace.add_bottom(scope_display_name, pos.clone());
+
+        Self {
+            value,
+            pos,
+            stack_trace,
+        }
+    }
+
+    /// Gets the thrown value, consuming `self`
+    pub fn into_value(self) -> Value<'source> {
+        self.value
+    }
+
+    /// Returns a mutable reference to the stack trace of the thrown value
+    pub fn stack_trace_mut(&mut self) -> &mut StackTrace<'source> {
+        &mut self.stack_trace
+    }
+
+    /// Converts the thrown value into a RuntimeError, for when it was never
+    /// caught
+    pub fn into_runtime_error(self) -> RuntimeError<'source> {
+        RuntimeError::UncaughtThrow {
+            pos: self.pos,
+            stack_trace: self.stack_trace,
+            value: self.value.icelang_debug(),
+        }
+    }
+}
+
+impl Clone for ThrownValue<'_> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.deep_copy(),
+            pos: self.pos.clone(),
+            stack_trace: self.stack_trace.clone(),
+        }
+    }
+}
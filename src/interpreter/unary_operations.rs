@@ -23,7 +23,7 @@ macro_rules! impl_simple_unary_op {
         fn $func_name<'source>(
             $node: &AstNodeUnaryOperation<'source>,
             $state: &mut RuntimeState<'source>,
-        ) -> RuntimeResult<'source, Value> {
+        ) -> RuntimeResult<'source, Value<'source>> {
             assert!($node.operation() == UnaryOperationKind::$op_kind);
 
             let operand = interpret_expression($node.operand(), $state)?;
@@ -70,7 +70,7 @@ impl_simple_unary_op!(interpret_negation, node, state, operand, Negation, {
 pub fn interpret_unary_operation<'source>(
     node: &AstNodeUnaryOperation<'source>,
     state: &mut RuntimeState<'source>,
-) -> RuntimeResult<'source, Value> {
+) -> RuntimeResult<'source, Value<'source>> {
     match node.operation() {
         UnaryOperationKind::Not => interpret_not(node, state),
         UnaryOperationKind::Identity => interpret_identity(node, state),
@@ -7,6 +7,8 @@ use crate::{
 
 use super::{
     core::interpret_expression,
+    destructuring::declare_pattern,
+    functions::lookup_function_value,
     runtime_result::{NonLinearControlFlow, RuntimeResult},
 };
 
@@ -15,12 +17,17 @@ pub fn interpret_variable_declaration<'source>(
     variable_declaration: &AstNodeVariableDeclaration<'source>,
     state: &mut RuntimeState<'source>,
 ) -> RuntimeResult<'source, ()> {
-    for (ident, value_expr, pos) in variable_declaration.declarations() {
+    for (pattern, value_expr, pos) in variable_declaration.declarations() {
         let value = match value_expr {
             Some(value_expr) => interpret_expression(value_expr, state)?,
             None => Value::Null,
         };
 
+        let Some(ident) = pattern.ident() else {
+            declare_pattern(pattern, value, variable_declaration.is_constant(), state)?;
+            continue;
+        };
+
         if state.lookup_local_variable(ident).is_some() {
             return Err(NonLinearControlFlow::RuntimeError(
                 RuntimeError::new_identifier_already_declared_error(
@@ -31,7 +38,11 @@ pub fn interpret_variable_declaration<'source>(
             ));
         }
 
-        state.declare_variable(ident.to_string(), value);
+        if variable_declaration.is_constant() {
+            state.declare_constant(ident.to_string(), value);
+        } else {
+            state.declare_variable(ident.to_string(), value);
+        }
     }
 
     Ok(())
@@ -40,10 +51,17 @@ pub fn interpret_variable_declaration<'source>(
 /// Interprets a variable access AstNode
 pub fn interpret_variable_access<'source>(
     variable_access: &AstNodeVariableAccess<'source>,
-    state: &mut RuntimeState,
-) -> RuntimeResult<'source, Value> {
-    match state.lookup_variable(variable_access.ident()) {
-        Some(value) => Ok(value.reference_copy()),
+    state: &mut RuntimeState<'source>,
+) -> RuntimeResult<'source, Value<'source>> {
+    // Whichever of a variable or a function is declared in the innermost scope
+    // wins, and a variable also shadows a standard library function
+    if let Some(value) = state.lookup_unshadowed_variable(variable_access.ident()) {
+        return Ok(value);
+    }
+
+    // If there's no variable by this name, it may refer to a function
+    match lookup_function_value(variable_access.ident(), state) {
+        Some(function) => Ok(Value::Function(function)),
         None => Err(NonLinearControlFlow::RuntimeError(
             RuntimeError::new_undefined_reference_error(
                 variable_access.pos().clone(),
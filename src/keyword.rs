@@ -21,6 +21,8 @@ pub enum Keyword {
     For,
     /// The "in" keyword
     In,
+    /// The "not" keyword
+    Not,
     /// The "match" keyword
     Match,
     /// The "break" keyword
@@ -29,10 +31,26 @@ pub enum Keyword {
     Continue,
     /// The "return" keyword
     Return,
+    /// The "try" keyword
+    Try,
+    /// The "catch" keyword
+    Catch,
+    /// The "throw" keyword
+    Throw,
     /// The "fn" keyword
     Fn,
     /// The "let" keyword
     Let,
+    /// The "const" keyword
+    Const,
+    /// The "yield" keyword
+    Yield,
+    /// The "import" keyword
+    Import,
+    /// The "record" keyword
+    Record,
+    /// The "as" keyword
+    As,
     /// The "int" keyword
     Int,
     /// The "byte" keyword
@@ -47,6 +65,8 @@ pub enum Keyword {
     List,
     /// The "dict" keyword
     Dict,
+    /// The "set" keyword
+    Set,
     /// The "true" bool keyword literal
     True,
     /// The "false" bool keyword literal
@@ -70,12 +90,21 @@ impl TryFrom<&str> for Keyword {
             "while" => Ok(Self::While),
             "for" => Ok(Self::For),
             "in" => Ok(Self::In),
+            "not" => Ok(Self::Not),
             "match" => Ok(Self::Match),
             "break" => Ok(Self::Break),
             "continue" => Ok(Self::Continue),
             "return" => Ok(Self::Return),
+            "try" => Ok(Self::Try),
+            "catch" => Ok(Self::Catch),
+            "throw" => Ok(Self::Throw),
             "fn" => Ok(Self::Fn),
             "let" => Ok(Self::Let),
+            "const" => Ok(Self::Const),
+            "yield" => Ok(Self::Yield),
+            "import" => Ok(Self::Import),
+            "record" => Ok(Self::Record),
+            "as" => Ok(Self::As),
             "int" => Ok(Self::Int),
             "byte" => Ok(Self::Byte),
             "float" => Ok(Self::Float),
@@ -83,6 +112,7 @@ impl TryFrom<&str> for Keyword {
             "string" => Ok(Self::String),
             "list" => Ok(Self::List),
             "dict" => Ok(Self::Dict),
+            "set" => Ok(Self::Set),
             "true" => Ok(Self::True),
             "false" => Ok(Self::False),
             "null" => Ok(Self::Null),
@@ -105,12 +135,21 @@ impl Display for Keyword {
                 Self::While => "while",
                 Self::For => "for",
                 Self::In => "in",
+                Self::Not => "not",
                 Self::Match => "match",
                 Self::Break => "break",
                 Self::Continue => "continue",
                 Self::Return => "return",
+                Self::Try => "try",
+                Self::Catch => "catch",
+                Self::Throw => "throw",
                 Self::Fn => "fn",
                 Self::Let => "let",
+                Self::Const => "const",
+                Self::Yield => "yield",
+                Self::Import => "import",
+                Self::Record => "record",
+                Self::As => "as",
                 Self::Int => "int",
                 Self::Byte => "byte",
                 Self::Float => "float",
@@ -118,6 +157,7 @@ impl Display for Keyword {
                 Self::String => "string",
                 Self::List => "list",
                 Self::Dict => "dict",
+                Self::Set => "set",
                 Self::True => "true",
                 Self::False => "false",
                 Self::Null => "null",
@@ -139,12 +179,21 @@ impl Keyword {
             Keyword::While => false,
             Keyword::For => false,
             Keyword::In => false,
+            Keyword::Not => false,
             Keyword::Match => false,
             Keyword::Break => false,
             Keyword::Continue => false,
             Keyword::Return => false,
+            Keyword::Try => false,
+            Keyword::Catch => false,
+            Keyword::Throw => false,
             Keyword::Fn => false,
             Keyword::Let => false,
+            Keyword::Const => false,
+            Keyword::Yield => false,
+            Keyword::Import => false,
+            Keyword::Record => false,
+            Keyword::As => false,
             Keyword::Int => false,
             Keyword::Byte => false,
             Keyword::Float => false,
@@ -152,6 +201,7 @@ impl Keyword {
             Keyword::String => false,
             Keyword::List => false,
             Keyword::Dict => false,
+            Keyword::Set => false,
             Keyword::True => true,
             Keyword::False => true,
             Keyword::Null => true,
@@ -170,12 +220,21 @@ impl Keyword {
             Keyword::While => false,
             Keyword::For => false,
             Keyword::In => false,
+            Keyword::Not => false,
             Keyword::Match => false,
             Keyword::Break => false,
             Keyword::Continue => false,
             Keyword::Return => false,
+            Keyword::Try => false,
+            Keyword::Catch => false,
+            Keyword::Throw => false,
             Keyword::Fn => false,
             Keyword::Let => false,
+            Keyword::Const => false,
+            Keyword::Yield => false,
+            Keyword::Import => false,
+            Keyword::Record => false,
+            Keyword::As => false,
             Keyword::Int => false,
             Keyword::Byte => false,
             Keyword::Float => false,
@@ -183,6 +242,7 @@ impl Keyword {
             Keyword::String => false,
             Keyword::List => false,
             Keyword::Dict => false,
+            Keyword::Set => false,
             Keyword::True => true,
             Keyword::False => true,
             Keyword::Null => false,
@@ -201,12 +261,21 @@ impl Keyword {
             Keyword::While => false,
             Keyword::For => false,
             Keyword::In => false,
+            Keyword::Not => false,
             Keyword::Match => false,
             Keyword::Break => false,
             Keyword::Continue => false,
             Keyword::Return => false,
+            Keyword::Try => false,
+            Keyword::Catch => false,
+            Keyword::Throw => false,
             Keyword::Fn => false,
             Keyword::Let => false,
+            Keyword::Const => false,
+            Keyword::Yield => false,
+            Keyword::Import => false,
+            Keyword::Record => false,
+            Keyword::As => false,
             Keyword::Int => true,
             Keyword::Byte => true,
             Keyword::Float => true,
@@ -214,6 +283,7 @@ impl Keyword {
             Keyword::String => true,
             Keyword::List => true,
             Keyword::Dict => true,
+            Keyword::Set => true,
             Keyword::True => false,
             Keyword::False => false,
             Keyword::Null => true,
@@ -232,12 +302,21 @@ impl Keyword {
             Keyword::While => false,
             Keyword::For => false,
             Keyword::In => false,
+            Keyword::Not => false,
             Keyword::Match => false,
             Keyword::Break => false,
             Keyword::Continue => false,
             Keyword::Return => false,
+            Keyword::Try => false,
+            Keyword::Catch => false,
+            Keyword::Throw => false,
             Keyword::Fn => false,
             Keyword::Let => false,
+            Keyword::Const => false,
+            Keyword::Yield => false,
+            Keyword::Import => false,
+            Keyword::Record => false,
+            Keyword::As => false,
             Keyword::Int => true,
             Keyword::Byte => true,
             Keyword::Float => true,
@@ -245,6 +324,7 @@ impl Keyword {
             Keyword::String => true,
             Keyword::List => true,
             Keyword::Dict => true,
+            Keyword::Set => true,
             Keyword::True => false,
             Keyword::False => false,
             Keyword::Null => false,
@@ -266,12 +346,21 @@ impl Keyword {
             Keyword::While => None,
             Keyword::For => None,
             Keyword::In => None,
+            Keyword::Not => None,
             Keyword::Match => None,
             Keyword::Break => None,
             Keyword::Continue => None,
             Keyword::Return => None,
+            Keyword::Try => None,
+            Keyword::Catch => None,
+            Keyword::Throw => None,
             Keyword::Fn => None,
             Keyword::Let => None,
+            Keyword::Const => None,
+            Keyword::Yield => None,
+            Keyword::Import => None,
+            Keyword::Record => None,
+            Keyword::As => None,
             Keyword::Int => Some(IcelangType::Int),
             Keyword::Byte => Some(IcelangType::Byte),
             Keyword::Float => Some(IcelangType::Float),
@@ -279,6 +368,7 @@ impl Keyword {
             Keyword::String => Some(IcelangType::String),
             Keyword::List => Some(IcelangType::List),
             Keyword::Dict => Some(IcelangType::Dict),
+            Keyword::Set => Some(IcelangType::Set),
             Keyword::True => Some(IcelangType::Bool),
             Keyword::False => Some(IcelangType::Bool),
             Keyword::Null => Some(IcelangType::Null),
@@ -300,12 +390,21 @@ mod tests {
         assert_eq!(Keyword::While.to_string(), "while");
         assert_eq!(Keyword::For.to_string(), "for");
         assert_eq!(Keyword::In.to_string(), "in");
+        assert_eq!(Keyword::Not.to_string(), "not");
         assert_eq!(Keyword::Match.to_string(), "match");
         assert_eq!(Keyword::Break.to_string(), "break");
         assert_eq!(Keyword::Continue.to_string(), "continue");
         assert_eq!(Keyword::Return.to_string(), "return");
+        assert_eq!(Keyword::Try.to_string(), "try");
+        assert_eq!(Keyword::Catch.to_string(), "catch");
+        assert_eq!(Keyword::Throw.to_string(), "throw");
         assert_eq!(Keyword::Fn.to_string(), "fn");
         assert_eq!(Keyword::Let.to_string(), "let");
+        assert_eq!(Keyword::Const.to_string(), "const");
+        assert_eq!(Keyword::Yield.to_string(), "yield");
+        assert_eq!(Keyword::Import.to_string(), "import");
+        assert_eq!(Keyword::Record.to_string(), "record");
+        assert_eq!(Keyword::As.to_string(), "as");
         assert_eq!(Keyword::Int.to_string(), "int");
         assert_eq!(Keyword::Byte.to_string(), "byte");
         assert_eq!(Keyword::Bool.to_string(), "bool");
@@ -313,6 +412,7 @@ mod tests {
         assert_eq!(Keyword::String.to_string(), "string");
         assert_eq!(Keyword::List.to_string(), "list");
         assert_eq!(Keyword::Dict.to_string(), "dict");
+        assert_eq!(Keyword::Set.to_string(), "set");
         assert_eq!(Keyword::True.to_string(), "true");
         assert_eq!(Keyword::False.to_string(), "false");
         assert_eq!(Keyword::Null.to_string(), "null");
@@ -328,12 +428,21 @@ mod tests {
         assert_eq!(Ok(Keyword::While), Keyword::try_from("while"));
         assert_eq!(Ok(Keyword::For), Keyword::try_from("for"));
         assert_eq!(Ok(Keyword::In), Keyword::try_from("in"));
+        assert_eq!(Ok(Keyword::Not), Keyword::try_from("not"));
         assert_eq!(Ok(Keyword::Match), Keyword::try_from("match"));
         assert_eq!(Ok(Keyword::Break), Keyword::try_from("break"));
         assert_eq!(Ok(Keyword::Continue), Keyword::try_from("continue"));
         assert_eq!(Ok(Keyword::Return), Keyword::try_from("return"));
+        assert_eq!(Ok(Keyword::Try), Keyword::try_from("try"));
+        assert_eq!(Ok(Keyword::Catch), Keyword::try_from("catch"));
+        assert_eq!(Ok(Keyword::Throw), Keyword::try_from("throw"));
         assert_eq!(Ok(Keyword::Fn), Keyword::try_from("fn"));
         assert_eq!(Ok(Keyword::Let), Keyword::try_from("let"));
+        assert_eq!(Ok(Keyword::Const), Keyword::try_from("const"));
+        assert_eq!(Ok(Keyword::Yield), Keyword::try_from("yield"));
+        assert_eq!(Ok(Keyword::Import), Keyword::try_from("import"));
+        assert_eq!(Ok(Keyword::Record), Keyword::try_from("record"));
+        assert_eq!(Ok(Keyword::As), Keyword::try_from("as"));
         assert_eq!(Ok(Keyword::Int), Keyword::try_from("int"));
         assert_eq!(Ok(Keyword::Byte), Keyword::try_from("byte"));
         assert_eq!(Ok(Keyword::Float), Keyword::try_from("float"));
@@ -341,6 +450,7 @@ mod tests {
         assert_eq!(Ok(Keyword::String), Keyword::try_from("string"));
         assert_eq!(Ok(Keyword::List), Keyword::try_from("list"));
         assert_eq!(Ok(Keyword::Dict), Keyword::try_from("dict"));
+        assert_eq!(Ok(Keyword::Set), Keyword::try_from("set"));
         assert_eq!(Ok(Keyword::True), Keyword::try_from("true"));
         assert_eq!(Ok(Keyword::False), Keyword::try_from("false"));
         assert_eq!(Ok(Keyword::Null), Keyword::try_from("null"));
@@ -372,12 +482,21 @@ mod tests {
         assert_eq!(Keyword::While.can_be_literal(), false);
         assert_eq!(Keyword::For.can_be_literal(), false);
         assert_eq!(Keyword::In.can_be_literal(), false);
+        assert_eq!(Keyword::Not.can_be_literal(), false);
         assert_eq!(Keyword::Match.can_be_literal(), false);
         assert_eq!(Keyword::Break.can_be_literal(), false);
         assert_eq!(Keyword::Continue.can_be_literal(), false);
         assert_eq!(Keyword::Return.can_be_literal(), false);
+        assert_eq!(Keyword::Try.can_be_literal(), false);
+        assert_eq!(Keyword::Catch.can_be_literal(), false);
+        assert_eq!(Keyword::Throw.can_be_literal(), false);
         assert_eq!(Keyword::Fn.can_be_literal(), false);
         assert_eq!(Keyword::Let.can_be_literal(), false);
+        assert_eq!(Keyword::Const.can_be_literal(), false);
+        assert_eq!(Keyword::Yield.can_be_literal(), false);
+        assert_eq!(Keyword::Import.can_be_literal(), false);
+        assert_eq!(Keyword::Record.can_be_literal(), false);
+        assert_eq!(Keyword::As.can_be_literal(), false);
         assert_eq!(Keyword::Int.can_be_literal(), false);
         assert_eq!(Keyword::Byte.can_be_literal(), false);
         assert_eq!(Keyword::Float.can_be_literal(), false);
@@ -385,6 +504,7 @@ mod tests {
         assert_eq!(Keyword::String.can_be_literal(), false);
         assert_eq!(Keyword::List.can_be_literal(), false);
         assert_eq!(Keyword::Dict.can_be_literal(), false);
+        assert_eq!(Keyword::Set.can_be_literal(), false);
         assert_eq!(Keyword::True.can_be_literal(), true);
         assert_eq!(Keyword::False.can_be_literal(), true);
         assert_eq!(Keyword::Null.can_be_literal(), true);
@@ -401,12 +521,21 @@ mod tests {
         assert_eq!(Keyword::While.can_only_be_literal(), false);
         assert_eq!(Keyword::For.can_only_be_literal(), false);
         assert_eq!(Keyword::In.can_only_be_literal(), false);
+        assert_eq!(Keyword::Not.can_only_be_literal(), false);
         assert_eq!(Keyword::Match.can_only_be_literal(), false);
         assert_eq!(Keyword::Break.can_only_be_literal(), false);
         assert_eq!(Keyword::Continue.can_only_be_literal(), false);
         assert_eq!(Keyword::Return.can_only_be_literal(), false);
+        assert_eq!(Keyword::Try.can_only_be_literal(), false);
+        assert_eq!(Keyword::Catch.can_only_be_literal(), false);
+        assert_eq!(Keyword::Throw.can_only_be_literal(), false);
         assert_eq!(Keyword::Fn.can_only_be_literal(), false);
         assert_eq!(Keyword::Let.can_only_be_literal(), false);
+        assert_eq!(Keyword::Const.can_only_be_literal(), false);
+        assert_eq!(Keyword::Yield.can_only_be_literal(), false);
+        assert_eq!(Keyword::Import.can_only_be_literal(), false);
+        assert_eq!(Keyword::Record.can_only_be_literal(), false);
+        assert_eq!(Keyword::As.can_only_be_literal(), false);
         assert_eq!(Keyword::Int.can_only_be_literal(), false);
         assert_eq!(Keyword::Byte.can_only_be_literal(), false);
         assert_eq!(Keyword::Float.can_only_be_literal(), false);
@@ -414,6 +543,7 @@ mod tests {
         assert_eq!(Keyword::String.can_only_be_literal(), false);
         assert_eq!(Keyword::List.can_only_be_literal(), false);
         assert_eq!(Keyword::Dict.can_only_be_literal(), false);
+        assert_eq!(Keyword::Set.can_only_be_literal(), false);
         assert_eq!(Keyword::True.can_only_be_literal(), true);
         assert_eq!(Keyword::False.can_only_be_literal(), true);
         assert_eq!(Keyword::Null.can_only_be_literal(), false);
@@ -430,12 +560,21 @@ mod tests {
         assert_eq!(Keyword::While.can_be_type(), false);
         assert_eq!(Keyword::For.can_be_type(), false);
         assert_eq!(Keyword::In.can_be_type(), false);
+        assert_eq!(Keyword::Not.can_be_type(), false);
         assert_eq!(Keyword::Match.can_be_type(), false);
         assert_eq!(Keyword::Break.can_be_type(), false);
         assert_eq!(Keyword::Continue.can_be_type(), false);
         assert_eq!(Keyword::Return.can_be_type(), false);
+        assert_eq!(Keyword::Try.can_be_type(), false);
+        assert_eq!(Keyword::Catch.can_be_type(), false);
+        assert_eq!(Keyword::Throw.can_be_type(), false);
         assert_eq!(Keyword::Fn.can_be_type(), false);
         assert_eq!(Keyword::Let.can_be_type(), false);
+        assert_eq!(Keyword::Const.can_be_type(), false);
+        assert_eq!(Keyword::Yield.can_be_type(), false);
+        assert_eq!(Keyword::Import.can_be_type(), false);
+        assert_eq!(Keyword::Record.can_be_type(), false);
+        assert_eq!(Keyword::As.can_be_type(), false);
         assert_eq!(Keyword::Int.can_be_type(), true);
         assert_eq!(Keyword::Byte.can_be_type(), true);
         assert_eq!(Keyword::Float.can_be_type(), true);
@@ -443,6 +582,7 @@ mod tests {
         assert_eq!(Keyword::String.can_be_type(), true);
         assert_eq!(Keyword::List.can_be_type(), true);
         assert_eq!(Keyword::Dict.can_be_type(), true);
+        assert_eq!(Keyword::Set.can_be_type(), true);
         assert_eq!(Keyword::True.can_be_type(), false);
         assert_eq!(Keyword::False.can_be_type(), false);
         assert_eq!(Keyword::Null.can_be_type(), true);
@@ -468,6 +608,7 @@ mod tests {
         assert_eq!(Keyword::String.icelang_type(), Some(IcelangType::String));
         assert_eq!(Keyword::List.icelang_type(), Some(IcelangType::List));
         assert_eq!(Keyword::Dict.icelang_type(), Some(IcelangType::Dict));
+        assert_eq!(Keyword::Set.icelang_type(), Some(IcelangType::Set));
         assert_eq!(Keyword::Null.icelang_type(), Some(IcelangType::Null));
     }
 }
@@ -10,7 +10,7 @@ use crate::{
     source_range::SourceRange,
     token::{
         FormattedStringLiteralSectionKind, Token, TokenFormattedStringLiteralSection, TokenIdent,
-        TokenKeyword, TokenLiteral, TokenPunctuator,
+        TokenKeyword, TokenLabel, TokenLiteral, TokenPunctuator,
     },
     value::Value,
 };
@@ -23,7 +23,20 @@ pub fn tokenize<'source>(
     let chars: Vec<char> = source_code.chars().collect();
     let mut index = 0;
     let mut tokens: Vec<Token> = Vec::new();
-    let mut brace_depth_stack: Vec<u32> = Vec::new();
+    // What's still unclosed in each replacement field we're currently inside
+    // of, which decides whether a ':' starts a format spec
+    #[derive(Default)]
+    struct ReplacementField {
+        // The number of unclosed brackets of any kind
+        bracket_depth: u32,
+        // The number of inline conditional '?'s outside of any brackets which
+        // haven't been matched by a ':' yet
+        unmatched_conditionals: u32,
+    }
+    let mut replacement_field_stack: Vec<ReplacementField> = Vec::new();
+    // The start index and contents of the format spec just read at the end of
+    // a replacement field, if any
+    let mut pending_format_spec: Option<(usize, String)> = None;
 
     while index < chars.len() {
         // Ignore whitespace
@@ -174,6 +187,11 @@ pub fn tokenize<'source>(
                             literal.push(chars[index]);
                             index += 1;
                         }
+                        // A second '.' means this is an int literal followed
+                        // by a range punctuator (e.g. "1..5")
+                        '.' if chars.get(index + 1) == Some(&'.') => {
+                            break;
+                        }
                         '.' => {
                             // We know now this literal is a float literal
                             kind = Float;
@@ -697,6 +715,7 @@ pub fn tokenize<'source>(
                                     raw,
                                     value,
                                     FormattedStringLiteralSectionKind::Complete,
+                                    None,
                                     SourceRange::new(
                                         source_code,
                                         source_file_name,
@@ -902,8 +921,8 @@ pub fn tokenize<'source>(
                             index += 1;
                         }
                         (Some('{'), _) => {
-                            // Entering a replacement field, push 0
-                            brace_depth_stack.push(0);
+                            // Entering a replacement field
+                            replacement_field_stack.push(ReplacementField::default());
 
                             // Add the '{'
                             raw.push(chars[index]);
@@ -916,6 +935,7 @@ pub fn tokenize<'source>(
                                     raw,
                                     value,
                                     FormattedStringLiteralSectionKind::Start,
+                                    None,
                                     SourceRange::new(
                                         source_code,
                                         source_file_name,
@@ -959,19 +979,84 @@ pub fn tokenize<'source>(
 
                 continue;
             }
+            // The ':' of an inline conditional outside of any brackets in a
+            // replacement field
+            (':', _)
+                if replacement_field_stack.last().is_some_and(|field| {
+                    field.bracket_depth == 0 && field.unmatched_conditionals > 0
+                }) =>
+            {
+                // This ':' matches the most recent '?', and will be tokenized
+                // later as a punctuator
+                replacement_field_stack
+                    .last_mut()
+                    .unwrap()
+                    .unmatched_conditionals -= 1;
+            }
+            // Format spec at the end of a replacement field
+            (':', _)
+                if replacement_field_stack
+                    .last()
+                    .is_some_and(|field| field.bracket_depth == 0) =>
+            {
+                // Store the starting index of the format spec
+                let start_index = index;
+
+                // Advance past the ':'
+                index += 1;
+
+                // Read characters into the format spec until we reach the '}'
+                // ending the replacement field
+                let mut format_spec = String::new();
+                loop {
+                    match chars.get(index) {
+                        Some('}') => break,
+                        Some(&c) if c != '"' && c != '{' => {
+                            format_spec.push(c);
+                            index += 1;
+                        }
+                        _ => {
+                            return Err(LexerError::new_invalid_literal(SourceRange::new(
+                                source_code,
+                                source_file_name,
+                                start_index,
+                                index.min(chars.len() - 1),
+                            )));
+                        }
+                    }
+                }
+
+                // The '}' is handled next, and the format spec becomes part of
+                // the formatted string literal section it starts
+                pending_format_spec = Some((start_index, format_spec));
+
+                continue;
+            }
             // Closing brace (relevant to formatted string literals)
             ('}', _) => {
-                match brace_depth_stack.last_mut() {
+                match replacement_field_stack.last_mut() {
                     // End of a replacement field
-                    Some(&mut 0) => {
-                        // We're exiting this replacement field, pop the 0
-                        brace_depth_stack.pop();
-
-                        // Store the starting index of the formatted string literal
-                        let start_index = index;
+                    Some(ReplacementField {
+                        bracket_depth: 0,
+                        unmatched_conditionals: _,
+                    }) => {
+                        // We're exiting this replacement field
+                        replacement_field_stack.pop();
+
+                        // Store the starting index of the formatted string
+                        // literal section, which includes the format spec of
+                        // the replacement field if it had one
+                        let (start_index, format_spec) = match pending_format_spec.take() {
+                            Some((start_index, format_spec)) => (start_index, Some(format_spec)),
+                            None => (index, None),
+                        };
 
-                        // Add the '}' to the formatted string literal section
-                        let mut raw = String::new();
+                        // Add the format spec and the '}' to the formatted
+                        // string literal section
+                        let mut raw = match &format_spec {
+                            Some(format_spec) => format!(":{format_spec}"),
+                            None => String::new(),
+                        };
                         let mut value = String::new();
                         raw.push(chars[index]);
                         index += 1;
@@ -991,6 +1076,7 @@ pub fn tokenize<'source>(
                                             raw,
                                             value,
                                             FormattedStringLiteralSectionKind::End,
+                                            format_spec,
                                             SourceRange::new(
                                                 source_code,
                                                 source_file_name,
@@ -1210,8 +1296,8 @@ pub fn tokenize<'source>(
                                     index += 1;
                                 }
                                 (Some('{'), _) => {
-                                    // Entering a replacement field, push 0
-                                    brace_depth_stack.push(0);
+                                    // Entering a replacement field
+                                    replacement_field_stack.push(ReplacementField::default());
 
                                     // Add the '{'
                                     raw.push(chars[index]);
@@ -1224,6 +1310,7 @@ pub fn tokenize<'source>(
                                             raw,
                                             value,
                                             FormattedStringLiteralSectionKind::Continuation,
+                                            format_spec,
                                             SourceRange::new(
                                                 source_code,
                                                 source_file_name,
@@ -1269,10 +1356,10 @@ pub fn tokenize<'source>(
                         continue;
                     }
                     // Closing brace inside a replacement field
-                    Some(brace_depth) => {
-                        // Decrement the brace depth of the current replacement
+                    Some(field) => {
+                        // Decrement the bracket depth of the current replacement
                         // field
-                        *brace_depth -= 1;
+                        field.bracket_depth -= 1;
 
                         // No need to tokenize the '}', it will be handled later
                         // as a punctuator
@@ -1284,21 +1371,74 @@ pub fn tokenize<'source>(
                     }
                 }
             }
-            // Opening brace (relevant to formatted string literals)
-            ('{', _) => {
-                // Opening brace inside a replacement field
-                if let Some(brace_depth) = brace_depth_stack.last_mut() {
-                    // Increment the brace depth of the current replacement
+            // Opening brackets (relevant to formatted string literals), including
+            // the '[' of an optional index, which is tokenized along with its '?'
+            ('{' | '(' | '[', _) | ('?', Some('[')) => {
+                // Opening bracket inside a replacement field
+                if let Some(field) = replacement_field_stack.last_mut() {
+                    // Increment the bracket depth of the current replacement
                     // field
-                    *brace_depth += 1;
+                    field.bracket_depth += 1;
 
-                    // No need to tokenize the '{', it will be handled later
-                    // as a punctuator
+                    // No need to tokenize the bracket, it will be handled
+                    // later as a punctuator
+                }
+            }
+            // Closing parentheses and square brackets (relevant to formatted
+            // string literals, since a ':' inside them doesn't start a format
+            // spec)
+            (')' | ']', _) => {
+                // Closing bracket inside a replacement field. An unmatched
+                // one is left for the parser to complain about
+                if let Some(field) = replacement_field_stack.last_mut() {
+                    field.bracket_depth = field.bracket_depth.saturating_sub(1);
+                }
+            }
+            // Other punctuators starting with a '?' (like "??" and "?."), which
+            // aren't part of an inline conditional
+            ('?', Some('?' | '.')) => {}
+            // The '?' of an inline conditional (relevant to formatted string
+            // literals, since its ':' doesn't start a format spec)
+            ('?', _) => {
+                if let Some(field) = replacement_field_stack
+                    .last_mut()
+                    .filter(|field| field.bracket_depth == 0)
+                {
+                    field.unmatched_conditionals += 1;
                 }
             }
             _ => { /* Not a string literal, carry on */ }
         }
 
+        // Loop labels
+        if chars[index] == '\''
+            && chars
+                .get(index + 1)
+                .is_some_and(|c| c.is_ascii_alphabetic() || *c == '_')
+        {
+            // Store the starting index of the label
+            let start_index = index;
+
+            // Advance past the "'"
+            index += 1;
+
+            // Keep adding characters until we reach the end of the label
+            let mut label = String::new();
+            while index < chars.len() && (chars[index].is_alphanumeric() || chars[index] == '_') {
+                label.push(chars[index]);
+                index += 1;
+            }
+
+            tokens.push(
+                TokenLabel::new(
+                    label,
+                    SourceRange::new(source_code, source_file_name, start_index, index - 1),
+                )
+                .into(),
+            );
+            continue;
+        }
+
         // Identifiers, keywords, and keyword literals
         if chars[index].is_ascii_alphabetic() || chars[index] == '_' {
             // Store the starting index of the identifier (or keyword (or
@@ -1369,7 +1509,10 @@ pub fn tokenize<'source>(
             | ('<', Some('<'), Some('='))
             | ('>', Some('>'), Some('='))
             | ('&', Some('&'), Some('='))
-            | ('|', Some('|'), Some('=')) => {
+            | ('|', Some('|'), Some('='))
+            | ('.', Some('.'), Some('.'))
+            | ('.', Some('.'), Some('='))
+            | ('?', Some('?'), Some('=')) => {
                 let start_index = index;
                 let mut punctuator = String::with_capacity(3);
                 for _ in 0..3 {
@@ -1395,7 +1538,9 @@ pub fn tokenize<'source>(
             | ('<', Some('<'), _)
             | ('>', Some('>'), _)
             | ('*', Some('*'), _)
-            | ('=', Some('>'), _) => {
+            | ('=', Some('>'), _)
+            | ('.', Some('.'), _)
+            | ('?', Some('?' | '.' | '['), _) => {
                 let start_index = index;
                 let mut punctuator = String::with_capacity(2);
                 for _ in 0..2 {
@@ -1494,7 +1639,8 @@ f\"{{}} {{ }}  }} }}\"
 f\"{9} + {10} = {2 + 2} is a {true} fact, {name}\"
 = += -= *= /= %= **= <<= >>= &= ^= |= &&= ||=
 ? : || && == != < > <= >= | ^ & << >> + - * / % ** !
-=> ; , . ( ) { } [ ]
+=> ; , . ( ) { } [ ] ... .. ..= 1..5 0..=9
+'outer '_ 'a1
 ";
         let source_file_name = "tokens.ice";
         let tokens: Vec<Token> = tokenize(source_code, source_file_name).unwrap();
@@ -1678,6 +1824,18 @@ f\"{9} + {10} = {2 + 2} is a {true} fact, {name}\"
             "[Token] Punctuator: }",
             "[Token] Punctuator: [",
             "[Token] Punctuator: ]",
+            "[Token] Punctuator: ...",
+            "[Token] Punctuator: ..",
+            "[Token] Punctuator: ..=",
+            "[Token] Literal (int): 1",
+            "[Token] Punctuator: ..",
+            "[Token] Literal (int): 5",
+            "[Token] Literal (int): 0",
+            "[Token] Punctuator: ..=",
+            "[Token] Literal (int): 9",
+            "[Token] Label: 'outer",
+            "[Token] Label: '_",
+            "[Token] Label: 'a1",
         ];
 
         for (token, expected) in tokens.zip(expected) {
@@ -1685,6 +1843,93 @@ f\"{9} + {10} = {2 + 2} is a {true} fact, {name}\"
         }
     }
 
+    #[test]
+    fn test_tokenize_format_specs() {
+        let source_code = "f\"{x:>8.3} and {y}{[1, 2][0:1]:?}{ {1: 2}[1]:*^5}{(a ? b : c):}\"";
+        let source_file_name = "format_specs.ice";
+        let tokens: Vec<Token> = tokenize(source_code, source_file_name).unwrap();
+
+        let sections: Vec<_> = tokens
+            .iter()
+            .filter_map(|token| match token {
+                Token::FormattedStringLiteralSection(section) => {
+                    Some((section.raw(), section.format_spec()))
+                }
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(
+            sections,
+            [
+                ("f\"{", None),
+                (":>8.3} and {", Some(">8.3")),
+                ("}{", None),
+                (":?}{", Some("?")),
+                (":*^5}{", Some("*^5")),
+                (":}\"", Some("")),
+            ]
+        );
+
+        // Colons inside brackets aren't format specs
+        assert_eq!(
+            tokens
+                .iter()
+                .filter(
+                    |token| matches!(token, Token::Punctuator(token) if token.punctuator() == ":")
+                )
+                .count(),
+            3
+        );
+
+        for source_code in ["f\"{x:>8\"", "f\"{x:{y}}\"", "f\"{x:"] {
+            assert!(tokenize(source_code, source_file_name).is_err());
+        }
+    }
+
+    #[test]
+    fn test_tokenize_format_specs_after_inline_conditionals() {
+        let source_file_name = "format_specs.ice";
+        let cases = [
+            // The ':' of an inline conditional doesn't start a format spec
+            ("f\"{a ? b : c}\"", 1, [None].as_slice()),
+            ("f\"{a ? b : c:>5}\"", 1, &[Some(">5")]),
+            ("f\"{a ? b ? c : d : e:?}\"", 2, &[Some("?")]),
+            ("f\"{a ? b : c ? d : e}\"", 2, &[None]),
+            // Neither do colons inside brackets within a conditional
+            ("f\"{a ? xs[1:2] : c:x}\"", 2, &[Some("x")]),
+            // Other punctuators starting with '?' aren't conditionals
+            ("f\"{a ?? b:>3}\"", 0, &[Some(">3")]),
+            ("f\"{a?.b:?}\"", 0, &[Some("?")]),
+            ("f\"{xs?[1:]:?}\"", 1, &[Some("?")]),
+            // Each replacement field tracks its own conditionals
+            ("f\"{a ? b : c}{d:?}\"", 1, &[None, Some("?")]),
+        ];
+
+        for (source_code, colon_count, format_specs) in cases {
+            let tokens = tokenize(source_code, source_file_name).unwrap();
+
+            let colons = tokens
+                .iter()
+                .filter(
+                    |token| matches!(token, Token::Punctuator(token) if token.punctuator() == ":"),
+                )
+                .count();
+            assert_eq!(colons, colon_count, "{source_code}");
+
+            // The first section starts the literal, so it never has a spec
+            let specs: Vec<_> = tokens
+                .iter()
+                .filter_map(|token| match token {
+                    Token::FormattedStringLiteralSection(section) => Some(section.format_spec()),
+                    _ => None,
+                })
+                .skip(1)
+                .collect();
+            assert_eq!(specs, format_specs, "{source_code}");
+        }
+    }
+
     mod test_tokenize_randomized {
         // use rand::{
         //     seq::{IteratorRandom, SliceRandom},
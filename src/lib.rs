@@ -6,13 +6,17 @@
 pub mod ast;
 mod call_stack;
 pub mod error;
+pub mod format_spec;
 pub mod function;
+pub mod generator;
 mod icelang_std_lib;
 pub mod icelang_type;
 pub mod interpreter;
 pub mod keyword;
 pub mod lexer;
 pub mod parser;
+pub mod range;
+pub mod record;
 pub mod runtime_state;
 pub mod source_range;
 mod symbol_table;
@@ -1,135 +1,203 @@
 //! Contains code related to parsing (converting tokens to an abstract syntax tree (AST))
 
-use std::collections::VecDeque;
+use std::{collections::VecDeque, path::Path};
 
 use crate::{
     ast::*,
     error::ParseError,
+    format_spec::FormatSpec,
     function::FunctionParameters,
     icelang_type::IcelangType,
     keyword::Keyword,
     source_range::SourceRange,
-    token::{FormattedStringLiteralSectionKind, Token},
+    token::{
+        FormattedStringLiteralSectionKind, Token, TokenFormattedStringLiteralSection, TokenLabel,
+    },
     value::Value,
 };
 
+/// Parses a rest parameter (`[name]`) of a function declaration from a token
+/// stream
+fn parse_rest_parameter<'source>(
+    token_stream: &mut VecDeque<&Token<'source>>,
+    start_pos: &SourceRange<'source>,
+) -> Result<(String, SourceRange<'source>), ParseError<'source>> {
+    // Expect a "["
+    match token_stream.pop_front() {
+        Some(Token::Punctuator(token)) if token.punctuator() == "[" => {}
+        Some(token) => {
+            return Err(ParseError::new_unexpected_token(
+                "expected opening square bracket in rest parameter".to_string(),
+                token.pos().clone(),
+            ));
+        }
+        None => {
+            return Err(ParseError::new_unexpected_eof(
+                "incomplete function declaration".to_string(),
+                start_pos.extended_to_end(),
+            ));
+        }
+    };
+
+    // Read the parameter identifier
+    let (parameter_name, parameter_name_pos) = match token_stream.pop_front() {
+        Some(Token::Ident(token)) => (token.ident(), token.pos()),
+        Some(token) => {
+            return Err(ParseError::new_unexpected_token(
+                "expected function parameter name".to_string(),
+                token.pos().clone(),
+            ));
+        }
+        None => {
+            return Err(ParseError::new_unexpected_eof(
+                "incomplete function declaration".to_string(),
+                start_pos.extended_to_end(),
+            ));
+        }
+    };
+
+    // Expect a closing bracket
+    match token_stream.pop_front() {
+        Some(Token::Punctuator(token)) if token.punctuator() == "]" => {}
+        Some(token) => {
+            return Err(ParseError::new_unexpected_token(
+                "expected closing square bracket in function parameters".to_string(),
+                token.pos().clone(),
+            ));
+        }
+        None => {
+            return Err(ParseError::new_unexpected_eof(
+                "incomplete function declaration".to_string(),
+                start_pos.extended_to_end(),
+            ));
+        }
+    };
+
+    Ok((parameter_name.to_string(), parameter_name_pos.clone()))
+}
+
 /// Parses a function declaration's parameters from a token stream
 fn parse_function_declaration_parameters<'source>(
     token_stream: &mut VecDeque<&Token<'source>>,
     start_pos: &SourceRange<'source>,
 ) -> Result<FunctionParameters<'source>, ParseError<'source>> {
-    match token_stream.front() {
-        // Variadic function
-        Some(Token::Punctuator(token)) if token.punctuator() == "[" => {
-            // Consume the "["
-            token_stream.pop_front();
+    let mut parameters: Vec<(String, Option<AstNode>, SourceRange)> = Vec::new();
+    let mut rest_parameter = None;
 
-            // Read the parameter identifier
-            let (parameter_name, parameter_name_pos) = match token_stream.pop_front() {
-                Some(Token::Ident(token)) => (token.ident(), token.pos()),
-                Some(token) => {
-                    return Err(ParseError::new_unexpected_token(
-                        "expected function parameter name".to_string(),
-                        token.pos().clone(),
-                    ));
-                }
-                None => {
-                    return Err(ParseError::new_unexpected_eof(
-                        "incomplete function declaration".to_string(),
-                        start_pos.extended_to_end(),
-                    ));
-                }
-            };
+    loop {
+        match token_stream.front() {
+            // The end of the parameters
+            Some(Token::Punctuator(token)) if token.punctuator() == ")" => break,
 
-            // Expect a closing bracket
-            match token_stream.pop_front() {
-                Some(Token::Punctuator(token)) if token.punctuator() == "]" => {}
-                Some(token) => {
-                    return Err(ParseError::new_unexpected_token(
-                        "expected closing square bracket in function parameters".to_string(),
-                        token.pos().clone(),
-                    ));
+            // The rest parameter, which must be the last parameter
+            Some(Token::Punctuator(token)) if token.punctuator() == "[" => {
+                rest_parameter = Some(parse_rest_parameter(token_stream, start_pos)?);
+
+                // Allow an optional comma after the rest parameter
+                if let Some(Token::Punctuator(token)) = token_stream.front() {
+                    if token.punctuator() == "," {
+                        token_stream.pop_front();
+                    }
                 }
-                None => {
-                    return Err(ParseError::new_unexpected_eof(
-                        "incomplete function declaration".to_string(),
-                        start_pos.extended_to_end(),
-                    ));
+
+                match token_stream.front() {
+                    Some(Token::Punctuator(token)) if token.punctuator() == ")" => break,
+                    Some(token) => {
+                        return Err(ParseError::new_unexpected_token(
+                            "the rest parameter must be the last function parameter".to_string(),
+                            token.pos().clone(),
+                        ));
+                    }
+                    None => {
+                        return Err(ParseError::new_unexpected_eof(
+                            "incomplete function declaration".to_string(),
+                            start_pos.extended_to_end(),
+                        ));
+                    }
                 }
-            };
+            }
 
-            Ok(FunctionParameters::Variadic {
-                parameter_name: (parameter_name.to_string(), parameter_name_pos.clone()),
-            })
-        }
+            // A normal parameter, possibly with a default value
+            Some(Token::Ident(parameter_name_token)) => {
+                token_stream.pop_front();
 
-        // Nullary (zero parameter) function
-        Some(Token::Punctuator(token)) if token.punctuator() == ")" => {
-            Ok(FunctionParameters::Polyadic { parameters: vec![] })
-        }
+                let default_value = match token_stream.front() {
+                    Some(Token::Punctuator(token)) if token.punctuator() == "=" => {
+                        // Consume the "="
+                        token_stream.pop_front();
 
-        // One-or-more-ary function (technically, multiary means 2 or more)
-        Some(Token::Ident(first_parameter_name_token)) => {
-            // Read the first parameter
-            let mut parameters = vec![(
-                first_parameter_name_token.ident().to_string(),
-                first_parameter_name_token.pos().clone(),
-            )];
-            token_stream.pop_front();
+                        // Ensure the token stream isn't empty
+                        if token_stream.is_empty() {
+                            return Err(ParseError::new_unexpected_eof(
+                                "incomplete function declaration".to_string(),
+                                start_pos.extended_to_end(),
+                            ));
+                        }
 
-            // Read any subsequent parameters
-            loop {
+                        Some(parse_expression(token_stream)?)
+                    }
+                    _ => {
+                        // Parameters with default values must come last, so
+                        // that every positional argument has a parameter
+                        if parameters
+                            .iter()
+                            .any(|(_, default_value, _)| default_value.is_some())
+                        {
+                            return Err(ParseError::new_unexpected_token(
+                                "a parameter without a default value can't follow a parameter with a default value".to_string(),
+                                parameter_name_token.pos().clone(),
+                            ));
+                        }
+
+                        None
+                    }
+                };
+
+                parameters.push((
+                    parameter_name_token.ident().to_string(),
+                    default_value,
+                    parameter_name_token.pos().clone(),
+                ));
+
+                // Expect a "," or the end of the parameters
                 match token_stream.front() {
                     Some(Token::Punctuator(token)) if token.punctuator() == "," => {
                         // Consume the ","
                         token_stream.pop_front();
-
-                        // Read the next parameter name
-                        match token_stream.front() {
-                            Some(Token::Ident(next_parameter_token)) => {
-                                parameters.push((
-                                    next_parameter_token.ident().to_string(),
-                                    next_parameter_token.pos().clone(),
-                                ));
-                                token_stream.pop_front();
-                            }
-                            // If this was the optional comma after the last
-                            // parameter, we're done
-                            Some(Token::Punctuator(closing_paren_token))
-                                if closing_paren_token.punctuator() == ")" =>
-                            {
-                                break
-                            }
-                            Some(token) => {
-                                return Err(ParseError::new_unexpected_token(
-                                    "expected function parameter name".to_string(),
-                                    token.pos().clone(),
-                                ));
-                            }
-                            None => {
-                                return Err(ParseError::new_unexpected_eof(
-                                    "incomplete function declaration".to_string(),
-                                    start_pos.extended_to_end(),
-                                ));
-                            }
-                        };
                     }
                     _ => break,
                 }
             }
 
-            Ok(FunctionParameters::Polyadic { parameters })
+            // Invalid parameters
+            Some(token) => {
+                return Err(ParseError::new_unexpected_token(
+                    if parameters.is_empty() {
+                        "expected parameter list in function declaration".to_string()
+                    } else {
+                        "expected function parameter name".to_string()
+                    },
+                    token.pos().clone(),
+                ));
+            }
+            None => {
+                return Err(ParseError::new_unexpected_eof(
+                    "incomplete function declaration".to_string(),
+                    start_pos.extended_to_end(),
+                ));
+            }
         }
+    }
 
-        // Invalid arguments
-        Some(token) => Err(ParseError::new_unexpected_token(
-            "expected parameter list in function declaration".to_string(),
-            token.pos().clone(),
-        )),
-        None => Err(ParseError::new_unexpected_eof(
-            "incomplete function declaration".to_string(),
-            start_pos.extended_to_end(),
-        )),
+    // A lone rest parameter makes a (purely) variadic function
+    match rest_parameter {
+        Some(parameter_name) if parameters.is_empty() => {
+            Ok(FunctionParameters::Variadic { parameter_name })
+        }
+        rest_parameter => Ok(FunctionParameters::Polyadic {
+            parameters,
+            rest_parameter,
+        }),
     }
 }
 
@@ -219,423 +287,1736 @@ fn parse_function_declaration<'source>(
     .into())
 }
 
-/// Parses a variable declaration statement from a token stream
+/// Parses an anonymous function expression from a token stream
 ///
 /// # Panics
 /// - If the token stream is empty
-fn parse_variable_declaration<'source>(
+fn parse_anonymous_function<'source>(
     token_stream: &mut VecDeque<&Token<'source>>,
 ) -> Result<AstNode<'source>, ParseError<'source>> {
     assert!(!token_stream.is_empty());
 
-    // Expect a "let" keyword
+    // Expect a "fn" keyword
     let start_pos = match token_stream.pop_front().unwrap() {
-        Token::Keyword(token) if token.keyword() == Keyword::Let => token.pos(),
+        Token::Keyword(token) if token.keyword() == Keyword::Fn => token.pos(),
         token => {
             return Err(ParseError::new_unexpected_token(
-                "expected `let` keyword in variable declaration".to_string(),
+                "expected `fn` keyword in anonymous function".to_string(),
                 token.pos().clone(),
-            ));
+            ))
         }
     };
 
-    // Parse the first variable declaration
-    let mut declarations = Vec::new();
-    let mut pos = start_pos.clone();
+    // Expect an opening parenthesis
     match token_stream.pop_front() {
-        Some(Token::Ident(token)) => {
-            // Save the identifier and update pos
-            let ident = token.ident().to_string();
-            let mut declaration_pos = token.pos().clone();
-            pos.extend_to(token.pos());
-
-            // Check if this declaration has an initialization value, and
-            // if so parse it
-            let value = match token_stream.front() {
-                Some(Token::Punctuator(token)) if token.punctuator() == "=" => {
-                    // Consume the "="
-                    token_stream.pop_front();
-
-                    // Ensure the token stream isn't empty
-                    if token_stream.is_empty() {
-                        return Err(ParseError::new_unexpected_eof(
-                            "incomplete variable declaration".to_string(),
-                            start_pos.extended_to_end(),
-                        ));
-                    };
+        Some(Token::Punctuator(token)) if token.punctuator() == "(" => {}
+        Some(token) => {
+            return Err(ParseError::new_unexpected_token(
+                "expected opening parenthesis in anonymous function".to_string(),
+                token.pos().clone(),
+            ));
+        }
+        None => {
+            return Err(ParseError::new_unexpected_eof(
+                "incomplete anonymous function".to_string(),
+                start_pos.extended_to_end(),
+            ));
+        }
+    };
 
-                    let value = parse_expression(token_stream)?;
-                    pos.extend_to(value.pos());
-                    declaration_pos.extend_to(value.pos());
-                    Some(value)
-                }
-                _ => None,
-            };
+    // Parse function parameters
+    let parameters = parse_function_declaration_parameters(token_stream, start_pos)?;
 
-            declarations.push((ident, value, declaration_pos));
-        }
+    // Expect a closing parenthesis
+    match token_stream.pop_front() {
+        Some(Token::Punctuator(token)) if token.punctuator() == ")" => {}
         Some(token) => {
             return Err(ParseError::new_unexpected_token(
-                "expected identifier in variable declaration".to_string(),
+                "expected closing parenthesis in anonymous function".to_string(),
                 token.pos().clone(),
-            ))
+            ));
         }
         None => {
             return Err(ParseError::new_unexpected_eof(
-                "expected identifier in variable declaration".to_string(),
+                "incomplete anonymous function".to_string(),
                 start_pos.extended_to_end(),
-            ))
+            ));
         }
+    };
+
+    // Ensure the token stream isn't empty
+    if token_stream.is_empty() {
+        return Err(ParseError::new_unexpected_eof(
+            "incomplete anonymous function".to_string(),
+            start_pos.extended_to_end(),
+        ));
     }
 
-    // Parse any subsequent variable declarations
-    loop {
-        match token_stream.front() {
-            Some(Token::Punctuator(token)) if token.punctuator() == "," => {
-                // Consume the ","
-                token_stream.pop_front();
+    // Parse function body
+    let (body, body_pos) = parse_code_block(token_stream)?;
 
-                // Expect an identifier
-                let (ident, mut declaration_pos) = match token_stream.front() {
-                    Some(Token::Ident(token)) => {
-                        // Consume the identifier
+    Ok(AstNodeAnonymousFunction::new(parameters, body, start_pos.extended_to(&body_pos)).into())
+}
+
+/// Parses a destructuring pattern from a token stream
+///
+/// If `lvalue_targets` is true, the targets of the pattern may be any lvalue
+/// (as in an assignment), otherwise they must be identifiers (as in a
+/// variable declaration or for loop)
+///
+/// # Panics
+/// - If the token stream is empty
+fn parse_destructuring_pattern<'source>(
+    token_stream: &mut VecDeque<&Token<'source>>,
+    lvalue_targets: bool,
+) -> Result<DestructuringPattern<'source>, ParseError<'source>> {
+    assert!(!token_stream.is_empty());
+
+    match token_stream.front().unwrap() {
+        // List pattern
+        Token::Punctuator(token) if token.punctuator() == "[" => {
+            // Consume the "["
+            token_stream.pop_front();
+            let start_pos = token.pos();
+
+            let mut elements = Vec::new();
+            let mut rest = None;
+            let pos = loop {
+                // Expect an element, a rest element, or the closing square
+                // bracket
+                match token_stream.front() {
+                    Some(Token::Punctuator(token)) if token.punctuator() == "]" => {
+                        // Consume the "]"
                         token_stream.pop_front();
 
-                        // Save the identifier and update pos
-                        pos.extend_to(token.pos());
-                        (token.ident().to_string(), token.pos().clone())
-                    }
-                    _ => {
-                        // This must have been the optional trailing comma after
-                        // the last variable declaration - we're done here
-                        break;
+                        break start_pos.extended_to(token.pos());
                     }
-                };
-
-                // Check if this declaration has an initialization value, and
-                // if so parse it
-                let value = match token_stream.front() {
-                    Some(Token::Punctuator(token)) if token.punctuator() == "=" => {
-                        // Consume the "="
+                    Some(Token::Punctuator(token)) if token.punctuator() == "..." => {
+                        // Consume the "..."
                         token_stream.pop_front();
 
-                        // Ensure the token stream isn't empty
+                        if rest.is_some() {
+                            return Err(ParseError::new_unexpected_token(
+                                "list pattern can only have one rest element".to_string(),
+                                token.pos().clone(),
+                            ));
+                        }
                         if token_stream.is_empty() {
                             return Err(ParseError::new_unexpected_eof(
-                                "incomplete variable declaration".to_string(),
-                                start_pos.extended_to_end(),
+                                "expected pattern after `...` in list pattern".to_string(),
+                                token.pos().extended_to_end(),
                             ));
-                        };
+                        }
 
-                        let value = parse_expression(token_stream)?;
-                        pos.extend_to(value.pos());
-                        declaration_pos.extend_to(value.pos());
-                        Some(value)
+                        let rest_pattern =
+                            parse_destructuring_pattern(token_stream, lvalue_targets)?;
+                        rest = Some((elements.len(), Box::new(rest_pattern)));
                     }
-                    _ => None,
-                };
+                    Some(_) => {
+                        elements.push(parse_destructuring_pattern(token_stream, lvalue_targets)?);
+                    }
+                    None => {
+                        return Err(ParseError::new_unexpected_eof(
+                            "incomplete list pattern".to_string(),
+                            start_pos.extended_to_end(),
+                        ));
+                    }
+                }
 
-                declarations.push((ident, value, declaration_pos));
-            }
-            _ => break,
-        }
-    }
-
-    Ok(AstNodeVariableDeclaration::new(declarations, pos).into())
-}
-
-/// Parses an if-else statement from a token stream
-///
-/// # Panics
-/// - If the token stream is empty
-fn parse_if_else_statement<'source>(
-    token_stream: &mut VecDeque<&Token<'source>>,
-) -> Result<AstNode<'source>, ParseError<'source>> {
-    assert!(!token_stream.is_empty());
+                // Expect a comma or the closing square bracket
+                match token_stream.pop_front() {
+                    Some(Token::Punctuator(token)) if token.punctuator() == "," => {}
+                    Some(Token::Punctuator(token)) if token.punctuator() == "]" => {
+                        break start_pos.extended_to(token.pos());
+                    }
+                    Some(token) => {
+                        return Err(ParseError::new_unexpected_token(
+                            "expected comma or closing square bracket in list pattern".to_string(),
+                            token.pos().clone(),
+                        ));
+                    }
+                    None => {
+                        return Err(ParseError::new_unexpected_eof(
+                            "incomplete list pattern".to_string(),
+                            start_pos.extended_to_end(),
+                        ));
+                    }
+                }
+            };
 
-    // Expect an "if" keyword
-    let mut pos = match token_stream.pop_front().unwrap() {
-        Token::Keyword(token) if token.keyword() == Keyword::If => token.pos().clone(),
-        token => {
-            return Err(ParseError::new_unexpected_token(
-                "expected `if` keyword in if statement".to_string(),
-                token.pos().clone(),
-            ));
+            Ok(DestructuringPattern::List {
+                elements,
+                rest,
+                pos,
+            })
         }
-    };
 
-    // Ensure the token stream isn't empty
-    if token_stream.is_empty() {
-        return Err(ParseError::new_unexpected_eof(
-            "incomplete if statement".to_string(),
-            pos.extended_to_end(),
-        ));
-    };
+        // Dict pattern
+        Token::Punctuator(token) if token.punctuator() == "{" => {
+            // Consume the "{"
+            token_stream.pop_front();
+            let start_pos = token.pos();
 
-    // Parse the condition of the if statement
-    let condition = parse_expression(token_stream)?;
+            let mut entries = Vec::new();
+            let mut rest = None;
+            let pos = loop {
+                // Expect an entry, a rest element, or the closing curly brace
+                match token_stream.front() {
+                    Some(Token::Punctuator(token)) if token.punctuator() == "}" => {
+                        // Consume the "}"
+                        token_stream.pop_front();
 
-    // Ensure the token stream isn't empty
-    if token_stream.is_empty() {
-        return Err(ParseError::new_unexpected_eof(
-            "incomplete if statement".to_string(),
-            pos.extended_to_end(),
-        ));
-    };
+                        break start_pos.extended_to(token.pos());
+                    }
+                    Some(Token::Punctuator(token)) if token.punctuator() == "..." => {
+                        // Consume the "..."
+                        token_stream.pop_front();
 
-    // Parse the body of the if statement
-    let (body, body_pos) = parse_code_block(token_stream)?;
-    pos.extend_to(&body_pos);
+                        if token_stream.is_empty() {
+                            return Err(ParseError::new_unexpected_eof(
+                                "expected pattern after `...` in dict pattern".to_string(),
+                                token.pos().extended_to_end(),
+                            ));
+                        }
 
-    // Keep track of all the conditional branches of this if-else statement
-    let mut conditional_branches = vec![(condition, body)];
+                        rest = Some(Box::new(parse_destructuring_pattern(
+                            token_stream,
+                            lvalue_targets,
+                        )?));
+                    }
+                    Some(_) => {
+                        // Parse the key
+                        let key = parse_expression(token_stream)?;
 
-    // Parse any else-if statements
-    loop {
-        match (token_stream.front(), token_stream.get(1)) {
-            (Some(Token::Keyword(token_else)), Some(Token::Keyword(token_if)))
-                if token_else.keyword() == Keyword::Else && token_if.keyword() == Keyword::If =>
-            {
-                // Consume the "else" and "if" tokens
-                token_stream.pop_front();
-                token_stream.pop_front();
+                        // Expect a colon
+                        match token_stream.pop_front() {
+                            Some(Token::Punctuator(token)) if token.punctuator() == ":" => {}
+                            Some(token) => {
+                                return Err(ParseError::new_unexpected_token(
+                                    "expected colon in dict pattern entry".to_string(),
+                                    token.pos().clone(),
+                                ));
+                            }
+                            None => {
+                                return Err(ParseError::new_unexpected_eof(
+                                    "expected colon in dict pattern entry".to_string(),
+                                    key.pos().extended_to_end(),
+                                ));
+                            }
+                        };
 
-                // Ensure the token stream isn't empty
-                if token_stream.is_empty() {
-                    return Err(ParseError::new_unexpected_eof(
-                        "incomplete else-if statement".to_string(),
-                        pos.extended_to_end(),
-                    ));
-                };
+                        // Ensure the token stream isn't empty
+                        if token_stream.is_empty() {
+                            return Err(ParseError::new_unexpected_eof(
+                                "incomplete dict pattern entry".to_string(),
+                                key.pos().extended_to_end(),
+                            ));
+                        };
 
-                // Parse the condition of the else-if statement
-                let condition = parse_expression(token_stream)?;
+                        // Parse the value pattern
+                        let value = parse_destructuring_pattern(token_stream, lvalue_targets)?;
+                        entries.push((key, value));
+                    }
+                    None => {
+                        return Err(ParseError::new_unexpected_eof(
+                            "incomplete dict pattern".to_string(),
+                            start_pos.extended_to_end(),
+                        ));
+                    }
+                }
 
-                // Ensure the token stream isn't empty
-                if token_stream.is_empty() {
-                    return Err(ParseError::new_unexpected_eof(
-                        "incomplete else-if statement".to_string(),
-                        pos.extended_to_end(),
-                    ));
-                };
+                // Expect a comma or the closing curly brace. The rest element
+                // must be the last one
+                match token_stream.pop_front() {
+                    Some(Token::Punctuator(token))
+                        if token.punctuator() == "," && rest.is_none() => {}
+                    Some(Token::Punctuator(token)) if token.punctuator() == "," => {
+                        // The trailing comma after the rest element
+                        match token_stream.pop_front() {
+                            Some(Token::Punctuator(token)) if token.punctuator() == "}" => {
+                                break start_pos.extended_to(token.pos());
+                            }
+                            Some(token) => {
+                                return Err(ParseError::new_unexpected_token(
+                                    "the rest element must be last in a dict pattern".to_string(),
+                                    token.pos().clone(),
+                                ));
+                            }
+                            None => {
+                                return Err(ParseError::new_unexpected_eof(
+                                    "incomplete dict pattern".to_string(),
+                                    start_pos.extended_to_end(),
+                                ));
+                            }
+                        }
+                    }
+                    Some(Token::Punctuator(token)) if token.punctuator() == "}" => {
+                        break start_pos.extended_to(token.pos());
+                    }
+                    Some(token) => {
+                        return Err(ParseError::new_unexpected_token(
+                            "expected comma or closing curly brace in dict pattern".to_string(),
+                            token.pos().clone(),
+                        ));
+                    }
+                    None => {
+                        return Err(ParseError::new_unexpected_eof(
+                            "incomplete dict pattern".to_string(),
+                            start_pos.extended_to_end(),
+                        ));
+                    }
+                }
+            };
 
-                // Parse the body of the else-if statement
-                let (body, body_pos) = parse_code_block(token_stream)?;
-                pos.extend_to(&body_pos);
+            Ok(DestructuringPattern::Dict { entries, rest, pos })
+        }
 
-                conditional_branches.push((condition, body));
+        // Any lvalue (in assignments)
+        _ if lvalue_targets => {
+            let target = parse_expr_usage_suffix(token_stream)?;
+            match target {
+                target if is_assignment_target(&target) => {
+                    Ok(DestructuringPattern::Target(Box::new(target)))
+                }
+                target => Err(ParseError::new_unexpected_token(
+                    "invalid assignment target in pattern".to_string(),
+                    target.pos().clone(),
+                )),
             }
-            _ => break,
         }
-    }
 
-    // Parse an optional else branch
-    let else_branch = match token_stream.front() {
-        Some(Token::Keyword(token)) if token.keyword() == Keyword::Else => {
-            // Consume the "else" token
+        // Identifier (in declarations)
+        Token::Ident(token) => {
+            // Consume the identifier
             token_stream.pop_front();
 
-            // Ensure the token stream isn't empty
-            if token_stream.is_empty() {
-                return Err(ParseError::new_unexpected_eof(
-                    "incomplete else statement".to_string(),
-                    pos.extended_to_end(),
-                ));
-            };
+            Ok(DestructuringPattern::Target(Box::new(
+                AstNodeVariableAccess::new(token.ident().to_string(), token.pos().clone()).into(),
+            )))
+        }
+        token => Err(ParseError::new_unexpected_token(
+            "expected identifier or pattern".to_string(),
+            token.pos().clone(),
+        )),
+    }
+}
 
-            // Parse the body of the else statement
-            let (body, body_pos) = parse_code_block(token_stream)?;
-            pos.extend_to(&body_pos);
+/// Returns whether a token can start a destructuring pattern (an identifier, or
+/// the opening bracket of a list or dict pattern)
+fn starts_destructuring_pattern(token: &Token<'_>) -> bool {
+    match token {
+        Token::Ident(_) => true,
+        Token::Punctuator(token) => token.punctuator() == "[" || token.punctuator() == "{",
+        _ => false,
+    }
+}
 
-            Some(body)
+/// Returns whether the token stream starts with a list or dict pattern which
+/// is immediately followed by a (non-augmented) assignment operator, meaning it
+/// should be parsed as the left-hand side of a destructuring assignment
+fn starts_with_destructuring_assignment(token_stream: &VecDeque<&Token<'_>>) -> bool {
+    if !matches!(
+        token_stream.front(),
+        Some(Token::Punctuator(token)) if token.punctuator() == "[" || token.punctuator() == "{"
+    ) {
+        return false;
+    }
+
+    // Find the bracket which closes the opening one, and check what follows it
+    let mut depth = 0usize;
+    for (i, token) in token_stream.iter().enumerate() {
+        match token {
+            Token::Punctuator(token) if ["[", "{", "("].contains(&token.punctuator()) => {
+                depth += 1;
+            }
+            Token::Punctuator(token) if ["]", "}", ")"].contains(&token.punctuator()) => {
+                depth -= 1;
+                if depth == 0 {
+                    return matches!(
+                        token_stream.get(i + 1),
+                        Some(Token::Punctuator(token)) if token.punctuator() == "="
+                    );
+                }
+            }
+            _ => {}
         }
-        _ => None,
-    };
+    }
 
-    Ok(AstNodeIfElseStatement::new(conditional_branches, else_branch, pos).into())
+    false
 }
 
-/// Parses a simple loop from a token stream
+/// Parses a single declaration (an identifier or pattern, and an optional
+/// initialization value) in a variable declaration statement from a token
+/// stream
 ///
 /// # Panics
 /// - If the token stream is empty
-fn parse_simple_loop<'source>(
+fn parse_single_variable_declaration<'source>(
     token_stream: &mut VecDeque<&Token<'source>>,
-) -> Result<AstNode<'source>, ParseError<'source>> {
-    assert!(!token_stream.is_empty());
-
-    // Expect a "loop" keyword
-    let start_pos = match token_stream.pop_front().unwrap() {
-        Token::Keyword(token) if token.keyword() == Keyword::Loop => token.pos(),
+    start_pos: &SourceRange<'source>,
+    is_constant: bool,
+) -> Result<
+    (
+        DestructuringPattern<'source>,
+        Option<AstNode<'source>>,
+        SourceRange<'source>,
+    ),
+    ParseError<'source>,
+> {
+    let pattern = match token_stream.front().unwrap() {
+        token if starts_destructuring_pattern(token) => {
+            parse_destructuring_pattern(token_stream, false)?
+        }
         token => {
             return Err(ParseError::new_unexpected_token(
-                "expected `loop` keyword in simple loop".to_string(),
+                "expected identifier in variable declaration".to_string(),
                 token.pos().clone(),
-            ));
+            ))
         }
     };
+    let mut declaration_pos = pattern.pos().clone();
 
-    // Ensure the token stream isn't empty
-    if token_stream.is_empty() {
-        return Err(ParseError::new_unexpected_eof(
-            "incomplete simple loop".to_string(),
-            start_pos.extended_to_end(),
-        ));
-    };
+    // Check if this declaration has an initialization value, and if so parse
+    // it
+    let value = match token_stream.front() {
+        Some(Token::Punctuator(token)) if token.punctuator() == "=" => {
+            // Consume the "="
+            token_stream.pop_front();
 
-    let condition = match token_stream.front() {
-        Some(Token::Punctuator(token)) if token.punctuator() == "{" => None,
-        _ => Some(parse_expression(token_stream)?),
+            // Ensure the token stream isn't empty
+            if token_stream.is_empty() {
+                return Err(ParseError::new_unexpected_eof(
+                    "incomplete variable declaration".to_string(),
+                    start_pos.extended_to_end(),
+                ));
+            };
+
+            let value = parse_expression(token_stream)?;
+            declaration_pos.extend_to(value.pos());
+            Some(value)
+        }
+        _ => None,
     };
 
-    // Ensure the token stream isn't empty
-    if token_stream.is_empty() {
-        return Err(ParseError::new_unexpected_eof(
-            "incomplete simple loop".to_string(),
-            start_pos.extended_to_end(),
+    // A constant could never be given a value after its declaration
+    if value.is_none() && is_constant {
+        return Err(ParseError::new_unexpected_token(
+            "constant declaration must have an initialization value".to_string(),
+            pattern.pos().clone(),
         ));
-    };
+    }
 
-    let (body, end_pos) = parse_code_block(token_stream)?;
-    let pos = start_pos.extended_to(&end_pos);
+    // There's nothing to destructure without an initialization value
+    if value.is_none() && pattern.ident().is_none() {
+        return Err(ParseError::new_unexpected_token(
+            "destructuring declaration must have an initialization value".to_string(),
+            pattern.pos().clone(),
+        ));
+    }
 
-    Ok(AstNodeSimpleLoop::new(condition, body, pos).into())
+    Ok((pattern, value, declaration_pos))
 }
 
-/// Parses a while loop from a token stream
+/// Parses a variable declaration statement from a token stream
 ///
 /// # Panics
 /// - If the token stream is empty
-fn parse_while_loop<'source>(
+fn parse_variable_declaration<'source>(
     token_stream: &mut VecDeque<&Token<'source>>,
 ) -> Result<AstNode<'source>, ParseError<'source>> {
     assert!(!token_stream.is_empty());
 
-    // Expect a "while" keyword
-    let start_pos = match token_stream.pop_front().unwrap() {
-        Token::Keyword(token) if token.keyword() == Keyword::While => token.pos(),
+    // Expect a "let" or "const" keyword
+    let (start_pos, is_constant) = match token_stream.pop_front().unwrap() {
+        Token::Keyword(token) if token.keyword() == Keyword::Let => (token.pos(), false),
+        Token::Keyword(token) if token.keyword() == Keyword::Const => (token.pos(), true),
         token => {
             return Err(ParseError::new_unexpected_token(
-                "expected `while` keyword in while loop".to_string(),
+                "expected `let` or `const` keyword in variable declaration".to_string(),
                 token.pos().clone(),
             ));
         }
     };
 
-    // Ensure the token stream isn't empty
-    if token_stream.is_empty() {
-        return Err(ParseError::new_unexpected_eof(
-            "incomplete while loop".to_string(),
-            start_pos.extended_to_end(),
-        ));
-    };
-
-    let condition = parse_expression(token_stream)?;
-
-    // Ensure the token stream isn't empty
-    if token_stream.is_empty() {
-        return Err(ParseError::new_unexpected_eof(
-            "incomplete while loop".to_string(),
-            start_pos.extended_to_end(),
-        ));
+    // Parse the first variable declaration
+    let mut declarations = Vec::new();
+    let mut pos = start_pos.clone();
+    match token_stream.front() {
+        Some(_) => {
+            let declaration =
+                parse_single_variable_declaration(token_stream, start_pos, is_constant)?;
+            pos.extend_to(&declaration.2);
+            declarations.push(declaration);
+        }
+        None => {
+            return Err(ParseError::new_unexpected_eof(
+                "expected identifier in variable declaration".to_string(),
+                start_pos.extended_to_end(),
+            ))
+        }
+    }
+
+    // Parse any subsequent variable declarations
+    loop {
+        match token_stream.front() {
+            Some(Token::Punctuator(token)) if token.punctuator() == "," => {
+                // Consume the ","
+                token_stream.pop_front();
+
+                // Expect an identifier or pattern
+                match token_stream.front() {
+                    Some(Token::Ident(_)) => {}
+                    Some(Token::Punctuator(token))
+                        if token.punctuator() == "[" || token.punctuator() == "{" => {}
+                    _ => {
+                        // This must have been the optional trailing comma after
+                        // the last variable declaration - we're done here
+                        break;
+                    }
+                };
+
+                let declaration =
+                    parse_single_variable_declaration(token_stream, start_pos, is_constant)?;
+                pos.extend_to(&declaration.2);
+                declarations.push(declaration);
+            }
+            _ => break,
+        }
+    }
+
+    Ok(AstNodeVariableDeclaration::new(declarations, is_constant, pos).into())
+}
+
+/// Parses an import from a token stream
+///
+/// # Panics
+/// - If the token stream is empty
+fn parse_import<'source>(
+    token_stream: &mut VecDeque<&Token<'source>>,
+) -> Result<AstNode<'source>, ParseError<'source>> {
+    assert!(!token_stream.is_empty());
+
+    // Expect an "import" keyword
+    let start_pos = match token_stream.pop_front().unwrap() {
+        Token::Keyword(token) if token.keyword() == Keyword::Import => token.pos(),
+        token => {
+            return Err(ParseError::new_unexpected_token(
+                "expected `import` keyword in import".to_string(),
+                token.pos().clone(),
+            ));
+        }
     };
 
-    let (body, end_pos) = parse_code_block(token_stream)?;
-    let pos = start_pos.extended_to(&end_pos);
+    // Expect the path of the module, as a string literal
+    let (path, path_pos) = match token_stream.pop_front() {
+        Some(Token::Literal(token)) if token.icelang_type() == IcelangType::String => {
+            let Value::String(path) = token.value() else {
+                unreachable!("string literals always have string values");
+            };
+            (path.to_string(), token.pos())
+        }
+        Some(token) => {
+            return Err(ParseError::new_unexpected_token(
+                "expected string literal module path in import".to_string(),
+                token.pos().clone(),
+            ))
+        }
+        None => {
+            return Err(ParseError::new_unexpected_eof(
+                "expected string literal module path in import".to_string(),
+                start_pos.extended_to_end(),
+            ))
+        }
+    };
+    let mut pos = start_pos.extended_to(path_pos);
+
+    // Check for an explicit namespace
+    let namespace = match token_stream.front() {
+        Some(Token::Keyword(token)) if token.keyword() == Keyword::As => {
+            // Consume the "as" keyword
+            token_stream.pop_front();
+
+            // Expect an identifier
+            match token_stream.pop_front() {
+                Some(Token::Ident(token)) => {
+                    pos.extend_to(token.pos());
+                    token.ident().to_string()
+                }
+                Some(token) => {
+                    return Err(ParseError::new_unexpected_token(
+                        "expected identifier after `as` in import".to_string(),
+                        token.pos().clone(),
+                    ))
+                }
+                None => {
+                    return Err(ParseError::new_unexpected_eof(
+                        "expected identifier after `as` in import".to_string(),
+                        pos.extended_to_end(),
+                    ))
+                }
+            }
+        }
+        // If there is no explicit namespace, the namespace is the name of the
+        // module's file (without the extension)
+        _ => {
+            let stem = Path::new(&path)
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .unwrap_or_default();
+            let mut chars = stem.chars();
+            let is_valid_ident = chars
+                .next()
+                .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+                && chars.all(|c| c.is_alphanumeric() || c == '_')
+                && Keyword::try_from(stem).is_err();
+
+            if !is_valid_ident {
+                return Err(ParseError::new_unexpected_token(
+                    format!(
+                        "module name \"{stem}\" is not a valid identifier, use `as` to name the import"
+                    ),
+                    path_pos.clone(),
+                ));
+            }
+
+            stem.to_string()
+        }
+    };
 
-    Ok(AstNodeWhileLoop::new(condition, body, pos).into())
+    Ok(AstNodeImport::new(path, namespace, pos).into())
 }
 
-/// Parses a for loop from a token stream
+/// Parses a record declaration from a token stream
 ///
 /// # Panics
 /// - If the token stream is empty
-fn parse_for_loop<'source>(
+fn parse_record_declaration<'source>(
     token_stream: &mut VecDeque<&Token<'source>>,
 ) -> Result<AstNode<'source>, ParseError<'source>> {
     assert!(!token_stream.is_empty());
 
-    // Expect a "for" keyword
+    // Expect a "record" keyword
     let start_pos = match token_stream.pop_front().unwrap() {
-        Token::Keyword(token) if token.keyword() == Keyword::For => token.pos(),
+        Token::Keyword(token) if token.keyword() == Keyword::Record => token.pos(),
         token => {
             return Err(ParseError::new_unexpected_token(
-                "expected `for` keyword in for loop".to_string(),
+                "expected `record` keyword in record declaration".to_string(),
                 token.pos().clone(),
             ));
         }
     };
 
-    // Expect an identifier
-    let ident = match token_stream.pop_front() {
+    // Expect the name of the record type
+    let name = match token_stream.pop_front() {
         Some(Token::Ident(token)) => token.ident().to_string(),
         Some(token) => {
             return Err(ParseError::new_unexpected_token(
-                "expected identifier in for loop".to_string(),
+                "expected identifier in record declaration".to_string(),
                 token.pos().clone(),
             ));
         }
         None => {
             return Err(ParseError::new_unexpected_eof(
-                "expected identifier in for loop".to_string(),
+                "expected identifier in record declaration".to_string(),
                 start_pos.extended_to_end(),
             ));
         }
     };
 
-    // Expect an "in" keyword
+    // Expect an opening curly brace
     match token_stream.pop_front() {
-        Some(Token::Keyword(token)) if token.keyword() == Keyword::In => token.pos(),
+        Some(Token::Punctuator(token)) if token.punctuator() == "{" => {}
         Some(token) => {
             return Err(ParseError::new_unexpected_token(
-                "expected `in` keyword in for loop".to_string(),
+                "expected opening curly brace in record declaration".to_string(),
                 token.pos().clone(),
             ));
         }
         None => {
             return Err(ParseError::new_unexpected_eof(
-                "expected `in` keyword in for loop".to_string(),
+                "expected opening curly brace in record declaration".to_string(),
                 start_pos.extended_to_end(),
             ));
         }
     };
 
+    // Parse the field names
+    let mut fields = Vec::new();
+    let pos = loop {
+        // Expect a field name, or the closing curly brace if there is at least
+        // one field
+        match token_stream.pop_front() {
+            Some(Token::Ident(token)) => {
+                fields.push((token.ident().to_string(), token.pos().clone()));
+            }
+            Some(Token::Punctuator(token)) if token.punctuator() == "}" && !fields.is_empty() => {
+                break start_pos.extended_to(token.pos());
+            }
+            Some(token) => {
+                return Err(ParseError::new_unexpected_token(
+                    "expected field name in record declaration".to_string(),
+                    token.pos().clone(),
+                ));
+            }
+            None => {
+                return Err(ParseError::new_unexpected_eof(
+                    "incomplete record declaration".to_string(),
+                    start_pos.extended_to_end(),
+                ));
+            }
+        }
+
+        // Expect a comma or the closing curly brace
+        match token_stream.pop_front() {
+            Some(Token::Punctuator(token)) if token.punctuator() == "," => {}
+            Some(Token::Punctuator(token)) if token.punctuator() == "}" => {
+                break start_pos.extended_to(token.pos());
+            }
+            Some(token) => {
+                return Err(ParseError::new_unexpected_token(
+                    "expected comma or closing curly brace in record declaration".to_string(),
+                    token.pos().clone(),
+                ));
+            }
+            None => {
+                return Err(ParseError::new_unexpected_eof(
+                    "incomplete record declaration".to_string(),
+                    start_pos.extended_to_end(),
+                ));
+            }
+        }
+    };
+
+    Ok(AstNodeRecordDeclaration::new(name, fields, pos).into())
+}
+
+/// Parses an if-else statement from a token stream
+///
+/// # Panics
+/// - If the token stream is empty
+fn parse_if_else_statement<'source>(
+    token_stream: &mut VecDeque<&Token<'source>>,
+) -> Result<AstNode<'source>, ParseError<'source>> {
+    assert!(!token_stream.is_empty());
+
+    // Expect an "if" keyword
+    let mut pos = match token_stream.pop_front().unwrap() {
+        Token::Keyword(token) if token.keyword() == Keyword::If => token.pos().clone(),
+        token => {
+            return Err(ParseError::new_unexpected_token(
+                "expected `if` keyword in if statement".to_string(),
+                token.pos().clone(),
+            ));
+        }
+    };
+
     // Ensure the token stream isn't empty
     if token_stream.is_empty() {
         return Err(ParseError::new_unexpected_eof(
-            "incomplete for loop".to_string(),
-            start_pos.extended_to_end(),
+            "incomplete if statement".to_string(),
+            pos.extended_to_end(),
         ));
     };
 
-    // Parse the iterable expression
-    let iterable = parse_expression(token_stream)?;
+    // Parse the condition of the if statement
+    let condition = parse_expression(token_stream)?;
 
     // Ensure the token stream isn't empty
     if token_stream.is_empty() {
         return Err(ParseError::new_unexpected_eof(
-            "incomplete for loop".to_string(),
-            start_pos.extended_to_end(),
+            "incomplete if statement".to_string(),
+            pos.extended_to_end(),
         ));
     };
 
-    let (body, end_pos) = parse_code_block(token_stream)?;
-    let pos = start_pos.extended_to(&end_pos);
+    // Parse the body of the if statement
+    let (body, body_pos) = parse_code_block(token_stream)?;
+    pos.extend_to(&body_pos);
+
+    // Keep track of all the conditional branches of this if-else statement
+    let mut conditional_branches = vec![(condition, body)];
+
+    // Parse any else-if statements
+    loop {
+        match (token_stream.front(), token_stream.get(1)) {
+            (Some(Token::Keyword(token_else)), Some(Token::Keyword(token_if)))
+                if token_else.keyword() == Keyword::Else && token_if.keyword() == Keyword::If =>
+            {
+                // Consume the "else" and "if" tokens
+                token_stream.pop_front();
+                token_stream.pop_front();
+
+                // Ensure the token stream isn't empty
+                if token_stream.is_empty() {
+                    return Err(ParseError::new_unexpected_eof(
+                        "incomplete else-if statement".to_string(),
+                        pos.extended_to_end(),
+                    ));
+                };
+
+                // Parse the condition of the else-if statement
+                let condition = parse_expression(token_stream)?;
 
-    Ok(AstNodeForLoop::new(ident, iterable, body, pos).into())
+                // Ensure the token stream isn't empty
+                if token_stream.is_empty() {
+                    return Err(ParseError::new_unexpected_eof(
+                        "incomplete else-if statement".to_string(),
+                        pos.extended_to_end(),
+                    ));
+                };
+
+                // Parse the body of the else-if statement
+                let (body, body_pos) = parse_code_block(token_stream)?;
+                pos.extend_to(&body_pos);
+
+                conditional_branches.push((condition, body));
+            }
+            _ => break,
+        }
+    }
+
+    // Parse an optional else branch
+    let else_branch = match token_stream.front() {
+        Some(Token::Keyword(token)) if token.keyword() == Keyword::Else => {
+            // Consume the "else" token
+            token_stream.pop_front();
+
+            // Ensure the token stream isn't empty
+            if token_stream.is_empty() {
+                return Err(ParseError::new_unexpected_eof(
+                    "incomplete else statement".to_string(),
+                    pos.extended_to_end(),
+                ));
+            };
+
+            // Parse the body of the else statement
+            let (body, body_pos) = parse_code_block(token_stream)?;
+            pos.extend_to(&body_pos);
+
+            Some(body)
+        }
+        _ => None,
+    };
+
+    Ok(AstNodeIfElseStatement::new(conditional_branches, else_branch, pos).into())
 }
 
-/// Parses a single match arm from a token stream
-///
-/// # Panics
-/// - If the token stream is empty
-fn parse_match_arm<'source>(
-    token_stream: &mut VecDeque<&Token<'source>>,
-) -> Result<MatchArm<'source>, ParseError<'source>> {
-    assert!(!token_stream.is_empty());
+/// Parses an optional loop label (like `'outer:`) from the start of a loop
+fn parse_loop_label<'a, 'source>(
+    token_stream: &mut VecDeque<&'a Token<'source>>,
+) -> Result<Option<&'a TokenLabel<'source>>, ParseError<'source>> {
+    let Some(Token::Label(label)) = token_stream.front() else {
+        return Ok(None);
+    };
+
+    // Consume the label
+    token_stream.pop_front();
+
+    // Expect a colon
+    match token_stream.pop_front() {
+        Some(Token::Punctuator(token)) if token.punctuator() == ":" => {}
+        Some(token) => {
+            return Err(ParseError::new_unexpected_token(
+                "expected colon after loop label".to_string(),
+                token.pos().clone(),
+            ));
+        }
+        None => {
+            return Err(ParseError::new_unexpected_eof(
+                "expected colon after loop label".to_string(),
+                label.pos().extended_to_end(),
+            ));
+        }
+    };
+
+    // Ensure the token stream isn't empty
+    if token_stream.is_empty() {
+        return Err(ParseError::new_unexpected_eof(
+            "expected loop after loop label".to_string(),
+            label.pos().extended_to_end(),
+        ));
+    };
+
+    Ok(Some(label))
+}
+
+/// Parses a labeled loop (of any kind) from a token stream
+///
+/// # Panics
+/// - If the token stream is empty
+fn parse_labeled_loop<'source>(
+    token_stream: &mut VecDeque<&Token<'source>>,
+) -> Result<AstNode<'source>, ParseError<'source>> {
+    assert!(!token_stream.is_empty());
+
+    // Look past the label and colon to see what kind of loop this is
+    match token_stream.get(2) {
+        Some(Token::Keyword(token)) if token.keyword() == Keyword::Loop => {
+            parse_simple_loop(token_stream)
+        }
+        Some(Token::Keyword(token)) if token.keyword() == Keyword::While => {
+            parse_while_loop(token_stream)
+        }
+        Some(Token::Keyword(token)) if token.keyword() == Keyword::For => {
+            parse_for_loop(token_stream)
+        }
+        _ => {
+            // Either the label itself is malformed (which parse_loop_label
+            // reports), or it isn't followed by a loop
+            parse_loop_label(token_stream)?;
+
+            Err(ParseError::new_unexpected_token(
+                "expected loop after loop label".to_string(),
+                token_stream.front().unwrap().pos().clone(),
+            ))
+        }
+    }
+}
+
+/// Parses a simple loop from a token stream
+///
+/// # Panics
+/// - If the token stream is empty
+fn parse_simple_loop<'source>(
+    token_stream: &mut VecDeque<&Token<'source>>,
+) -> Result<AstNode<'source>, ParseError<'source>> {
+    assert!(!token_stream.is_empty());
+
+    // Parse the label (if any)
+    let label = parse_loop_label(token_stream)?;
+
+    // Expect a "loop" keyword
+    let keyword_pos = match token_stream.pop_front().unwrap() {
+        Token::Keyword(token) if token.keyword() == Keyword::Loop => token.pos(),
+        token => {
+            return Err(ParseError::new_unexpected_token(
+                "expected `loop` keyword in simple loop".to_string(),
+                token.pos().clone(),
+            ));
+        }
+    };
+    let start_pos = label.map_or(keyword_pos, |label| label.pos());
+
+    // Ensure the token stream isn't empty
+    if token_stream.is_empty() {
+        return Err(ParseError::new_unexpected_eof(
+            "incomplete simple loop".to_string(),
+            start_pos.extended_to_end(),
+        ));
+    };
+
+    let condition = match token_stream.front() {
+        Some(Token::Punctuator(token)) if token.punctuator() == "{" => None,
+        _ => Some(parse_expression(token_stream)?),
+    };
+
+    // Ensure the token stream isn't empty
+    if token_stream.is_empty() {
+        return Err(ParseError::new_unexpected_eof(
+            "incomplete simple loop".to_string(),
+            start_pos.extended_to_end(),
+        ));
+    };
+
+    let (body, end_pos) = parse_code_block(token_stream)?;
+    let pos = start_pos.extended_to(&end_pos);
+
+    Ok(AstNodeSimpleLoop::new(
+        condition,
+        body,
+        label.map(|label| label.label().to_string()),
+        pos,
+    )
+    .into())
+}
+
+/// Parses a while loop from a token stream
+///
+/// # Panics
+/// - If the token stream is empty
+fn parse_while_loop<'source>(
+    token_stream: &mut VecDeque<&Token<'source>>,
+) -> Result<AstNode<'source>, ParseError<'source>> {
+    assert!(!token_stream.is_empty());
+
+    // Parse the label (if any)
+    let label = parse_loop_label(token_stream)?;
+
+    // Expect a "while" keyword
+    let keyword_pos = match token_stream.pop_front().unwrap() {
+        Token::Keyword(token) if token.keyword() == Keyword::While => token.pos(),
+        token => {
+            return Err(ParseError::new_unexpected_token(
+                "expected `while` keyword in while loop".to_string(),
+                token.pos().clone(),
+            ));
+        }
+    };
+    let start_pos = label.map_or(keyword_pos, |label| label.pos());
+
+    // Ensure the token stream isn't empty
+    if token_stream.is_empty() {
+        return Err(ParseError::new_unexpected_eof(
+            "incomplete while loop".to_string(),
+            start_pos.extended_to_end(),
+        ));
+    };
+
+    let condition = parse_expression(token_stream)?;
+
+    // Ensure the token stream isn't empty
+    if token_stream.is_empty() {
+        return Err(ParseError::new_unexpected_eof(
+            "incomplete while loop".to_string(),
+            start_pos.extended_to_end(),
+        ));
+    };
+
+    let (body, end_pos) = parse_code_block(token_stream)?;
+    let pos = start_pos.extended_to(&end_pos);
+
+    Ok(AstNodeWhileLoop::new(
+        condition,
+        body,
+        label.map(|label| label.label().to_string()),
+        pos,
+    )
+    .into())
+}
+
+/// Parses a try-catch statement from a token stream
+///
+/// # Panics
+/// - If the token stream is empty
+fn parse_try_catch<'source>(
+    token_stream: &mut VecDeque<&Token<'source>>,
+) -> Result<AstNode<'source>, ParseError<'source>> {
+    assert!(!token_stream.is_empty());
+
+    // Expect a "try" keyword
+    let start_pos = match token_stream.pop_front().unwrap() {
+        Token::Keyword(token) if token.keyword() == Keyword::Try => token.pos(),
+        token => {
+            return Err(ParseError::new_unexpected_token(
+                "expected `try` keyword in try-catch statement".to_string(),
+                token.pos().clone(),
+            ));
+        }
+    };
+
+    // Ensure the token stream isn't empty
+    if token_stream.is_empty() {
+        return Err(ParseError::new_unexpected_eof(
+            "incomplete try-catch statement".to_string(),
+            start_pos.extended_to_end(),
+        ));
+    };
+
+    let (try_body, _) = parse_code_block(token_stream)?;
+
+    // Expect a "catch" keyword
+    match token_stream.pop_front() {
+        Some(Token::Keyword(token)) if token.keyword() == Keyword::Catch => {}
+        Some(token) => {
+            return Err(ParseError::new_unexpected_token(
+                "expected `catch` keyword in try-catch statement".to_string(),
+                token.pos().clone(),
+            ));
+        }
+        None => {
+            return Err(ParseError::new_unexpected_eof(
+                "incomplete try-catch statement".to_string(),
+                start_pos.extended_to_end(),
+            ));
+        }
+    }
+
+    // Expect the identifier to bind the caught error to
+    let error_ident = match token_stream.pop_front() {
+        Some(Token::Ident(token)) => token.ident().to_string(),
+        Some(token) => {
+            return Err(ParseError::new_unexpected_token(
+                "expected identifier after `catch` in try-catch statement".to_string(),
+                token.pos().clone(),
+            ));
+        }
+        None => {
+            return Err(ParseError::new_unexpected_eof(
+                "incomplete try-catch statement".to_string(),
+                start_pos.extended_to_end(),
+            ));
+        }
+    };
+
+    // Ensure the token stream isn't empty
+    if token_stream.is_empty() {
+        return Err(ParseError::new_unexpected_eof(
+            "incomplete try-catch statement".to_string(),
+            start_pos.extended_to_end(),
+        ));
+    };
+
+    let (catch_body, end_pos) = parse_code_block(token_stream)?;
+    let pos = start_pos.extended_to(&end_pos);
+
+    Ok(AstNodeTryCatch::new(try_body, error_ident, catch_body, pos).into())
+}
+
+/// Parses a for loop from a token stream
+///
+/// # Panics
+/// - If the token stream is empty
+fn parse_for_loop<'source>(
+    token_stream: &mut VecDeque<&Token<'source>>,
+) -> Result<AstNode<'source>, ParseError<'source>> {
+    assert!(!token_stream.is_empty());
+
+    // Parse the label (if any)
+    let label = parse_loop_label(token_stream)?;
+
+    // Expect a "for" keyword
+    let keyword_pos = match token_stream.pop_front().unwrap() {
+        Token::Keyword(token) if token.keyword() == Keyword::For => token.pos(),
+        token => {
+            return Err(ParseError::new_unexpected_token(
+                "expected `for` keyword in for loop".to_string(),
+                token.pos().clone(),
+            ));
+        }
+    };
+    let start_pos = label.map_or(keyword_pos, |label| label.pos());
+
+    // Parse the loop-control variable pattern(s)
+    let (pattern, multiple_variables) =
+        parse_for_loop_patterns(token_stream, start_pos, "for loop")?;
+
+    // Ensure the token stream isn't empty
+    if token_stream.is_empty() {
+        return Err(ParseError::new_unexpected_eof(
+            "incomplete for loop".to_string(),
+            start_pos.extended_to_end(),
+        ));
+    };
+
+    // Parse the iterable expression
+    let iterable = parse_expression(token_stream)?;
+
+    // Ensure the token stream isn't empty
+    if token_stream.is_empty() {
+        return Err(ParseError::new_unexpected_eof(
+            "incomplete for loop".to_string(),
+            start_pos.extended_to_end(),
+        ));
+    };
+
+    let (body, end_pos) = parse_code_block(token_stream)?;
+    let pos = start_pos.extended_to(&end_pos);
+
+    Ok(AstNodeForLoop::new(
+        pattern,
+        multiple_variables,
+        iterable,
+        body,
+        label.map(|label| label.label().to_string()),
+        pos,
+    )
+    .into())
+}
+
+/// Parses the loop-control variable pattern(s) of a for loop (or of a `for`
+/// clause in a comprehension) from a token stream, along with the `in` keyword
+/// which follows them. Several comma-separated patterns (like `for k, v in
+/// ...`) are combined into a single list pattern, in which case the returned
+/// bool is true
+///
+/// `construct` describes what is being parsed, for use in error messages
+fn parse_for_loop_patterns<'source>(
+    token_stream: &mut VecDeque<&Token<'source>>,
+    start_pos: &SourceRange<'source>,
+    construct: &str,
+) -> Result<(DestructuringPattern<'source>, bool), ParseError<'source>> {
+    // Expect an identifier or pattern
+    let pattern = match token_stream.front() {
+        Some(token) if starts_destructuring_pattern(token) => {
+            parse_destructuring_pattern(token_stream, false)?
+        }
+        Some(token) => {
+            return Err(ParseError::new_unexpected_token(
+                format!("expected identifier in {construct}"),
+                token.pos().clone(),
+            ));
+        }
+        None => {
+            return Err(ParseError::new_unexpected_eof(
+                format!("expected identifier in {construct}"),
+                start_pos.extended_to_end(),
+            ));
+        }
+    };
+
+    // Parse any more comma-separated patterns (like `for k, v in ...`), which
+    // are combined into a single list pattern
+    let mut patterns = vec![pattern];
+    while let Some(Token::Punctuator(token)) = token_stream.front() {
+        if token.punctuator() != "," {
+            break;
+        }
+        token_stream.pop_front();
+
+        match token_stream.front() {
+            Some(token) if starts_destructuring_pattern(token) => {
+                patterns.push(parse_destructuring_pattern(token_stream, false)?);
+            }
+            Some(token) => {
+                return Err(ParseError::new_unexpected_token(
+                    format!("expected identifier after `,` in {construct}"),
+                    token.pos().clone(),
+                ));
+            }
+            None => {
+                return Err(ParseError::new_unexpected_eof(
+                    format!("expected identifier after `,` in {construct}"),
+                    start_pos.extended_to_end(),
+                ));
+            }
+        }
+    }
+    let multiple_variables = patterns.len() > 1;
+    let pattern = match multiple_variables {
+        true => {
+            let pos = patterns[0]
+                .pos()
+                .extended_to(patterns[patterns.len() - 1].pos());
+            DestructuringPattern::List {
+                elements: patterns,
+                rest: None,
+                pos,
+            }
+        }
+        false => patterns.pop().unwrap(),
+    };
+
+    // Expect an "in" keyword
+    match token_stream.pop_front() {
+        Some(Token::Keyword(token)) if token.keyword() == Keyword::In => token.pos(),
+        Some(token) => {
+            return Err(ParseError::new_unexpected_token(
+                format!("expected `in` keyword in {construct}"),
+                token.pos().clone(),
+            ));
+        }
+        None => {
+            return Err(ParseError::new_unexpected_eof(
+                format!("expected `in` keyword in {construct}"),
+                start_pos.extended_to_end(),
+            ));
+        }
+    };
+
+    Ok((pattern, multiple_variables))
+}
+
+/// Returns whether or not an expression can be assigned to (a variable access,
+/// or a member access or slice which isn't optional)
+fn is_assignment_target(node: &AstNode) -> bool {
+    match node {
+        AstNode::VariableAccess(_) => true,
+        AstNode::DotMemberAccess(node) => !node.is_optional(),
+        AstNode::ComputedMemberAccess(node) => !node.is_optional(),
+        AstNode::Slice(node) => !node.is_optional(),
+        _ => false,
+    }
+}
+
+/// Returns whether or not an expression is a dotted path (an identifier,
+/// followed by any number of dot member accesses)
+fn is_dotted_path(node: &AstNode) -> bool {
+    match node {
+        AstNode::VariableAccess(_) => true,
+        AstNode::DotMemberAccess(node) => !node.is_optional() && is_dotted_path(node.root()),
+        _ => false,
+    }
+}
+
+/// Returns whether or not an expression may be used as a value pattern in a
+/// match arm (a literal, a signed number literal, or a constant accessed
+/// through dot member access, like `Color.red`)
+fn is_match_pattern_value(node: &AstNode) -> bool {
+    match node {
+        AstNode::Literal(_) => true,
+        AstNode::UnaryOperation(node) => {
+            matches!(
+                node.operation(),
+                UnaryOperationKind::Negation | UnaryOperationKind::Identity
+            ) && matches!(
+                node.operand(),
+                AstNode::Literal(literal) if matches!(
+                    literal.icelang_type(),
+                    IcelangType::Int | IcelangType::Byte | IcelangType::Float
+                )
+            )
+        }
+        AstNode::DotMemberAccess(node) => !node.is_optional() && is_dotted_path(node.root()),
+        _ => false,
+    }
+}
+
+/// Parses a value in a match pattern (see `is_match_pattern_value`) from a
+/// token stream
+///
+/// # Panics
+/// - If the token stream is empty
+fn parse_match_pattern_value<'source>(
+    token_stream: &mut VecDeque<&Token<'source>>,
+) -> Result<AstNode<'source>, ParseError<'source>> {
+    let value = parse_expr_unary_prefix(token_stream)?;
+    if !is_match_pattern_value(&value) {
+        return Err(ParseError::new_unexpected_token(
+            "expected a literal or a constant (like `Color.red`) in match pattern".to_string(),
+            value.pos().clone(),
+        ));
+    }
+
+    Ok(value)
+}
+
+/// Parses a pattern in a match arm (possibly with alternatives) from a token
+/// stream
+///
+/// # Panics
+/// - If the token stream is empty
+fn parse_match_pattern<'source>(
+    token_stream: &mut VecDeque<&Token<'source>>,
+) -> Result<MatchPattern<'source>, ParseError<'source>> {
+    assert!(!token_stream.is_empty());
+
+    // Parse the first (and possibly only) alternative
+    let first = parse_single_match_pattern(token_stream)?;
+
+    // Parse any additional alternatives
+    let mut alternatives = vec![first];
+    loop {
+        match token_stream.front() {
+            Some(Token::Punctuator(token)) if token.punctuator() == "|" => {
+                // Consume the "|"
+                token_stream.pop_front();
+
+                // Ensure the token stream isn't empty
+                if token_stream.is_empty() {
+                    return Err(ParseError::new_unexpected_eof(
+                        "expected pattern after `|` in match pattern".to_string(),
+                        token.pos().extended_to_end(),
+                    ));
+                };
+
+                alternatives.push(parse_single_match_pattern(token_stream)?);
+            }
+            _ => break,
+        }
+    }
+
+    if alternatives.len() == 1 {
+        return Ok(alternatives.pop().unwrap());
+    }
+
+    // Every alternative must bind the same identifiers, so that they're always
+    // declared no matter which alternative matched
+    let binding_names = |pattern: &MatchPattern<'source>| {
+        let mut names: Vec<String> = pattern
+            .bindings()
+            .into_iter()
+            .map(|(ident, _)| ident.to_string())
+            .collect();
+        names.sort();
+        names
+    };
+    let expected_names = binding_names(&alternatives[0]);
+    for alternative in &alternatives[1..] {
+        if binding_names(alternative) != expected_names {
+            return Err(ParseError::new_unexpected_token(
+                "every alternative in a match pattern must bind the same identifiers".to_string(),
+                alternative.pos().clone(),
+            ));
+        }
+    }
+
+    let pos = alternatives[0]
+        .pos()
+        .extended_to(alternatives.last().unwrap().pos());
+
+    Ok(MatchPattern::Alternatives { alternatives, pos })
+}
+
+/// Parses a pattern in a match arm (without alternatives) from a token stream
+///
+/// # Panics
+/// - If the token stream is empty
+fn parse_single_match_pattern<'source>(
+    token_stream: &mut VecDeque<&Token<'source>>,
+) -> Result<MatchPattern<'source>, ParseError<'source>> {
+    assert!(!token_stream.is_empty());
+
+    match token_stream.front().unwrap() {
+        // List pattern
+        Token::Punctuator(token) if token.punctuator() == "[" => {
+            // Consume the "["
+            token_stream.pop_front();
+            let start_pos = token.pos();
+
+            let mut elements = Vec::new();
+            let mut rest = None;
+            let pos = loop {
+                // Expect an element, a rest element, or the closing square
+                // bracket
+                match token_stream.front() {
+                    Some(Token::Punctuator(token)) if token.punctuator() == "]" => {
+                        // Consume the "]"
+                        token_stream.pop_front();
+
+                        break start_pos.extended_to(token.pos());
+                    }
+                    Some(Token::Punctuator(token)) if token.punctuator() == "..." => {
+                        // Consume the "..."
+                        token_stream.pop_front();
+
+                        if rest.is_some() {
+                            return Err(ParseError::new_unexpected_token(
+                                "list pattern can only have one rest element".to_string(),
+                                token.pos().clone(),
+                            ));
+                        }
+
+                        // The rest element may leave out its pattern, in which
+                        // case it matches anything
+                        let rest_pattern = match token_stream.front() {
+                            Some(Token::Punctuator(next))
+                                if next.punctuator() == "," || next.punctuator() == "]" =>
+                            {
+                                MatchPattern::Wildcard {
+                                    pos: token.pos().clone(),
+                                }
+                            }
+                            Some(_) => parse_match_pattern(token_stream)?,
+                            None => {
+                                return Err(ParseError::new_unexpected_eof(
+                                    "incomplete list pattern".to_string(),
+                                    token.pos().extended_to_end(),
+                                ));
+                            }
+                        };
+                        rest = Some((elements.len(), Box::new(rest_pattern)));
+                    }
+                    Some(_) => {
+                        elements.push(parse_match_pattern(token_stream)?);
+                    }
+                    None => {
+                        return Err(ParseError::new_unexpected_eof(
+                            "incomplete list pattern".to_string(),
+                            start_pos.extended_to_end(),
+                        ));
+                    }
+                }
+
+                // Expect a comma or the closing square bracket
+                match token_stream.pop_front() {
+                    Some(Token::Punctuator(token)) if token.punctuator() == "," => {}
+                    Some(Token::Punctuator(token)) if token.punctuator() == "]" => {
+                        break start_pos.extended_to(token.pos());
+                    }
+                    Some(token) => {
+                        return Err(ParseError::new_unexpected_token(
+                            "expected comma or closing square bracket in list pattern".to_string(),
+                            token.pos().clone(),
+                        ));
+                    }
+                    None => {
+                        return Err(ParseError::new_unexpected_eof(
+                            "incomplete list pattern".to_string(),
+                            start_pos.extended_to_end(),
+                        ));
+                    }
+                }
+            };
+
+            Ok(MatchPattern::List {
+                elements,
+                rest,
+                pos,
+            })
+        }
+
+        // Dict pattern
+        Token::Punctuator(token) if token.punctuator() == "{" => {
+            // Consume the "{"
+            token_stream.pop_front();
+            let start_pos = token.pos();
+
+            let mut entries = Vec::new();
+            let mut rest = None;
+            let pos = loop {
+                // Expect an entry, a rest element, or the closing curly brace
+                match token_stream.front() {
+                    Some(Token::Punctuator(token)) if token.punctuator() == "}" => {
+                        // Consume the "}"
+                        token_stream.pop_front();
+
+                        break start_pos.extended_to(token.pos());
+                    }
+                    Some(Token::Punctuator(token)) if token.punctuator() == "..." => {
+                        // Consume the "..."
+                        token_stream.pop_front();
+
+                        // The rest element may leave out its pattern, in which
+                        // case it matches anything
+                        let rest_pattern = match token_stream.front() {
+                            Some(Token::Punctuator(next))
+                                if next.punctuator() == "," || next.punctuator() == "}" =>
+                            {
+                                MatchPattern::Wildcard {
+                                    pos: token.pos().clone(),
+                                }
+                            }
+                            Some(_) => parse_match_pattern(token_stream)?,
+                            None => {
+                                return Err(ParseError::new_unexpected_eof(
+                                    "incomplete dict pattern".to_string(),
+                                    token.pos().extended_to_end(),
+                                ));
+                            }
+                        };
+                        rest = Some(Box::new(rest_pattern));
+                    }
+                    Some(_) => {
+                        // Parse the key
+                        let key = parse_expression(token_stream)?;
+
+                        // Expect a colon
+                        match token_stream.pop_front() {
+                            Some(Token::Punctuator(token)) if token.punctuator() == ":" => {}
+                            Some(token) => {
+                                return Err(ParseError::new_unexpected_token(
+                                    "expected colon in dict pattern entry".to_string(),
+                                    token.pos().clone(),
+                                ));
+                            }
+                            None => {
+                                return Err(ParseError::new_unexpected_eof(
+                                    "expected colon in dict pattern entry".to_string(),
+                                    key.pos().extended_to_end(),
+                                ));
+                            }
+                        };
+
+                        // Ensure the token stream isn't empty
+                        if token_stream.is_empty() {
+                            return Err(ParseError::new_unexpected_eof(
+                                "incomplete dict pattern entry".to_string(),
+                                key.pos().extended_to_end(),
+                            ));
+                        };
+
+                        // Parse the value pattern
+                        let value = parse_match_pattern(token_stream)?;
+                        entries.push((key, value));
+                    }
+                    None => {
+                        return Err(ParseError::new_unexpected_eof(
+                            "incomplete dict pattern".to_string(),
+                            start_pos.extended_to_end(),
+                        ));
+                    }
+                }
+
+                // Expect a comma or the closing curly brace. The rest element
+                // must be the last one
+                match token_stream.pop_front() {
+                    Some(Token::Punctuator(token))
+                        if token.punctuator() == "," && rest.is_none() => {}
+                    Some(Token::Punctuator(token)) if token.punctuator() == "," => {
+                        // The trailing comma after the rest element
+                        match token_stream.pop_front() {
+                            Some(Token::Punctuator(token)) if token.punctuator() == "}" => {
+                                break start_pos.extended_to(token.pos());
+                            }
+                            Some(token) => {
+                                return Err(ParseError::new_unexpected_token(
+                                    "the rest element must be last in a dict pattern".to_string(),
+                                    token.pos().clone(),
+                                ));
+                            }
+                            None => {
+                                return Err(ParseError::new_unexpected_eof(
+                                    "incomplete dict pattern".to_string(),
+                                    start_pos.extended_to_end(),
+                                ));
+                            }
+                        }
+                    }
+                    Some(Token::Punctuator(token)) if token.punctuator() == "}" => {
+                        break start_pos.extended_to(token.pos());
+                    }
+                    Some(token) => {
+                        return Err(ParseError::new_unexpected_token(
+                            "expected comma or closing curly brace in dict pattern".to_string(),
+                            token.pos().clone(),
+                        ));
+                    }
+                    None => {
+                        return Err(ParseError::new_unexpected_eof(
+                            "incomplete dict pattern".to_string(),
+                            start_pos.extended_to_end(),
+                        ));
+                    }
+                }
+            };
+
+            Ok(MatchPattern::Dict { entries, rest, pos })
+        }
+
+        // Type pattern
+        Token::Keyword(token) if token.keyword().can_only_be_type() => {
+            // Consume the type keyword
+            token_stream.pop_front();
+            let icelang_type = token.keyword().icelang_type().unwrap();
+
+            // The type may be followed by a parenthesized pattern, which the
+            // value must also match
+            match token_stream.front() {
+                Some(Token::Punctuator(lparen)) if lparen.punctuator() == "(" => {
+                    // Consume the "("
+                    token_stream.pop_front();
+
+                    // Ensure the token stream isn't empty
+                    if token_stream.is_empty() {
+                        return Err(ParseError::new_unexpected_eof(
+                            "incomplete type pattern".to_string(),
+                            token.pos().extended_to_end(),
+                        ));
+                    };
+
+                    let inner = parse_match_pattern(token_stream)?;
+
+                    // Expect a closing parenthesis
+                    let end_pos = match token_stream.pop_front() {
+                        Some(Token::Punctuator(token)) if token.punctuator() == ")" => token.pos(),
+                        Some(token) => {
+                            return Err(ParseError::new_unexpected_token(
+                                "expected closing parenthesis in type pattern".to_string(),
+                                token.pos().clone(),
+                            ));
+                        }
+                        None => {
+                            return Err(ParseError::new_unexpected_eof(
+                                "incomplete type pattern".to_string(),
+                                token.pos().extended_to_end(),
+                            ));
+                        }
+                    };
+
+                    Ok(MatchPattern::Type {
+                        icelang_type,
+                        inner: Some(Box::new(inner)),
+                        pos: token.pos().extended_to(end_pos),
+                    })
+                }
+                _ => Ok(MatchPattern::Type {
+                    icelang_type,
+                    inner: None,
+                    pos: token.pos().clone(),
+                }),
+            }
+        }
+
+        // Wildcard or binding pattern (but not a dotted path, which is a value
+        // pattern)
+        Token::Ident(token)
+            if !matches!(
+                token_stream.get(1),
+                Some(Token::Punctuator(next)) if next.punctuator() == "."
+            ) =>
+        {
+            // Consume the identifier
+            token_stream.pop_front();
+
+            Ok(match token.ident() {
+                "_" => MatchPattern::Wildcard {
+                    pos: token.pos().clone(),
+                },
+                ident => MatchPattern::Binding {
+                    ident: ident.to_string(),
+                    pos: token.pos().clone(),
+                },
+            })
+        }
+
+        // Value pattern or range pattern
+        _ => {
+            let value = parse_match_pattern_value(token_stream)?;
+
+            let inclusive = match token_stream.front() {
+                Some(Token::Punctuator(token)) if token.punctuator() == ".." => false,
+                Some(Token::Punctuator(token)) if token.punctuator() == "..=" => true,
+                _ => return Ok(MatchPattern::Value(Box::new(value))),
+            };
+            let operator = token_stream.pop_front().unwrap();
+
+            // Parse the end of the range
+            if token_stream.is_empty() {
+                return Err(ParseError::new_unexpected_eof(
+                    "expected end of range pattern".to_string(),
+                    operator.pos().extended_to_end(),
+                ));
+            }
+            let end = parse_match_pattern_value(token_stream)?;
+
+            Ok(MatchPattern::Range(AstNodeRangeLiteral::new(
+                value, end, inclusive,
+            )))
+        }
+    }
+}
+
+/// Parses a single match arm from a token stream
+///
+/// # Panics
+/// - If the token stream is empty
+fn parse_match_arm<'source>(
+    token_stream: &mut VecDeque<&Token<'source>>,
+) -> Result<MatchArm<'source>, ParseError<'source>> {
+    assert!(!token_stream.is_empty());
+
+    // Parse the pattern to match
+    let pattern = parse_match_pattern(token_stream)?;
+
+    // A pattern can't bind the same identifier more than once
+    let bindings = pattern.bindings();
+    for (index, (ident, pos)) in bindings.iter().enumerate() {
+        if bindings[..index].iter().any(|(other, _)| other == ident) {
+            return Err(ParseError::new_unexpected_token(
+                format!("identifier {ident} is bound more than once in the same pattern"),
+                (*pos).clone(),
+            ));
+        }
+    }
+
+    // Parse the guard (if any)
+    let guard = match token_stream.front() {
+        Some(Token::Keyword(token)) if token.keyword() == Keyword::If => {
+            // Consume the "if"
+            token_stream.pop_front();
 
-    // Parse the pattern to match
-    let pattern = parse_expression(token_stream)?;
+            // Ensure the token stream isn't empty
+            if token_stream.is_empty() {
+                return Err(ParseError::new_unexpected_eof(
+                    "expected guard condition in match arm".to_string(),
+                    token.pos().extended_to_end(),
+                ));
+            };
+
+            Some(parse_expression(token_stream)?)
+        }
+        _ => None,
+    };
 
     // Expect a fat-arrow
     match token_stream.pop_front() {
@@ -658,7 +2039,7 @@ fn parse_match_arm<'source>(
     let (body, body_pos) = parse_code_block(token_stream)?;
     let pos = pattern.pos().extended_to(&body_pos);
 
-    Ok(MatchArm::new(pattern, body, pos))
+    Ok(MatchArm::new(pattern, guard, body, pos))
 }
 
 /// Parses a match statement from a token stream
@@ -787,6 +2168,16 @@ fn parse_match_statement<'source>(
         }
     };
 
+    // Arms after an unguarded arm which matches every value can never run
+    if let Some(index) = arms
+        .iter()
+        .position(|arm| arm.guard().is_none() && arm.pattern().is_irrefutable())
+    {
+        if let Some(arm) = arms.get(index + 1) {
+            return Err(ParseError::new_unreachable_match_arm(arm.pos().clone()));
+        }
+    }
+
     Ok(AstNodeMatchStatement::new(matched_expression, arms, pos).into())
 }
 
@@ -932,6 +2323,11 @@ fn parse_atomic<'source>(
         // Formatted string literal
         Token::FormattedStringLiteralSection(_) => parse_formatted_string_literal(token_stream),
 
+        // Anonymous function
+        Token::Keyword(token) if token.keyword() == Keyword::Fn => {
+            parse_anonymous_function(token_stream)
+        }
+
         // Null literal
         Token::Keyword(token) if token.keyword() == Keyword::Null => {
             // Consume the null keyword token
@@ -977,7 +2373,7 @@ fn parse_formatted_string_literal<'source>(
 ) -> Result<AstNode<'source>, ParseError<'source>> {
     assert!(!token_stream.is_empty());
 
-    let (start, start_pos) = match token_stream.pop_front().unwrap() {
+    let (mut start, start_pos) = match token_stream.pop_front().unwrap() {
         Token::FormattedStringLiteralSection(token)
             if token.kind() == FormattedStringLiteralSectionKind::Complete =>
         {
@@ -1001,7 +2397,11 @@ fn parse_formatted_string_literal<'source>(
             };
 
             (
-                (token.value().to_string(), parse_expression(token_stream)?),
+                (
+                    token.value().to_string(),
+                    parse_expression(token_stream)?,
+                    None,
+                ),
                 token.pos(),
             )
         }
@@ -1014,12 +2414,20 @@ fn parse_formatted_string_literal<'source>(
     };
 
     // Parse any continuations and the end
-    let mut continuations = Vec::new();
+    let mut continuations: Vec<(String, AstNode, Option<FormatSpec>)> = Vec::new();
     let (end, end_pos) = loop {
         match token_stream.pop_front() {
             Some(Token::FormattedStringLiteralSection(token))
                 if token.kind() == FormattedStringLiteralSectionKind::Continuation =>
             {
+                // The section's format spec belongs to the replacement field
+                // before it
+                let format_spec = parse_format_spec(token)?;
+                match continuations.last_mut() {
+                    Some(continuation) => continuation.2 = format_spec,
+                    None => start.2 = format_spec,
+                }
+
                 // Ensure the token stream isn't empty
                 if token_stream.is_empty() {
                     return Err(ParseError::new_unexpected_eof(
@@ -1028,11 +2436,23 @@ fn parse_formatted_string_literal<'source>(
                     ));
                 };
 
-                continuations.push((token.value().to_string(), parse_expression(token_stream)?));
+                continuations.push((
+                    token.value().to_string(),
+                    parse_expression(token_stream)?,
+                    None,
+                ));
             }
             Some(Token::FormattedStringLiteralSection(token))
                 if token.kind() == FormattedStringLiteralSectionKind::End =>
             {
+                // The section's format spec belongs to the replacement field
+                // before it
+                let format_spec = parse_format_spec(token)?;
+                match continuations.last_mut() {
+                    Some(continuation) => continuation.2 = format_spec,
+                    None => start.2 = format_spec,
+                }
+
                 break (token.value().to_string(), token.pos());
             }
             Some(token) => {
@@ -1054,6 +2474,71 @@ fn parse_formatted_string_literal<'source>(
     Ok(AstNodeFormattedStringLiteral::new(start, continuations, end, pos).into())
 }
 
+/// Parses the format spec (if any) of the replacement field before a
+/// formatted string literal section
+fn parse_format_spec<'source>(
+    token: &TokenFormattedStringLiteralSection<'source>,
+) -> Result<Option<FormatSpec>, ParseError<'source>> {
+    token
+        .format_spec()
+        .map(|format_spec| {
+            FormatSpec::parse(format_spec).map_err(|why| {
+                ParseError::new_unexpected_token(
+                    format!("invalid format spec `{format_spec}`: {why}"),
+                    token.pos().clone(),
+                )
+            })
+        })
+        .transpose()
+}
+
+/// Parses an expression which may be preceded by a spread operator (`...`)
+/// from a token stream, as an element of a list literal or an argument of a
+/// function call
+///
+/// # Panics
+/// - If the token stream is empty
+fn parse_spreadable_expression<'source>(
+    token_stream: &mut VecDeque<&Token<'source>>,
+) -> Result<AstNode<'source>, ParseError<'source>> {
+    assert!(!token_stream.is_empty());
+
+    match token_stream.front() {
+        Some(Token::Punctuator(token)) if token.punctuator() == "..." => {
+            Ok(parse_spread(token_stream)?.into())
+        }
+        _ => parse_expression(token_stream),
+    }
+}
+
+/// Parses a spread (`...expression`) from a token stream
+///
+/// # Panics
+/// - If the token stream doesn't start with a spread operator (`...`)
+fn parse_spread<'source>(
+    token_stream: &mut VecDeque<&Token<'source>>,
+) -> Result<AstNodeSpread<'source>, ParseError<'source>> {
+    // Consume the "..."
+    let start_pos = match token_stream.pop_front() {
+        Some(Token::Punctuator(token)) if token.punctuator() == "..." => token.pos(),
+        _ => panic!("expected spread operator"),
+    };
+
+    // Ensure the token stream isn't empty
+    if token_stream.is_empty() {
+        return Err(ParseError::new_unexpected_eof(
+            "incomplete spread".to_string(),
+            start_pos.extended_to_end(),
+        ));
+    };
+
+    // Parse the collection being spread
+    let body = parse_expression(token_stream)?;
+    let pos = start_pos.extended_to(body.pos());
+
+    Ok(AstNodeSpread::new(body, pos))
+}
+
 /// Parses a list literal from a token stream
 ///
 /// # Panics
@@ -1089,7 +2574,19 @@ fn parse_list_literal<'source>(
             }
             Some(_) => {
                 // Parse the next element
-                elements.push(parse_expression(token_stream)?);
+                elements.push(parse_spreadable_expression(token_stream)?);
+
+                // A single element followed by a `for` clause is the start of
+                // a list comprehension instead
+                let is_single_element =
+                    matches!(&elements[..], [element] if !matches!(element, AstNode::Spread(_)));
+                if is_single_element && starts_comprehension(token_stream) {
+                    let element = elements.pop().unwrap();
+                    let clauses = parse_comprehension_clauses(token_stream)?;
+                    let pos = parse_comprehension_end(token_stream, "]", start_pos)?;
+
+                    return Ok(AstNodeListComprehension::new(element, clauses, pos).into());
+                }
 
                 match token_stream.front() {
                     Some(Token::Punctuator(token)) if token.punctuator() == "," => {
@@ -1150,18 +2647,186 @@ fn parse_list_literal<'source>(
     Ok(AstNodeListLiteral::new(elements, pos).into())
 }
 
+/// Returns whether or not the next token in a token stream is the `for`
+/// keyword which starts the clauses of a comprehension
+fn starts_comprehension(token_stream: &VecDeque<&Token>) -> bool {
+    matches!(
+        token_stream.front(),
+        Some(Token::Keyword(token)) if token.keyword() == Keyword::For
+    )
+}
+
+/// Parses the clauses of a list or dict comprehension from a token stream
+/// (each of which is either `for <pattern> in <iterable>` or `if <condition>`)
+///
+/// # Panics
+/// - If the token stream is empty
+fn parse_comprehension_clauses<'source>(
+    token_stream: &mut VecDeque<&Token<'source>>,
+) -> Result<Vec<ComprehensionClause<'source>>, ParseError<'source>> {
+    assert!(!token_stream.is_empty());
+
+    let mut clauses = Vec::new();
+    loop {
+        let keyword_token = match token_stream.front() {
+            Some(Token::Keyword(token))
+                if token.keyword() == Keyword::For || token.keyword() == Keyword::If =>
+            {
+                token
+            }
+            _ => break,
+        };
+        let start_pos = keyword_token.pos();
+
+        // Consume the keyword
+        token_stream.pop_front();
+
+        // Parse the loop-control variable pattern(s) of a `for` clause
+        let for_loop_patterns = match keyword_token.keyword() {
+            Keyword::For => Some(parse_for_loop_patterns(
+                token_stream,
+                start_pos,
+                "comprehension",
+            )?),
+            _ => None,
+        };
+
+        // Ensure the token stream isn't empty
+        if token_stream.is_empty() {
+            return Err(ParseError::new_unexpected_eof(
+                "incomplete comprehension".to_string(),
+                start_pos.extended_to_end(),
+            ));
+        };
+
+        // Parse the iterable or condition
+        let expression = parse_expression(token_stream)?;
+        let pos = start_pos.extended_to(expression.pos());
+
+        clauses.push(match for_loop_patterns {
+            Some((pattern, multiple_variables)) => ComprehensionClause::For {
+                pattern,
+                multiple_variables,
+                iterable: Box::new(expression),
+                pos,
+            },
+            None => ComprehensionClause::If {
+                condition: Box::new(expression),
+                pos,
+            },
+        });
+    }
+
+    Ok(clauses)
+}
+
+/// Expects the closing bracket (`closing_bracket`) at the end of a list or
+/// dict comprehension which started at `start_pos`, and returns the position
+/// of the whole comprehension
+fn parse_comprehension_end<'source>(
+    token_stream: &mut VecDeque<&Token<'source>>,
+    closing_bracket: &str,
+    start_pos: &SourceRange<'source>,
+) -> Result<SourceRange<'source>, ParseError<'source>> {
+    match token_stream.pop_front() {
+        Some(Token::Punctuator(token)) if token.punctuator() == closing_bracket => {
+            Ok(start_pos.extended_to(token.pos()))
+        }
+        Some(token) => Err(ParseError::new_unexpected_token(
+            "unexpected token in comprehension".to_string(),
+            token.pos().clone(),
+        )),
+        None => Err(ParseError::new_unexpected_eof(
+            "incomplete comprehension".to_string(),
+            start_pos.extended_to_end(),
+        )),
+    }
+}
+
+/// Parses the rest of a set literal from a token stream, given its already
+/// parsed first element and the position of its opening curly brace
+fn parse_set_literal<'source>(
+    token_stream: &mut VecDeque<&Token<'source>>,
+    first_element: AstNode<'source>,
+    start_pos: &SourceRange<'source>,
+) -> Result<AstNode<'source>, ParseError<'source>> {
+    let mut elements = vec![first_element];
+    let pos;
+    loop {
+        match token_stream.pop_front() {
+            Some(Token::Punctuator(token)) if token.punctuator() == "," => {
+                match token_stream.front() {
+                    Some(Token::Punctuator(token)) if token.punctuator() == "}" => {
+                        // Consume the "}"
+                        token_stream.pop_front();
+
+                        pos = start_pos.extended_to(token.pos());
+
+                        break;
+                    }
+                    Some(_) => {
+                        // Parse the next element
+                        elements.push(parse_expression(token_stream)?);
+                    }
+                    None => {
+                        return Err(ParseError::new_unexpected_eof(
+                            "incomplete set literal".to_string(),
+                            start_pos.extended_to_end(),
+                        ))
+                    }
+                }
+            }
+            Some(Token::Punctuator(token)) if token.punctuator() == "}" => {
+                pos = start_pos.extended_to(token.pos());
+
+                break;
+            }
+            Some(token) => {
+                return Err(ParseError::new_unexpected_token(
+                    "unexpected token in set literal".to_string(),
+                    token.pos().clone(),
+                ))
+            }
+            None => {
+                return Err(ParseError::new_unexpected_eof(
+                    "incomplete set literal".to_string(),
+                    start_pos.extended_to_end(),
+                ))
+            }
+        }
+    }
+
+    Ok(AstNodeSetLiteral::new(elements, pos).into())
+}
+
 /// Parses a single entry in a dict literal from a token stream
 ///
 /// # Panics
 /// - If the token stream is empty
 fn parse_dict_entry<'source>(
     token_stream: &mut VecDeque<&Token<'source>>,
-) -> Result<(AstNode<'source>, AstNode<'source>), ParseError<'source>> {
+) -> Result<DictLiteralEntry<'source>, ParseError<'source>> {
     assert!(!token_stream.is_empty());
 
+    // Parse a spread entry
+    if let Some(Token::Punctuator(token)) = token_stream.front() {
+        if token.punctuator() == "..." {
+            return Ok(DictLiteralEntry::Spread(parse_spread(token_stream)?));
+        }
+    }
+
     // Parse the key
     let key = parse_expression(token_stream)?;
 
+    parse_dict_entry_value(token_stream, key)
+}
+
+/// Parses the rest of an entry in a dict literal (the colon and the value)
+/// from a token stream, given the entry's already parsed key
+fn parse_dict_entry_value<'source>(
+    token_stream: &mut VecDeque<&Token<'source>>,
+    key: AstNode<'source>,
+) -> Result<DictLiteralEntry<'source>, ParseError<'source>> {
     // Expect a colon
     match token_stream.pop_front() {
         Some(Token::Punctuator(token)) if token.punctuator() == ":" => {}
@@ -1190,7 +2855,7 @@ fn parse_dict_entry<'source>(
     // Parse the value
     let value = parse_expression(token_stream)?;
 
-    Ok((key, value))
+    Ok(DictLiteralEntry::KeyValue(Box::new(key), Box::new(value)))
 }
 
 /// Parses a dict literal from a token stream
@@ -1226,9 +2891,36 @@ fn parse_dict_literal<'source>(
 
                 break;
             }
-            Some(_) => {
-                // Parse the next entry
-                entries.push(parse_dict_entry(token_stream)?);
+            Some(token) => {
+                // Parse the next entry. If the first entry is just a key which
+                // isn't followed by a colon, this is actually a set literal
+                let is_spread =
+                    matches!(token, Token::Punctuator(token) if token.punctuator() == "...");
+                if entries.is_empty() && !is_spread {
+                    let key = parse_expression(token_stream)?;
+                    match token_stream.front() {
+                        Some(Token::Punctuator(token)) if token.punctuator() == ":" => {
+                            entries.push(parse_dict_entry_value(token_stream, key)?);
+                        }
+                        _ => return parse_set_literal(token_stream, key, start_pos),
+                    }
+                } else {
+                    entries.push(parse_dict_entry(token_stream)?);
+                }
+
+                // A single entry followed by a `for` clause is the start of a
+                // dict comprehension instead
+                if let [DictLiteralEntry::KeyValue(..)] = &entries[..] {
+                    if starts_comprehension(token_stream) {
+                        let Some(DictLiteralEntry::KeyValue(key, value)) = entries.pop() else {
+                            unreachable!();
+                        };
+                        let clauses = parse_comprehension_clauses(token_stream)?;
+                        let pos = parse_comprehension_end(token_stream, "}", start_pos)?;
+
+                        return Ok(AstNodeDictComprehension::new(*key, *value, clauses, pos).into());
+                    }
+                }
 
                 match token_stream.front() {
                     Some(Token::Punctuator(token)) if token.punctuator() == "," => {
@@ -1289,6 +2981,96 @@ fn parse_dict_literal<'source>(
     Ok(AstNodeDictLiteral::new(entries, pos).into())
 }
 
+/// Parses a record literal usage suffix from a token stream, given the
+/// already-parsed expression naming the record type
+///
+/// # Panics
+/// - If the token stream doesn't immediately start with an opening curly brace
+fn parse_record_literal<'source>(
+    record_type: AstNode<'source>,
+    token_stream: &mut VecDeque<&Token<'source>>,
+) -> Result<AstNode<'source>, ParseError<'source>> {
+    // Expect an opening curly brace
+    let start_pos = match token_stream.pop_front() {
+        Some(Token::Punctuator(token)) if token.punctuator() == "{" => token.pos(),
+        _ => panic!("expected opening curly brace in record literal"),
+    };
+
+    let mut fields = Vec::new();
+    let end_pos = loop {
+        // Expect a field name, or the closing curly brace if there is at least
+        // one field
+        let (field_name, field_pos) = match token_stream.pop_front() {
+            Some(Token::Ident(token)) => (token.ident().to_string(), token.pos().clone()),
+            Some(Token::Punctuator(token)) if token.punctuator() == "}" && !fields.is_empty() => {
+                break token.pos();
+            }
+            Some(token) => {
+                return Err(ParseError::new_unexpected_token(
+                    "expected field name in record literal".to_string(),
+                    token.pos().clone(),
+                ));
+            }
+            None => {
+                return Err(ParseError::new_unexpected_eof(
+                    "incomplete record literal".to_string(),
+                    start_pos.extended_to_end(),
+                ));
+            }
+        };
+
+        // Expect a colon
+        match token_stream.pop_front() {
+            Some(Token::Punctuator(token)) if token.punctuator() == ":" => {}
+            Some(token) => {
+                return Err(ParseError::new_unexpected_token(
+                    "expected colon in record literal field".to_string(),
+                    token.pos().clone(),
+                ));
+            }
+            None => {
+                return Err(ParseError::new_unexpected_eof(
+                    "expected colon in record literal field".to_string(),
+                    field_pos.extended_to_end(),
+                ));
+            }
+        };
+
+        // Ensure the token stream isn't empty
+        if token_stream.is_empty() {
+            return Err(ParseError::new_unexpected_eof(
+                "incomplete record literal field".to_string(),
+                field_pos.extended_to_end(),
+            ));
+        };
+
+        // Parse the value
+        let value = parse_expression(token_stream)?;
+        fields.push((field_name, value, field_pos));
+
+        // Expect a comma or the closing curly brace
+        match token_stream.pop_front() {
+            Some(Token::Punctuator(token)) if token.punctuator() == "," => {}
+            Some(Token::Punctuator(token)) if token.punctuator() == "}" => break token.pos(),
+            Some(token) => {
+                return Err(ParseError::new_unexpected_token(
+                    "expected comma or closing curly brace in record literal".to_string(),
+                    token.pos().clone(),
+                ));
+            }
+            None => {
+                return Err(ParseError::new_unexpected_eof(
+                    "incomplete record literal".to_string(),
+                    start_pos.extended_to_end(),
+                ));
+            }
+        }
+    };
+
+    let pos = record_type.pos().extended_to(end_pos);
+    Ok(AstNodeRecordLiteral::new(record_type, fields, pos).into())
+}
+
 /// Parses a usage suffix expression from a token stream
 ///
 /// # Panics
@@ -1302,9 +3084,13 @@ fn parse_expr_usage_suffix<'source>(
     // Parse any usage suffixes
     loop {
         match token_stream.front() {
-            // Dot member access
-            Some(Token::Punctuator(token)) if token.punctuator() == "." => {
-                // Consume the "."
+            // Dot member access (or optional dot member access)
+            Some(Token::Punctuator(token))
+                if token.punctuator() == "." || token.punctuator() == "?." =>
+            {
+                let optional = token.punctuator() == "?.";
+
+                // Consume the "." or "?."
                 token_stream.pop_front();
 
                 // Expect an identifier
@@ -1326,12 +3112,17 @@ fn parse_expr_usage_suffix<'source>(
 
                 // Update the root
                 let pos = root.pos().extended_to(ident_pos);
-                root = AstNodeDotMemberAccess::new(root, ident.to_string(), pos).into();
+                root = AstNodeDotMemberAccess::new(root, ident.to_string(), optional, pos).into();
             }
 
-            // Computed (square bracket) member access
-            Some(Token::Punctuator(token)) if token.punctuator() == "[" => {
-                // Consume the "["
+            // Computed (square bracket) member access (or optional computed
+            // member access)
+            Some(Token::Punctuator(token))
+                if token.punctuator() == "[" || token.punctuator() == "?[" =>
+            {
+                let optional = token.punctuator() == "?[";
+
+                // Consume the "[" or "?["
                 token_stream.pop_front();
 
                 // Ensure the token stream isn't empty
@@ -1342,30 +3133,70 @@ fn parse_expr_usage_suffix<'source>(
                     ));
                 };
 
-                // Parse the expression inside the brackets
-                let body = parse_expression(token_stream)?;
+                // Parse the expressions inside the brackets. A single
+                // expression is a computed member access, and up to three
+                // colon-separated (and possibly omitted) expressions are a
+                // slice
+                let mut parts = Vec::new();
+                let end_pos = loop {
+                    let part = match token_stream.front() {
+                        Some(Token::Punctuator(token))
+                            if token.punctuator() == ":" || token.punctuator() == "]" =>
+                        {
+                            None
+                        }
+                        Some(_) => Some(parse_expression(token_stream)?),
+                        None => {
+                            return Err(ParseError::new_unexpected_eof(
+                                "incomplete computed member access suffix".to_string(),
+                                root.pos().extended_to_end(),
+                            ));
+                        }
+                    };
+                    parts.push(part);
 
-                // Expect a "]"
-                let end_pos = match token_stream.pop_front() {
-                    Some(Token::Punctuator(token)) if token.punctuator() == "]" => token.pos(),
-                    Some(token) => {
-                        return Err(ParseError::new_unexpected_token(
-                            "expected closing square bracket in computed member access suffix"
-                                .to_string(),
-                            token.pos().clone(),
-                        ));
-                    }
-                    None => {
-                        return Err(ParseError::new_unexpected_eof(
-                            "incomplete computed member access suffix".to_string(),
-                            root.pos().extended_to_end(),
-                        ));
+                    // Expect a ":" (if there can be another part) or a "]"
+                    match token_stream.pop_front() {
+                        Some(Token::Punctuator(token)) if token.punctuator() == "]" => {
+                            break token.pos();
+                        }
+                        Some(Token::Punctuator(token))
+                            if token.punctuator() == ":" && parts.len() < 3 => {}
+                        Some(token) => {
+                            return Err(ParseError::new_unexpected_token(
+                                "expected closing square bracket in computed member access suffix"
+                                    .to_string(),
+                                token.pos().clone(),
+                            ));
+                        }
+                        None => {
+                            return Err(ParseError::new_unexpected_eof(
+                                "incomplete computed member access suffix".to_string(),
+                                root.pos().extended_to_end(),
+                            ));
+                        }
                     }
                 };
 
                 // Update the root
                 let pos = root.pos().extended_to(end_pos);
-                root = AstNodeComputedMemberAccess::new(root, body, pos).into();
+                let mut parts = parts.into_iter();
+                root = match (parts.next().flatten(), parts.len()) {
+                    (Some(body), 0) => {
+                        AstNodeComputedMemberAccess::new(root, body, optional, pos).into()
+                    }
+                    (None, 0) => {
+                        return Err(ParseError::new_unexpected_token(
+                            "expected expression in computed member access suffix".to_string(),
+                            end_pos.clone(),
+                        ));
+                    }
+                    (start, _) => {
+                        let end = parts.next().flatten();
+                        let step = parts.next().flatten();
+                        AstNodeSlice::new(root, start, end, step, optional, pos).into()
+                    }
+                };
             }
 
             // Function call
@@ -1373,88 +3204,127 @@ fn parse_expr_usage_suffix<'source>(
                 // Consume the "("
                 token_stream.pop_front();
 
-                // Parse the function arguments
+                // Parse the function arguments. Positional arguments come
+                // first, followed by any named arguments
                 let mut arguments = Vec::new();
-                let end_pos = match token_stream.front() {
-                    // No arguments
-                    Some(Token::Punctuator(token)) if token.punctuator() == ")" => {
-                        // Consume the ")"
-                        token_stream.pop_front();
+                let mut named_arguments: Vec<(String, AstNode, SourceRange)> = Vec::new();
+                let end_pos = loop {
+                    match token_stream.front() {
+                        // The end of the arguments
+                        Some(Token::Punctuator(token)) if token.punctuator() == ")" => {
+                            // Consume the ")"
+                            token_stream.pop_front();
+
+                            break token.pos();
+                        }
 
-                        token.pos()
-                    }
+                        // A named argument
+                        Some(Token::Ident(name_token))
+                            if matches!(
+                                token_stream.get(1),
+                                Some(Token::Punctuator(token)) if token.punctuator() == ":"
+                            ) =>
+                        {
+                            // Consume the name and the ":"
+                            token_stream.pop_front();
+                            token_stream.pop_front();
+
+                            // Ensure each parameter is only named once
+                            if named_arguments
+                                .iter()
+                                .any(|(name, _, _)| name == name_token.ident())
+                            {
+                                return Err(ParseError::new_unexpected_token(
+                                    format!("duplicate named argument \"{}\"", name_token.ident()),
+                                    name_token.pos().clone(),
+                                ));
+                            }
 
-                    // One or more arguments
-                    Some(_) => {
-                        // Parse the first argument
-                        arguments.push(parse_expression(token_stream)?);
-
-                        // Parse any subsequent parameters
-                        loop {
-                            match token_stream.front() {
-                                Some(Token::Punctuator(token)) if token.punctuator() == "," => {
-                                    // Consume the ","
-                                    token_stream.pop_front();
-
-                                    // Parse the next argument
-                                    match token_stream.front() {
-                                        // If this was the optional comma after
-                                        // the last argument, we're done
-                                        Some(Token::Punctuator(closing_paren_token))
-                                            if closing_paren_token.punctuator() == ")" =>
-                                        {
-                                            // Consume the ")"
-                                            token_stream.pop_front();
-
-                                            break closing_paren_token.pos();
-                                        }
-                                        Some(_) => {
-                                            // Parse the next argument
-                                            arguments.push(parse_expression(token_stream)?);
-                                        }
-                                        None => {
-                                            return Err(ParseError::new_unexpected_eof(
-                                                "incomplete function call suffix".to_string(),
-                                                root.pos().extended_to_end(),
-                                            ));
-                                        }
-                                    };
-                                }
-                                Some(Token::Punctuator(token)) if token.punctuator() == ")" => {
-                                    // Consume the ")"
-                                    token_stream.pop_front();
+                            // Ensure the token stream isn't empty
+                            if token_stream.is_empty() {
+                                return Err(ParseError::new_unexpected_eof(
+                                    "incomplete function call suffix".to_string(),
+                                    root.pos().extended_to_end(),
+                                ));
+                            }
 
-                                    break token.pos();
-                                }
-                                Some(token) => {
-                                    return Err(ParseError::new_unexpected_token(
-                                        "unexpected token in function arguments".to_string(),
-                                        token.pos().clone(),
-                                    ));
-                                }
-                                None => {
-                                    return Err(ParseError::UnexpectedEOF {
-                                        why: "expected closing parenthesis in function call suffix"
-                                            .to_string(),
-                                        pos: root.pos().extended_to_end(),
-                                    });
-                                }
+                            // Parse the value of the argument
+                            named_arguments.push((
+                                name_token.ident().to_string(),
+                                parse_expression(token_stream)?,
+                                name_token.pos().clone(),
+                            ));
+                        }
+
+                        // A positional argument
+                        Some(token) => {
+                            if !named_arguments.is_empty() {
+                                return Err(ParseError::new_unexpected_token(
+                                    "positional arguments can't follow named arguments".to_string(),
+                                    token.pos().clone(),
+                                ));
                             }
+
+                            arguments.push(parse_spreadable_expression(token_stream)?);
+                        }
+
+                        // EOF (without a closing parenthesis)
+                        None => {
+                            return Err(ParseError::UnexpectedEOF {
+                                why: "expected closing parenthesis in function call suffix"
+                                    .to_string(),
+                                pos: root.pos().extended_to_end(),
+                            });
                         }
                     }
 
-                    // EOF (without a closing parenthesis)
-                    None => {
-                        return Err(ParseError::UnexpectedEOF {
-                            why: "expected closing parenthesis in function call suffix".to_string(),
-                            pos: root.pos().extended_to_end(),
-                        });
+                    // Expect a "," (which may also be the optional comma after
+                    // the last argument) or the end of the arguments
+                    match token_stream.front() {
+                        Some(Token::Punctuator(token)) if token.punctuator() == "," => {
+                            // Consume the ","
+                            token_stream.pop_front();
+                        }
+                        Some(Token::Punctuator(token)) if token.punctuator() == ")" => {}
+                        Some(token) => {
+                            return Err(ParseError::new_unexpected_token(
+                                "unexpected token in function arguments".to_string(),
+                                token.pos().clone(),
+                            ));
+                        }
+                        None => {
+                            return Err(ParseError::UnexpectedEOF {
+                                why: "expected closing parenthesis in function call suffix"
+                                    .to_string(),
+                                pos: root.pos().extended_to_end(),
+                            });
+                        }
                     }
                 };
 
                 // Update the root
                 let pos = root.pos().extended_to(end_pos);
-                root = AstNodeFunctionCall::new(root, arguments, pos).into();
+                root = AstNodeFunctionCall::new(root, arguments, named_arguments, pos).into();
+            }
+
+            // Record literal. Only a (possibly namespaced) name can be a
+            // record type, and the `{ field:` lookahead keeps this from being
+            // confused with a code block following an expression (such as the
+            // condition of an if statement)
+            Some(Token::Punctuator(token))
+                if token.punctuator() == "{"
+                    && matches!(
+                        root,
+                        AstNode::VariableAccess(_) | AstNode::DotMemberAccess(_)
+                    )
+                    && matches!(token_stream.get(1), Some(Token::Ident(_)))
+                    && matches!(
+                        token_stream.get(2),
+                        Some(Token::Punctuator(token)) if token.punctuator() == ":"
+                    ) =>
+            {
+                // Update the root
+                root = parse_record_literal(root, token_stream)?;
             }
 
             // Anything else is not a usage suffix
@@ -1661,6 +3531,52 @@ left_associative_bin_op!(
     }
 );
 
+/// Parses a range expression (`start..end` or `start..=end`) from a token
+/// stream
+///
+/// Ranges can't be chained, so there may be at most one range operator
+///
+/// # Panics
+/// - If the token stream is empty
+fn parse_expr_range<'source>(
+    token_stream: &mut VecDeque<&Token<'source>>,
+) -> Result<AstNode<'source>, ParseError<'source>> {
+    assert!(!token_stream.is_empty());
+
+    // Parse the start (or the whole expression, if it isn't a range)
+    let start = parse_expr_bitwise_or(token_stream)?;
+
+    let inclusive = match token_stream.front() {
+        Some(Token::Punctuator(token)) if token.punctuator() == ".." => false,
+        Some(Token::Punctuator(token)) if token.punctuator() == "..=" => true,
+        _ => return Ok(start),
+    };
+    let operator = token_stream.pop_front().unwrap();
+
+    // Parse the end
+    let end = match token_stream.front() {
+        Some(_) => parse_expr_bitwise_or(token_stream)?,
+        None => {
+            return Err(ParseError::new_unexpected_eof(
+                "expected end of range".to_string(),
+                operator.pos().extended_to_end(),
+            ));
+        }
+    };
+
+    // Ranges can't be chained
+    if let Some(Token::Punctuator(token)) = token_stream.front() {
+        if token.punctuator() == ".." || token.punctuator() == "..=" {
+            return Err(ParseError::new_unexpected_token(
+                "range operators can't be chained".to_string(),
+                token.pos().clone(),
+            ));
+        }
+    }
+
+    Ok(AstNodeRangeLiteral::new(start, end, inclusive).into())
+}
+
 /// Parses a comparison expression from a token stream
 ///
 /// # Panics
@@ -1671,7 +3587,7 @@ fn parse_expr_comparison<'source>(
     assert!(!token_stream.is_empty());
 
     // Parse the first operand
-    let first = parse_expr_bitwise_or(token_stream)?;
+    let first = parse_expr_range(token_stream)?;
 
     // Parse any additional comparisons
     let mut comparisons = Vec::new();
@@ -1692,7 +3608,7 @@ fn parse_expr_comparison<'source>(
 
                 // Parse the rhs
                 let rhs = match token_stream.front() {
-                    Some(_) => parse_expr_bitwise_or(token_stream)?,
+                    Some(_) => parse_expr_range(token_stream)?,
                     None => {
                         return Err(ParseError::UnexpectedEOF {
                             why: "expected right-hand side of comparison".to_string(),
@@ -1714,6 +3630,47 @@ fn parse_expr_comparison<'source>(
                     rhs,
                 ));
             }
+            Some(Token::Keyword(token))
+                if token.keyword() == Keyword::In || token.keyword() == Keyword::Not =>
+            {
+                let start_pos = token.pos();
+
+                // Consume the operator (`in`, or `not` followed by `in`)
+                token_stream.pop_front();
+                let kind = match token.keyword() {
+                    Keyword::In => ComparisonKind::In,
+                    _ => match token_stream.pop_front() {
+                        Some(Token::Keyword(token)) if token.keyword() == Keyword::In => {
+                            ComparisonKind::NotIn
+                        }
+                        Some(token) => {
+                            return Err(ParseError::new_unexpected_token(
+                                "expected `in` keyword after `not`".to_string(),
+                                token.pos().clone(),
+                            ));
+                        }
+                        None => {
+                            return Err(ParseError::new_unexpected_eof(
+                                "expected `in` keyword after `not`".to_string(),
+                                start_pos.extended_to_end(),
+                            ));
+                        }
+                    },
+                };
+
+                // Parse the rhs
+                let rhs = match token_stream.front() {
+                    Some(_) => parse_expr_range(token_stream)?,
+                    None => {
+                        return Err(ParseError::UnexpectedEOF {
+                            why: "expected right-hand side of comparison".to_string(),
+                            pos: start_pos.extended_to_end(),
+                        });
+                    }
+                };
+
+                comparisons.push((kind, rhs));
+            }
             _ => break,
         }
     }
@@ -1742,6 +3699,14 @@ left_associative_bin_op!(
         "||" => BinaryOperationKind::LogicalOr
     }
 );
+left_associative_bin_op!(
+    parse_expr_null_coalescing,
+    child: parse_expr_logical_or,
+    "a null-coalescing",
+    {
+        "??" => BinaryOperationKind::NullCoalescing
+    }
+);
 
 /// Parses an inline conditional (often called a
 /// ["ternary operator"](https://en.wikipedia.org/wiki/Ternary_conditional_operator))
@@ -1755,7 +3720,7 @@ fn parse_expr_inline_cond<'source>(
     assert!(!token_stream.is_empty());
 
     // Parse the condition
-    let condition = parse_expr_logical_or(token_stream)?;
+    let condition = parse_expr_null_coalescing(token_stream)?;
 
     // If this actually isn't an inline conditional, just return the "condition"
     if !matches!(
@@ -1820,6 +3785,49 @@ fn parse_expr_assignment<'source>(
 ) -> Result<AstNode<'source>, ParseError<'source>> {
     assert!(!token_stream.is_empty());
 
+    // Control flow expressions (if/else, match, and loops) have the lowest
+    // precedence, so they can't be the operand of any operator
+    match token_stream.front().unwrap() {
+        Token::Keyword(token) if token.keyword() == Keyword::If => {
+            return parse_if_else_statement(token_stream);
+        }
+        Token::Keyword(token) if token.keyword() == Keyword::Match => {
+            return parse_match_statement(token_stream);
+        }
+        Token::Keyword(token) if token.keyword() == Keyword::Loop => {
+            return parse_simple_loop(token_stream);
+        }
+        Token::Keyword(token) if token.keyword() == Keyword::While => {
+            return parse_while_loop(token_stream);
+        }
+        Token::Keyword(token) if token.keyword() == Keyword::For => {
+            return parse_for_loop(token_stream);
+        }
+        Token::Label(_) => return parse_labeled_loop(token_stream),
+        _ => {}
+    }
+
+    // Destructuring assignment
+    if starts_with_destructuring_assignment(token_stream) {
+        let pattern = parse_destructuring_pattern(token_stream, true)?;
+
+        // Consume the "="
+        let equals_pos = token_stream.pop_front().unwrap().pos();
+
+        // Ensure the token stream isn't empty
+        if token_stream.is_empty() {
+            return Err(ParseError::new_unexpected_eof(
+                "incomplete assignment expression".to_string(),
+                equals_pos.extended_to_end(),
+            ));
+        };
+
+        // Parse the right-hand side
+        let rhs = parse_expr_assignment(token_stream)?;
+
+        return Ok(AstNodeDestructuringAssignment::new(pattern, rhs).into());
+    }
+
     // Parse the left-hand side
     let lhs = parse_expr_inline_cond(token_stream)?;
 
@@ -1839,6 +3847,9 @@ fn parse_expr_assignment<'source>(
         Some(Token::Punctuator(token)) if token.punctuator() == "|=" => AssignmentKind::BitOr,
         Some(Token::Punctuator(token)) if token.punctuator() == "&&=" => AssignmentKind::LogAnd,
         Some(Token::Punctuator(token)) if token.punctuator() == "||=" => AssignmentKind::LogOr,
+        Some(Token::Punctuator(token)) if token.punctuator() == "??=" => {
+            AssignmentKind::NullCoalescing
+        }
         _ => {
             return Ok(lhs);
         }
@@ -1847,6 +3858,14 @@ fn parse_expr_assignment<'source>(
     // Consume the assignment operator
     token_stream.pop_front();
 
+    // Ensure the left-hand side can be assigned to
+    if !is_assignment_target(&lhs) {
+        return Err(ParseError::new_unexpected_token(
+            "invalid left-hand side of assignment".to_string(),
+            lhs.pos().clone(),
+        ));
+    }
+
     // Ensure the token stream isn't empty
     if token_stream.is_empty() {
         return Err(ParseError::new_unexpected_eof(
@@ -1884,16 +3903,33 @@ fn parse_statement<'source>(
     assert!(!token_stream.is_empty());
 
     match token_stream.front().unwrap() {
-        // Function declaration
-        Token::Keyword(token) if token.keyword() == Keyword::Fn => {
+        // Function declaration (as opposed to an anonymous function, which
+        // is immediately followed by its parameter list)
+        Token::Keyword(token)
+            if token.keyword() == Keyword::Fn
+                && !matches!(
+                    token_stream.get(1),
+                    Some(Token::Punctuator(token)) if token.punctuator() == "("
+                ) =>
+        {
             parse_function_declaration(token_stream)
         }
 
         // Variable declaration
-        Token::Keyword(token) if token.keyword() == Keyword::Let => {
+        Token::Keyword(token)
+            if token.keyword() == Keyword::Let || token.keyword() == Keyword::Const =>
+        {
             parse_variable_declaration(token_stream)
         }
 
+        // Import
+        Token::Keyword(token) if token.keyword() == Keyword::Import => parse_import(token_stream),
+
+        // Record declaration
+        Token::Keyword(token) if token.keyword() == Keyword::Record => {
+            parse_record_declaration(token_stream)
+        }
+
         // If-else statement
         Token::Keyword(token) if token.keyword() == Keyword::If => {
             parse_if_else_statement(token_stream)
@@ -1917,24 +3953,63 @@ fn parse_statement<'source>(
             parse_match_statement(token_stream)
         }
 
+        // Labeled loop
+        Token::Label(_) => parse_labeled_loop(token_stream),
+
         // Break statement
         Token::Keyword(token) if token.keyword() == Keyword::Break => {
-            let pos = token.pos().clone();
+            let mut pos = token.pos().clone();
 
             // Consume the "break" keyword
             token_stream.pop_front();
 
-            Ok(AstNodeJumpStatement::new(None, JumpStatementKind::Break, pos).into())
+            // Parse the label of the targeted loop, if any
+            let label = match token_stream.front() {
+                Some(Token::Label(label)) => {
+                    // Consume the label
+                    token_stream.pop_front();
+
+                    pos.extend_to(label.pos());
+                    Some(label.label().to_string())
+                }
+                _ => None,
+            };
+
+            // Parse the expression following the break keyword (the value
+            // of the loop), if any
+            let body = match token_stream.front() {
+                Some(Token::Punctuator(token))
+                    if token.punctuator() == ";" || token.punctuator() == "}" =>
+                {
+                    None
+                }
+                None => None,
+                Some(_) => Some(parse_expression(token_stream)?),
+            };
+
+            Ok(AstNodeJumpStatement::new(body, JumpStatementKind::Break, label, pos).into())
         }
 
         // Continue statement
         Token::Keyword(token) if token.keyword() == Keyword::Continue => {
-            let pos = token.pos().clone();
+            let mut pos = token.pos().clone();
 
             // Consume the "continue" keyword
             token_stream.pop_front();
 
-            Ok(AstNodeJumpStatement::new(None, JumpStatementKind::Continue, pos).into())
+            // Parse the label of the targeted loop, if any
+            let label = match token_stream.front() {
+                Some(Token::Label(label)) => {
+                    // Consume the label
+                    token_stream.pop_front();
+
+                    pos.extend_to(label.pos());
+                    Some(label.label().to_string())
+                }
+                _ => None,
+            };
+
+            Ok(AstNodeJumpStatement::new(None, JumpStatementKind::Continue, label, pos).into())
         }
 
         // Return statement
@@ -1955,7 +4030,54 @@ fn parse_statement<'source>(
                 Some(_) => Some(parse_expression(token_stream)?),
             };
 
-            Ok(AstNodeJumpStatement::new(body, JumpStatementKind::Return, pos).into())
+            Ok(AstNodeJumpStatement::new(body, JumpStatementKind::Return, None, pos).into())
+        }
+
+        // Try-catch statement
+        Token::Keyword(token) if token.keyword() == Keyword::Try => parse_try_catch(token_stream),
+
+        // Throw statement
+        Token::Keyword(token) if token.keyword() == Keyword::Throw => {
+            let start_pos = token.pos();
+
+            // Consume the "throw" keyword
+            token_stream.pop_front();
+
+            // Parse the expression following the throw keyword
+            if token_stream.is_empty() {
+                return Err(ParseError::new_unexpected_eof(
+                    "expected expression after `throw`".to_string(),
+                    start_pos.extended_to_end(),
+                ));
+            }
+            let body = parse_expression(token_stream)?;
+            let pos = start_pos.extended_to(body.pos());
+
+            Ok(AstNodeThrow::new(body, pos).into())
+        }
+
+        // Yield statement
+        Token::Keyword(token) if token.keyword() == Keyword::Yield => {
+            let mut pos = token.pos().clone();
+
+            // Consume the "yield" keyword
+            token_stream.pop_front();
+
+            // Parse the expression following the yield keyword
+            let body = match token_stream.front() {
+                Some(Token::Punctuator(token))
+                    if token.punctuator() == ";" || token.punctuator() == "}" =>
+                {
+                    None
+                }
+                None => None,
+                Some(_) => Some(parse_expression(token_stream)?),
+            };
+            if let Some(body) = &body {
+                pos.extend_to(body.pos());
+            }
+
+            Ok(AstNodeYield::new(body, pos).into())
         }
 
         // Otherwise, assume it's an expression
@@ -2048,6 +4170,396 @@ fn parse_code_block<'source>(
     Ok((statements, pos))
 }
 
+/// Checks that every labeled `break` and `continue` in an AstNode (and its
+/// children) targets an enclosing loop with that label, and that every `yield`
+/// is a statement of a function body. `labels` holds the labels of the loops
+/// enclosing the node, from outermost to innermost
+///
+/// The node is treated as an expression, so a `yield` inside of it (outside of
+/// any nested functions) is an error
+fn check_loop_labels<'a, 'source>(
+    node: &'a AstNode<'source>,
+    labels: &mut Vec<&'a str>,
+) -> Result<(), ParseError<'source>> {
+    /// Checks the default values of a function's parameters, which (like the
+    /// function's body) can't jump to loops outside of the function
+    fn check_function_parameters<'source>(
+        parameters: &FunctionParameters<'source>,
+    ) -> Result<(), ParseError<'source>> {
+        match parameters {
+            FunctionParameters::Variadic { parameter_name: _ } => Ok(()),
+            FunctionParameters::Polyadic {
+                parameters,
+                rest_parameter: _,
+            } => parameters
+                .iter()
+                .filter_map(|(_, default_value, _)| default_value.as_ref())
+                .try_for_each(|default_value| check_loop_labels(default_value, &mut Vec::new())),
+        }
+    }
+
+    match node {
+        AstNode::JumpStatement(node) => {
+            if let Some(label) = node.label() {
+                if !labels.contains(&label) {
+                    return Err(ParseError::new_unexpected_token(
+                        format!("unknown loop label '{label}"),
+                        node.pos().clone(),
+                    ));
+                }
+            }
+            match node.body() {
+                Some(body) => check_loop_labels(body, labels),
+                None => Ok(()),
+            }
+        }
+
+        // Control flow statements that are used as expressions can't yield
+        AstNode::SimpleLoop(_)
+        | AstNode::WhileLoop(_)
+        | AstNode::ForLoop(_)
+        | AstNode::MatchStatement(_)
+        | AstNode::IfElseStatement(_)
+        | AstNode::TryCatch(_) => check_statement(node, labels, false),
+        AstNode::Yield(node) => Err(ParseError::new_unexpected_token(
+            "`yield` can only be used as a statement in the body of a function".to_string(),
+            node.pos().clone(),
+        )),
+
+        // Function bodies can't jump to loops outside of the function
+        AstNode::FunctionDeclaration(node) => {
+            check_function_parameters(node.parameters())?;
+            check_block(node.body(), &mut Vec::new(), true)
+        }
+        AstNode::AnonymousFunction(node) => {
+            check_function_parameters(node.parameters())?;
+            check_block(node.body(), &mut Vec::new(), true)
+        }
+
+        // Everything else just needs its children checked
+        AstNode::VariableDeclaration(node) => {
+            for (pattern, value, _) in node.declarations() {
+                check_destructuring_pattern(pattern, labels)?;
+                if let Some(value) = value {
+                    check_loop_labels(value, labels)?;
+                }
+            }
+            Ok(())
+        }
+        AstNode::Import(_)
+        | AstNode::RecordDeclaration(_)
+        | AstNode::VariableAccess(_)
+        | AstNode::Literal(_) => Ok(()),
+        AstNode::ListLiteral(node) => node
+            .elements()
+            .iter()
+            .try_for_each(|element| check_loop_labels(element, labels)),
+        AstNode::ListComprehension(node) => {
+            check_comprehension_clauses(node.clauses(), labels)?;
+            check_loop_labels(node.element(), labels)
+        }
+        AstNode::FormattedStringLiteral(node) => {
+            check_loop_labels(node.start().1, labels)?;
+            for (_, replacement_field, _) in node.continuations() {
+                check_loop_labels(replacement_field, labels)?;
+            }
+            Ok(())
+        }
+        AstNode::DictLiteral(node) => {
+            for entry in node.entries() {
+                match entry {
+                    DictLiteralEntry::KeyValue(key, value) => {
+                        check_loop_labels(key, labels)?;
+                        check_loop_labels(value, labels)?;
+                    }
+                    DictLiteralEntry::Spread(spread) => check_loop_labels(spread.body(), labels)?,
+                }
+            }
+            Ok(())
+        }
+        AstNode::DictComprehension(node) => {
+            check_comprehension_clauses(node.clauses(), labels)?;
+            check_loop_labels(node.key(), labels)?;
+            check_loop_labels(node.value(), labels)
+        }
+        AstNode::SetLiteral(node) => node
+            .elements()
+            .iter()
+            .try_for_each(|element| check_loop_labels(element, labels)),
+        AstNode::Spread(node) => check_loop_labels(node.body(), labels),
+        AstNode::RangeLiteral(node) => {
+            check_loop_labels(node.start(), labels)?;
+            check_loop_labels(node.end(), labels)
+        }
+        AstNode::RecordLiteral(node) => {
+            check_loop_labels(node.record_type(), labels)?;
+            for (_, value, _) in node.fields() {
+                check_loop_labels(value, labels)?;
+            }
+            Ok(())
+        }
+        AstNode::TypeCast(node) => check_loop_labels(node.body(), labels),
+        AstNode::DotMemberAccess(node) => check_loop_labels(node.root(), labels),
+        AstNode::ComputedMemberAccess(node) => {
+            check_loop_labels(node.root(), labels)?;
+            check_loop_labels(node.member_node(), labels)
+        }
+        AstNode::Slice(node) => {
+            check_loop_labels(node.root(), labels)?;
+            for bound in [node.start(), node.end(), node.step()]
+                .into_iter()
+                .flatten()
+            {
+                check_loop_labels(bound, labels)?;
+            }
+            Ok(())
+        }
+        AstNode::FunctionCall(node) => {
+            check_loop_labels(node.root(), labels)?;
+            for argument in node.arguments() {
+                check_loop_labels(argument, labels)?;
+            }
+            for (_, value, _) in node.named_arguments() {
+                check_loop_labels(value, labels)?;
+            }
+            Ok(())
+        }
+        AstNode::BinaryOperation(node) => {
+            check_loop_labels(node.lhs(), labels)?;
+            check_loop_labels(node.rhs(), labels)
+        }
+        AstNode::UnaryOperation(node) => check_loop_labels(node.operand(), labels),
+        AstNode::Comparison(node) => {
+            check_loop_labels(node.first(), labels)?;
+            for (_, operand) in node.comparisons() {
+                check_loop_labels(operand, labels)?;
+            }
+            Ok(())
+        }
+        AstNode::InlineConditional(node) => {
+            check_loop_labels(node.condition(), labels)?;
+            check_loop_labels(node.truthy_case(), labels)?;
+            check_loop_labels(node.falsey_case(), labels)
+        }
+        AstNode::Assignment(node) => {
+            check_loop_labels(node.lhs(), labels)?;
+            check_loop_labels(node.rhs(), labels)
+        }
+        AstNode::DestructuringAssignment(node) => {
+            check_destructuring_pattern(node.pattern(), labels)?;
+            check_loop_labels(node.rhs(), labels)
+        }
+        AstNode::Throw(node) => check_loop_labels(node.body(), labels),
+    }
+}
+
+/// Checks a statement in a code block in the same way as `check_loop_labels`,
+/// except that the statement may be a `yield` if `can_yield` is true (i.e. if
+/// the code block is part of a function body, and not part of an expression)
+fn check_statement<'a, 'source>(
+    node: &'a AstNode<'source>,
+    labels: &mut Vec<&'a str>,
+    can_yield: bool,
+) -> Result<(), ParseError<'source>> {
+    /// Checks the body of a loop, with the loop's label (if any) in scope
+    fn check_loop_body<'a, 'source>(
+        body: &'a [AstNode<'source>],
+        label: Option<&'a str>,
+        labels: &mut Vec<&'a str>,
+        can_yield: bool,
+    ) -> Result<(), ParseError<'source>> {
+        labels.extend(label);
+        let result = check_block(body, labels, can_yield);
+        if label.is_some() {
+            labels.pop();
+        }
+
+        result
+    }
+
+    match node {
+        AstNode::Yield(node) if can_yield => match node.body() {
+            Some(body) => check_loop_labels(body, labels),
+            None => Ok(()),
+        },
+
+        // Loops
+        AstNode::SimpleLoop(node) => {
+            if let Some(condition) = node.condition() {
+                check_loop_labels(condition, labels)?;
+            }
+            check_loop_body(node.body(), node.label(), labels, can_yield)
+        }
+        AstNode::WhileLoop(node) => {
+            check_loop_labels(node.condition(), labels)?;
+            check_loop_body(node.body(), node.label(), labels, can_yield)
+        }
+        AstNode::ForLoop(node) => {
+            check_destructuring_pattern(node.pattern(), labels)?;
+            check_loop_labels(node.iterable(), labels)?;
+            check_loop_body(node.body(), node.label(), labels, can_yield)
+        }
+
+        // Other control flow statements
+        AstNode::MatchStatement(node) => {
+            check_loop_labels(node.matched_expression(), labels)?;
+            for arm in node.arms() {
+                check_match_pattern(arm.pattern(), labels)?;
+                if let Some(guard) = arm.guard() {
+                    check_loop_labels(guard, labels)?;
+                }
+                check_block(arm.body(), labels, can_yield)?;
+            }
+            Ok(())
+        }
+        AstNode::IfElseStatement(node) => {
+            for (condition, body) in node.conditional_branches() {
+                check_loop_labels(condition, labels)?;
+                check_block(body, labels, can_yield)?;
+            }
+            match node.else_branch() {
+                Some(body) => check_block(body, labels, can_yield),
+                None => Ok(()),
+            }
+        }
+        AstNode::TryCatch(node) => {
+            check_block(node.try_body(), labels, can_yield)?;
+            check_block(node.catch_body(), labels, can_yield)
+        }
+
+        // Everything else is checked as an expression
+        _ => check_loop_labels(node, labels),
+    }
+}
+
+/// Checks every statement in a code block with `check_statement`
+fn check_block<'a, 'source>(
+    body: &'a [AstNode<'source>],
+    labels: &mut Vec<&'a str>,
+    can_yield: bool,
+) -> Result<(), ParseError<'source>> {
+    body.iter()
+        .try_for_each(|statement| check_statement(statement, labels, can_yield))
+}
+
+/// Checks every pattern and expression in the clauses of a comprehension
+fn check_comprehension_clauses<'a, 'source>(
+    clauses: &'a [ComprehensionClause<'source>],
+    labels: &mut Vec<&'a str>,
+) -> Result<(), ParseError<'source>> {
+    for clause in clauses {
+        match clause {
+            ComprehensionClause::For {
+                pattern,
+                multiple_variables: _,
+                iterable,
+                pos: _,
+            } => {
+                check_destructuring_pattern(pattern, labels)?;
+                check_loop_labels(iterable, labels)?;
+            }
+            ComprehensionClause::If { condition, pos: _ } => {
+                check_loop_labels(condition, labels)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks every expression in a destructuring pattern
+fn check_destructuring_pattern<'a, 'source>(
+    pattern: &'a DestructuringPattern<'source>,
+    labels: &mut Vec<&'a str>,
+) -> Result<(), ParseError<'source>> {
+    match pattern {
+        DestructuringPattern::Target(target) => check_loop_labels(target, labels),
+        DestructuringPattern::List {
+            elements,
+            rest,
+            pos: _,
+        } => {
+            for element in elements {
+                check_destructuring_pattern(element, labels)?;
+            }
+            match rest {
+                Some((_, rest)) => check_destructuring_pattern(rest, labels),
+                None => Ok(()),
+            }
+        }
+        DestructuringPattern::Dict {
+            entries,
+            rest,
+            pos: _,
+        } => {
+            for (key, value) in entries {
+                check_loop_labels(key, labels)?;
+                check_destructuring_pattern(value, labels)?;
+            }
+            match rest {
+                Some(rest) => check_destructuring_pattern(rest, labels),
+                None => Ok(()),
+            }
+        }
+    }
+}
+
+/// Checks every expression in a match pattern
+fn check_match_pattern<'a, 'source>(
+    pattern: &'a MatchPattern<'source>,
+    labels: &mut Vec<&'a str>,
+) -> Result<(), ParseError<'source>> {
+    match pattern {
+        MatchPattern::Wildcard { pos: _ } | MatchPattern::Binding { ident: _, pos: _ } => Ok(()),
+        MatchPattern::Value(value) => check_loop_labels(value, labels),
+        MatchPattern::Range(range) => {
+            check_loop_labels(range.start(), labels)?;
+            check_loop_labels(range.end(), labels)
+        }
+        MatchPattern::Type {
+            icelang_type: _,
+            inner,
+            pos: _,
+        } => match inner {
+            Some(inner) => check_match_pattern(inner, labels),
+            None => Ok(()),
+        },
+        MatchPattern::List {
+            elements,
+            rest,
+            pos: _,
+        } => {
+            for element in elements {
+                check_match_pattern(element, labels)?;
+            }
+            match rest {
+                Some((_, rest)) => check_match_pattern(rest, labels),
+                None => Ok(()),
+            }
+        }
+        MatchPattern::Dict {
+            entries,
+            rest,
+            pos: _,
+        } => {
+            for (key, value) in entries {
+                check_loop_labels(key, labels)?;
+                check_match_pattern(value, labels)?;
+            }
+            match rest {
+                Some(rest) => check_match_pattern(rest, labels),
+                None => Ok(()),
+            }
+        }
+        MatchPattern::Alternatives {
+            alternatives,
+            pos: _,
+        } => alternatives
+            .iter()
+            .try_for_each(|alternative| check_match_pattern(alternative, labels)),
+    }
+}
+
 /// Reads a list of tokens and produces an abstract syntax tree
 pub fn parse<'token, 'source: 'token>(
     tokens: impl Into<VecDeque<&'token Token<'source>>>,
@@ -2088,6 +4600,10 @@ pub fn parse<'token, 'source: 'token>(
     // Ensure there are no remaining tokens
     assert!(token_stream.is_empty());
 
+    // Ensure every labeled jump statement targets an enclosing loop, and that
+    // `yield` is only used in functions
+    check_block(&statements, &mut Vec::new(), false)?;
+
     // Return the AST
     Ok(Ast { statements })
 }
@@ -2111,5 +4627,15 @@ mod tests {
         assert_eq!(ast, Ast { statements: vec![] });
     }
 
+    #[test]
+    fn parse_unreachable_match_arm() {
+        let tokens = crate::lexer::tokenize("match 1 { n => {}, 0 => {}, };", "test.ice").unwrap();
+
+        let err = parse(tokens.iter().collect::<VecDeque<_>>()).unwrap_err();
+
+        assert!(matches!(err, ParseError::UnreachableMatchArm { .. }));
+        assert!(err.pos().read().starts_with("0 =>"));
+    }
+
     // TODO much more extensive unit testing
 }
@@ -0,0 +1,267 @@
+//! Contains code related to icelang ranges (lazy sequences of evenly spaced
+//! ints)
+
+use std::{fmt::Display, hash::Hash};
+
+use num_bigint::BigInt;
+use num_traits::{One, Signed, Zero};
+
+/// A lazy sequence of evenly spaced ints, which starts at some start value and
+/// steps towards some end value, which may or may not be included
+///
+/// The elements of a range are computed on demand, so a range takes up the
+/// same amount of memory no matter how many elements it has
+#[derive(Debug, Clone)]
+pub struct Range {
+    start: BigInt,
+    end: BigInt,
+    step: BigInt,
+    inclusive: bool,
+}
+
+impl Range {
+    /// Constructs a new Range from `start` (inclusive) to `end` (exclusive),
+    /// stepping by `step`
+    ///
+    /// If the step doesn't move from `start` towards `end`, the range is empty
+    pub fn new(start: BigInt, end: BigInt, step: BigInt) -> Self {
+        Self {
+            start,
+            end,
+            step,
+            inclusive: false,
+        }
+    }
+
+    /// Constructs a new Range from `start` to `end` (both inclusive), stepping
+    /// by 1
+    ///
+    /// If `start` is greater than `end`, the range is empty
+    pub fn new_inclusive(start: BigInt, end: BigInt) -> Self {
+        Self {
+            start,
+            end,
+            step: BigInt::one(),
+            inclusive: true,
+        }
+    }
+
+    /// Returns the start value of the range
+    pub fn start(&self) -> &BigInt {
+        &self.start
+    }
+
+    /// Returns the end value of the range
+    pub fn end(&self) -> &BigInt {
+        &self.end
+    }
+
+    /// Returns the step value of the range
+    pub fn step(&self) -> &BigInt {
+        &self.step
+    }
+
+    /// Returns whether or not the end value is included in the range
+    pub fn is_inclusive(&self) -> bool {
+        self.inclusive
+    }
+
+    /// Returns the number of elements in the range
+    pub fn len(&self) -> BigInt {
+        if self.step.is_zero() {
+            return BigInt::zero();
+        }
+
+        let distance = &self.end - &self.start;
+        if self.inclusive {
+            if distance.is_zero() || distance.sign() == self.step.sign() {
+                distance / &self.step + 1
+            } else {
+                BigInt::zero()
+            }
+        } else if !distance.is_zero() && distance.sign() == self.step.sign() {
+            // Rounds the (always positive) quotient up
+            (distance + &self.step - self.step.signum()) / &self.step
+        } else {
+            BigInt::zero()
+        }
+    }
+
+    /// Returns whether or not the range has no elements
+    pub fn is_empty(&self) -> bool {
+        self.len().is_zero()
+    }
+
+    /// Returns the element at the given index, or None if the index is out of
+    /// bounds
+    pub fn get(&self, index: &BigInt) -> Option<BigInt> {
+        if index.is_negative() || *index >= self.len() {
+            return None;
+        }
+
+        Some(&self.start + index * &self.step)
+    }
+
+    /// Returns whether or not the given int is one of the elements of the
+    /// range
+    pub fn contains(&self, value: &BigInt) -> bool {
+        if self.step.is_zero() {
+            return false;
+        }
+
+        let offset = value - &self.start;
+        if !(&offset % &self.step).is_zero() {
+            return false;
+        }
+
+        self.get(&(offset / &self.step)).is_some()
+    }
+
+    /// Returns an iterator over the elements of the range
+    pub fn iter(&self) -> RangeIter {
+        RangeIter {
+            next: self.start.clone(),
+            remaining: self.len(),
+            step: self.step.clone(),
+        }
+    }
+}
+
+impl Display for Range {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.inclusive, self.step.is_one()) {
+            (true, _) => write!(f, "{}..={}", self.start, self.end),
+            (false, true) => write!(f, "{}..{}", self.start, self.end),
+            (false, false) => write!(f, "range({}, {}, {})", self.start, self.end, self.step),
+        }
+    }
+}
+
+/// Two ranges are equal if they contain the same elements in the same order
+impl PartialEq for Range {
+    fn eq(&self, other: &Self) -> bool {
+        let len = self.len();
+
+        len == other.len()
+            && (len.is_zero() || self.start == other.start)
+            && (len <= BigInt::one() || self.step == other.step)
+    }
+}
+impl Eq for Range {}
+
+impl Hash for Range {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        // Only hash what's compared by PartialEq
+        let len = self.len();
+        len.hash(state);
+        if !len.is_zero() {
+            self.start.hash(state);
+        }
+        if len > BigInt::one() {
+            self.step.hash(state);
+        }
+    }
+}
+
+/// An iterator over the elements of a Range
+#[derive(Debug, Clone)]
+pub struct RangeIter {
+    next: BigInt,
+    remaining: BigInt,
+    step: BigInt,
+}
+
+impl Iterator for RangeIter {
+    type Item = BigInt;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining.is_zero() {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let next = &self.next + &self.step;
+        Some(std::mem::replace(&mut self.next, next))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn elements(range: &Range) -> Vec<i64> {
+        range.iter().map(|n| i64::try_from(n).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_range_elements() {
+        let cases = [
+            (
+                Range::new(0.into(), 5.into(), 1.into()),
+                vec![0, 1, 2, 3, 4],
+            ),
+            (Range::new(0.into(), 5.into(), 2.into()), vec![0, 2, 4]),
+            (Range::new(0.into(), 6.into(), 2.into()), vec![0, 2, 4]),
+            (Range::new(5.into(), 0.into(), (-2).into()), vec![5, 3, 1]),
+            (Range::new(5.into(), 0.into(), 1.into()), vec![]),
+            (Range::new(3.into(), 3.into(), 0.into()), vec![]),
+            (Range::new_inclusive(1.into(), 4.into()), vec![1, 2, 3, 4]),
+            (Range::new_inclusive(4.into(), 4.into()), vec![4]),
+            (Range::new_inclusive(5.into(), 4.into()), vec![]),
+        ];
+
+        for (range, expected) in cases {
+            assert_eq!(elements(&range), expected, "{range}");
+            assert_eq!(range.len(), BigInt::from(expected.len()), "{range}");
+            for (index, element) in expected.iter().enumerate() {
+                assert_eq!(range.get(&index.into()), Some((*element).into()));
+                assert!(range.contains(&(*element).into()));
+            }
+            assert_eq!(range.get(&expected.len().into()), None);
+            assert_eq!(range.get(&(-1).into()), None);
+        }
+    }
+
+    #[test]
+    fn test_range_contains() {
+        let range = Range::new(1.into(), 10.into(), 3.into());
+
+        assert!(range.contains(&7.into()));
+        assert!(!range.contains(&8.into()));
+        assert!(!range.contains(&10.into()));
+        assert!(!range.contains(&(-2).into()));
+    }
+
+    #[test]
+    fn test_range_eq() {
+        assert_eq!(
+            Range::new(0.into(), 5.into(), 1.into()),
+            Range::new_inclusive(0.into(), 4.into())
+        );
+        assert_eq!(
+            Range::new(0.into(), 5.into(), 2.into()),
+            Range::new(0.into(), 6.into(), 2.into())
+        );
+        assert_eq!(
+            Range::new(3.into(), 0.into(), 1.into()),
+            Range::new_inclusive(9.into(), 2.into())
+        );
+        assert_ne!(
+            Range::new(0.into(), 5.into(), 1.into()),
+            Range::new(0.into(), 5.into(), 2.into())
+        );
+    }
+
+    #[test]
+    fn test_range_display() {
+        assert_eq!(Range::new(0.into(), 5.into(), 1.into()).to_string(), "0..5");
+        assert_eq!(
+            Range::new_inclusive((-1).into(), 5.into()).to_string(),
+            "-1..=5"
+        );
+        assert_eq!(
+            Range::new(10.into(), 0.into(), (-3).into()).to_string(),
+            "range(10, 0, -3)"
+        );
+    }
+}
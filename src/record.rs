@@ -0,0 +1,113 @@
+//! Contains code related to user-defined icelang record types
+
+use std::{hash::Hash, rc::Rc};
+
+use crate::value::Value;
+
+/// A user-defined icelang record type, which has a fixed set of named fields
+#[derive(Debug)]
+pub struct RecordType {
+    name: String,
+    field_names: Vec<String>,
+}
+
+impl RecordType {
+    /// Constructs a new RecordType with the given name and field names
+    pub fn new(name: String, field_names: Vec<String>) -> Self {
+        Self { name, field_names }
+    }
+
+    /// Gets the name of the record type
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the names of the fields of the record type, in declaration order
+    pub fn field_names(&self) -> &[String] {
+        &self.field_names
+    }
+
+    /// Gets the index of the field with the given name, or None if the record
+    /// type has no such field
+    pub fn field_index(&self, field_name: &str) -> Option<usize> {
+        self.field_names.iter().position(|name| name == field_name)
+    }
+}
+
+/// An instance of a user-defined icelang record type
+#[derive(Debug)]
+pub struct Record<'source> {
+    record_type: Rc<RecordType>,
+    fields: Vec<Value<'source>>,
+}
+
+impl<'source> Record<'source> {
+    /// Constructs a new Record of the given record type, with the given field
+    /// values (in the same order as the record type's field names)
+    ///
+    /// # Panics
+    /// - If the number of field values doesn't match the number of fields in
+    ///   the record type
+    pub fn new(record_type: Rc<RecordType>, fields: Vec<Value<'source>>) -> Self {
+        assert_eq!(record_type.field_names().len(), fields.len());
+
+        Self {
+            record_type,
+            fields,
+        }
+    }
+
+    /// Gets the type of the record
+    pub fn record_type(&self) -> &Rc<RecordType> {
+        &self.record_type
+    }
+
+    /// Gets the value of the field with the given name, or None if the record
+    /// has no such field
+    pub fn get(&self, field_name: &str) -> Option<&Value<'source>> {
+        self.record_type
+            .field_index(field_name)
+            .map(|index| &self.fields[index])
+    }
+
+    /// Gets a mutable reference to the value of the field with the given name,
+    /// or None if the record has no such field
+    pub fn get_mut(&mut self, field_name: &str) -> Option<&mut Value<'source>> {
+        self.record_type
+            .field_index(field_name)
+            .map(|index| &mut self.fields[index])
+    }
+
+    /// Returns an iterator over the (name, value) pairs of the record's fields,
+    /// in declaration order
+    pub fn fields(&self) -> impl Iterator<Item = (&str, &Value<'source>)> {
+        self.record_type
+            .field_names()
+            .iter()
+            .map(String::as_str)
+            .zip(self.fields.iter())
+    }
+
+    /// Constructs a new Record of the same type, with each field value mapped
+    /// through the given function
+    pub fn map_fields(&self, f: impl Fn(&Value<'source>) -> Value<'source>) -> Self {
+        Self {
+            record_type: self.record_type.clone(),
+            fields: self.fields.iter().map(f).collect(),
+        }
+    }
+}
+
+impl PartialEq for Record<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.record_type, &other.record_type) && self.fields == other.fields
+    }
+}
+impl Eq for Record<'_> {}
+
+impl Hash for Record<'_> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        Rc::as_ptr(&self.record_type).hash(state);
+        self.fields.hash(state);
+    }
+}
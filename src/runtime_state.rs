@@ -1,14 +1,21 @@
 //! Contains code related to `RuntimeState`s, which represent the entire state
 //! of an icelang program during execution.
 
-use std::fmt::{Debug, Display};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fmt::{Debug, Display},
+    path::{Path, PathBuf},
+    rc::Rc,
+};
 
 use rand::RngCore;
 
 use crate::{
     ast::AstNode,
-    call_stack::CallStack,
-    function::{FunctionGroup, FunctionParameters},
+    call_stack::{CallStack, CallStackDepth, Environment, Scope, StackFrame},
+    function::{FunctionGroup, FunctionParameters, FunctionValue},
+    generator::ResumePoint,
     icelang_std_lib::{IcelangFmt, IcelangFmtArgs},
     source_range::SourceRange,
     value::Value,
@@ -32,9 +39,35 @@ impl Clone for Box<dyn CloneableRng> {
 
 /// Represents the entire state of an icelang program during execution
 pub struct RuntimeState<'source> {
-    most_recent_value: Value,
+    most_recent_value: Value<'source>,
     call_stack: CallStack<'source>,
     rng: Box<dyn CloneableRng>,
+    /// Every module which has been imported, keyed by path. A module which is
+    /// still being run has no namespace yet
+    modules: HashMap<PathBuf, Option<Value<'source>>>,
+    /// The state of each importer suspended while one of its imports runs
+    suspended_importers: Vec<SuspendedImporter<'source>>,
+    /// Where the generator currently being resumed left off, ordered from the
+    /// innermost construct to the outermost one (see `ResumePoint`)
+    resume_points: Vec<ResumePoint<'source>>,
+}
+
+/// The state of some importing code, saved while the module it imported runs
+#[derive(Debug)]
+struct SuspendedImporter<'source> {
+    module_path: PathBuf,
+    most_recent_value: Value<'source>,
+    call_stack: CallStack<'source>,
+}
+
+impl Clone for SuspendedImporter<'_> {
+    fn clone(&self) -> Self {
+        Self {
+            module_path: self.module_path.clone(),
+            most_recent_value: self.most_recent_value.deep_copy(),
+            call_stack: self.call_stack.clone(),
+        }
+    }
 }
 
 impl<'source> RuntimeState<'source> {
@@ -44,6 +77,9 @@ impl<'source> RuntimeState<'source> {
             most_recent_value: Value::Null,
             call_stack: CallStack::new("<global>".to_string()),
             rng: Box::new(rand::thread_rng()),
+            modules: HashMap::new(),
+            suspended_importers: Vec::new(),
+            resume_points: Vec::new(),
         }
     }
 
@@ -57,9 +93,10 @@ impl<'source> RuntimeState<'source> {
         self.call_stack.scope_display_name()
     }
 
-    /// Pushes a new stack frame to the call stack
-    pub fn push_stack_frame(&mut self, display_name: String) {
-        self.call_stack.push_stack_frame(display_name);
+    /// Pushes a new stack frame to the call stack. Variables and functions in
+    /// the given environment will be visible from within the new stack frame
+    pub fn push_stack_frame(&mut self, display_name: String, environment: Environment<'source>) {
+        self.call_stack.push_stack_frame(display_name, environment);
     }
 
     /// Pops a stack frame from the call stack
@@ -70,11 +107,36 @@ impl<'source> RuntimeState<'source> {
         self.call_stack.pop_stack_frame();
     }
 
+    /// Pushes an existing stack frame (such as one returned by
+    /// `take_stack_frame`) to the call stack
+    pub fn push_existing_stack_frame(&mut self, frame: StackFrame<'source>) {
+        self.call_stack.push_existing_stack_frame(frame);
+    }
+
+    /// Pops a stack frame from the call stack, and returns it
+    ///
+    /// # Panics
+    /// - If the call stack is empty
+    pub fn take_stack_frame(&mut self) -> StackFrame<'source> {
+        self.call_stack.take_stack_frame()
+    }
+
     /// Pushes a new scope to the stack frame
     pub fn push_scope(&mut self) {
         self.call_stack.push_scope();
     }
 
+    /// Pushes an existing scope (such as one returned by `innermost_scope`) to
+    /// the stack frame
+    pub fn push_existing_scope(&mut self, scope: Scope<'source>) {
+        self.call_stack.push_existing_scope(scope);
+    }
+
+    /// Returns the innermost scope of the current stack frame
+    pub fn innermost_scope(&self) -> Scope<'source> {
+        self.call_stack.innermost_scope().clone()
+    }
+
     /// Pops a scope from the stack frame
     ///
     /// # Panics
@@ -83,13 +145,51 @@ impl<'source> RuntimeState<'source> {
         self.call_stack.pop_scope();
     }
 
+    /// Returns the current depth of the call stack
+    pub fn call_stack_depth(&self) -> CallStackDepth {
+        self.call_stack.depth()
+    }
+
+    /// Unwinds the call stack to a previous depth, popping every stack frame
+    /// and scope pushed since then
+    ///
+    /// # Panics
+    /// - If the call stack is already shallower than the given depth
+    pub fn unwind_call_stack(&mut self, depth: CallStackDepth) {
+        self.call_stack.unwind_to(depth);
+    }
+
+    /// Captures the environment of the current scope, for use by a function
+    /// declared in the current scope
+    pub fn capture_environment(&self) -> Environment<'source> {
+        self.call_stack.capture_environment()
+    }
+
+    /// Starts resuming a generator, which left off at the given resume points
+    /// (ordered from the innermost construct to the outermost one)
+    ///
+    /// # Panics
+    /// - If another generator is already being resumed
+    pub(crate) fn start_resuming(&mut self, resume_points: Vec<ResumePoint<'source>>) {
+        assert!(self.resume_points.is_empty());
+
+        self.resume_points = resume_points;
+    }
+
+    /// Takes the resume point of the outermost construct which hasn't resumed
+    /// yet, or returns None if no generator is being resumed (or it has
+    /// already fully resumed)
+    pub(crate) fn take_resume_point(&mut self) -> Option<ResumePoint<'source>> {
+        self.resume_points.pop()
+    }
+
     /// Returns the most recent value from an expression
-    pub fn most_recent_value(&self) -> &Value {
+    pub fn most_recent_value(&self) -> &Value<'source> {
         &self.most_recent_value
     }
 
     /// Updates the most recent value from an expression
-    pub fn update_most_recent_value(&mut self, value: Value) {
+    pub fn update_most_recent_value(&mut self, value: Value<'source>) {
         self.most_recent_value = value;
     }
 
@@ -97,16 +197,33 @@ impl<'source> RuntimeState<'source> {
     ///
     /// # Panics
     /// - If the variable is already defined
-    pub fn declare_variable(&mut self, identifier: String, value: Value) {
+    pub fn declare_variable(&mut self, identifier: String, value: Value<'source>) {
         assert!(self.call_stack.lookup_local_variable(&identifier).is_none());
 
         self.call_stack.declare_variable(identifier, value);
     }
 
+    /// Declares a new constant (a variable which can't be reassigned) and
+    /// assigns the given value
+    ///
+    /// # Panics
+    /// - If the variable is already defined
+    pub fn declare_constant(&mut self, identifier: String, value: Value<'source>) {
+        assert!(self.call_stack.lookup_local_variable(&identifier).is_none());
+
+        self.call_stack.declare_constant(identifier, value);
+    }
+
+    /// Returns whether or not the variable with the given identifier refers
+    /// to a constant
+    pub fn is_constant(&self, identifier: &str) -> bool {
+        self.call_stack.is_constant(identifier)
+    }
+
     /// Declares a function (or overloads a function)
     ///
     /// # Panics
-    /// - If the function is already defined (including arity)
+    /// - If the function is already defined with a conflicting overload
     pub fn declare_function(
         &mut self,
         identifier: String,
@@ -119,29 +236,31 @@ impl<'source> RuntimeState<'source> {
     }
 
     /// Looks up a variable in the runtime state
-    pub fn lookup_variable(&self, identifier: &str) -> Option<&Value> {
+    pub fn lookup_variable(&self, identifier: &str) -> Option<Value<'source>> {
         self.call_stack.lookup_variable(identifier)
     }
 
-    /// Looks up a variable (mutably) in the runtime state
-    pub fn lookup_variable_mut(&mut self, identifier: &str) -> Option<&mut Value> {
-        self.call_stack.lookup_variable_mut(identifier)
+    /// Looks up a variable in the runtime state, unless a function with the
+    /// same identifier is declared in a more local scope (in which case the
+    /// function shadows the variable)
+    pub fn lookup_unshadowed_variable(&self, identifier: &str) -> Option<Value<'source>> {
+        self.call_stack.lookup_unshadowed_variable(identifier)
     }
 
     /// Looks up a variable in the runtime state, only checking the most local
     /// scope
-    pub fn lookup_local_variable(&self, identifier: &str) -> Option<&Value> {
+    pub fn lookup_local_variable(&self, identifier: &str) -> Option<Value<'source>> {
         self.call_stack.lookup_local_variable(identifier)
     }
 
     /// Looks up a function in the runtime state
-    pub fn lookup_function(&self, identifier: &str) -> Option<&FunctionGroup<'source>> {
+    pub fn lookup_function(&self, identifier: &str) -> Option<Rc<FunctionGroup<'source>>> {
         self.call_stack.lookup_function(identifier)
     }
 
     /// Looks up a function in the runtime state, only checking the most local
     /// scope
-    pub fn lookup_local_function(&self, identifier: &str) -> Option<&FunctionGroup<'source>> {
+    pub fn lookup_local_function(&self, identifier: &str) -> Option<Rc<FunctionGroup<'source>>> {
         self.call_stack.lookup_local_function(identifier)
     }
 
@@ -149,11 +268,116 @@ impl<'source> RuntimeState<'source> {
     ///
     /// # Panics
     /// - If the variable isn't already defined
-    pub fn reassign_variable(&mut self, identifier: &str, value: Value) {
+    pub fn reassign_variable(&mut self, identifier: &str, value: Value<'source>) {
         assert!(self.call_stack.lookup_variable(identifier).is_some());
 
         self.call_stack.reassign_variable(identifier, value)
     }
+
+    /// Looks up the namespace of an already imported module
+    pub fn lookup_module(&self, path: &Path) -> Option<Value<'source>> {
+        self.modules
+            .get(path)
+            .and_then(|namespace| namespace.as_ref().map(Value::reference_copy))
+    }
+
+    /// Returns whether or not the module at the given path is currently being
+    /// run (meaning importing it again would be a circular import)
+    pub fn is_module_running(&self, path: &Path) -> bool {
+        matches!(self.modules.get(path), Some(None))
+    }
+
+    /// Starts running the module at the given path. Until the module is exited,
+    /// code runs in the module's own global scope, and can't see anything from
+    /// the importing code
+    ///
+    /// # Panics
+    /// - If the module has already been imported
+    pub fn enter_module(&mut self, path: PathBuf) {
+        assert!(!self.modules.contains_key(&path));
+
+        self.modules.insert(path.clone(), None);
+        self.suspended_importers.push(SuspendedImporter {
+            module_path: path,
+            most_recent_value: std::mem::replace(&mut self.most_recent_value, Value::Null),
+            call_stack: std::mem::replace(
+                &mut self.call_stack,
+                CallStack::new("<global>".to_string()),
+            ),
+        });
+    }
+
+    /// Finishes running the most recently entered module, returning to the
+    /// importing code. The module's namespace (a dict of the variables and
+    /// functions declared in its global scope) is cached and returned. The
+    /// namespace is a snapshot, so it doesn't see later changes to the
+    /// module's global scope
+    ///
+    /// # Panics
+    /// - If no module is being run
+    pub fn exit_module(&mut self) -> Value<'source> {
+        let global_scope = self.call_stack.global_scope().clone();
+        let module_path = self.resume_importer();
+
+        let global_scope = global_scope.borrow();
+        let variables = global_scope
+            .variables()
+            .map(|(identifier, value)| (identifier, value.reference_copy()));
+        let functions = global_scope
+            .functions()
+            .map(|(identifier, function_group)| {
+                let function = FunctionValue::UserDefined {
+                    name: Some(identifier.into()),
+                    function_group: function_group.clone(),
+                };
+
+                (identifier, Value::Function(function))
+            });
+        // The scope doesn't remember declaration order, so the namespace's
+        // entries are sorted by name to keep its order deterministic
+        let mut entries: Vec<_> = variables.chain(functions).collect();
+        entries.sort_by_key(|(identifier, _)| *identifier);
+        let namespace = Value::Dict(Rc::new(RefCell::new(
+            entries
+                .into_iter()
+                .map(|(identifier, value)| (Value::String(identifier.into()), value))
+                .collect(),
+        )));
+
+        self.modules
+            .insert(module_path, Some(namespace.reference_copy()));
+
+        namespace
+    }
+
+    /// Stops running the most recently entered module (because it failed),
+    /// returning to the importing code. The module isn't cached, so importing
+    /// it again will run it again
+    ///
+    /// # Panics
+    /// - If no module is being run
+    pub fn abort_module(&mut self) {
+        let module_path = self.resume_importer();
+
+        self.modules.remove(&module_path);
+    }
+
+    /// Restores the state of the most recently suspended importer, returning
+    /// the path of the module it imported
+    ///
+    /// # Panics
+    /// - If no module is being run
+    fn resume_importer(&mut self) -> PathBuf {
+        let importer = self
+            .suspended_importers
+            .pop()
+            .expect("a module should be running");
+
+        self.most_recent_value = importer.most_recent_value;
+        self.call_stack = importer.call_stack;
+
+        importer.module_path
+    }
 }
 
 impl Clone for RuntimeState<'_> {
@@ -162,6 +386,15 @@ impl Clone for RuntimeState<'_> {
             most_recent_value: self.most_recent_value.deep_copy(),
             call_stack: self.call_stack.clone(),
             rng: Clone::clone(&self.rng),
+            modules: self
+                .modules
+                .iter()
+                .map(|(path, namespace)| (path.clone(), namespace.as_ref().map(Value::deep_copy)))
+                .collect(),
+            suspended_importers: self.suspended_importers.clone(),
+            // Resume points only exist partway through resuming a generator,
+            // and can't be copied anyway
+            resume_points: Vec::new(),
         }
     }
 }
@@ -185,6 +418,9 @@ impl Debug for RuntimeState<'_> {
             .field("most_recent_value", &self.most_recent_value)
             .field("call_stack", &self.call_stack)
             .field("rng", &DebugAsIs("<random number generator>"))
+            .field("modules", &self.modules)
+            .field("suspended_importers", &self.suspended_importers)
+            .field("resume_points", &self.resume_points)
             .finish()
     }
 }
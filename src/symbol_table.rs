@@ -1,7 +1,11 @@
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
 
 use crate::{
     ast::AstNode,
+    call_stack::Environment,
     function::{Function, FunctionGroup, FunctionParameters},
     source_range::SourceRange,
     value::Value,
@@ -11,8 +15,9 @@ use crate::{
 /// scope
 #[derive(Debug)]
 pub struct SymbolTable<'source> {
-    functions: HashMap<String, FunctionGroup<'source>>,
-    variables: HashMap<String, Value>,
+    functions: HashMap<String, Rc<FunctionGroup<'source>>>,
+    variables: HashMap<String, Value<'source>>,
+    constants: HashSet<String>,
 }
 
 impl<'source> SymbolTable<'source> {
@@ -21,6 +26,7 @@ impl<'source> SymbolTable<'source> {
         Self {
             functions: HashMap::new(),
             variables: HashMap::new(),
+            constants: HashSet::new(),
         }
     }
 
@@ -29,60 +35,85 @@ impl<'source> SymbolTable<'source> {
     ///
     /// # Panics
     /// - If the variable is already defined
-    pub fn declare_variable(&mut self, identifier: String, value: Value) {
+    pub fn declare_variable(&mut self, identifier: String, value: Value<'source>) {
         assert!(!self.variables.contains_key(&identifier));
 
         self.variables.insert(identifier, value);
     }
 
+    /// Declares a new constant (a variable which can't be reassigned) with the
+    /// given value
+    ///
+    /// # Panics
+    /// - If the variable is already defined
+    pub fn declare_constant(&mut self, identifier: String, value: Value<'source>) {
+        self.constants.insert(identifier.clone());
+        self.declare_variable(identifier, value);
+    }
+
+    /// Returns whether or not the variable with the given identifier was
+    /// declared as a constant
+    pub fn is_constant(&self, identifier: &str) -> bool {
+        self.constants.contains(identifier)
+    }
+
     /// Looks up a variable in the symbol table, returning None if the variable
     /// isn't defined
-    pub fn lookup_variable(&self, identifier: &str) -> Option<&Value> {
+    pub fn lookup_variable(&self, identifier: &str) -> Option<&Value<'source>> {
         self.variables.get(identifier)
     }
 
-    /// Looks up a variable (mutably) in the symbol table, returning None if the
-    /// variable isn't defined
-    pub fn lookup_variable_mut(&mut self, identifier: &str) -> Option<&mut Value> {
-        self.variables.get_mut(identifier)
+    /// Returns an iterator over every variable in the symbol table
+    pub fn variables(&self) -> impl Iterator<Item = (&str, &Value<'source>)> {
+        self.variables
+            .iter()
+            .map(|(identifier, value)| (identifier.as_str(), value))
     }
 
-    /// Declares a new function with the given parameters and body, or adds an
-    /// overload to an existing function group
+    /// Declares a new function with the given parameters, body, and captured
+    /// environment, or adds an overload to an existing function group
     ///
     /// # Panics
-    /// - If the function is already defined and an overload with the same
-    ///   parameter arity already exists
+    /// - If the function is already defined and a conflicting overload (one
+    ///   accepting any of the same numbers of arguments) already exists
     pub fn declare_function(
         &mut self,
         identifier: String,
         parameters: FunctionParameters<'source>,
         body: Vec<AstNode<'source>>,
+        environment: Environment<'source>,
         pos: SourceRange<'source>,
     ) {
+        let function = Function::new(parameters, body, environment, pos);
         match self.functions.get_mut(&identifier) {
-            Some(function_group) => {
-                function_group.add_overload(Function::new(parameters, body, pos))
-            }
+            Some(function_group) => Rc::make_mut(function_group).add_overload(function),
             None => {
                 let mut new_function_group = FunctionGroup::new();
-                new_function_group.add_overload(Function::new(parameters, body, pos));
-                self.functions.insert(identifier, new_function_group);
+                new_function_group.add_overload(function);
+                self.functions
+                    .insert(identifier, Rc::new(new_function_group));
             }
         }
     }
 
     /// Accesses a function in the symbol table, returning None if the function
     /// isn't defined
-    pub fn lookup_function(&self, identifier: &str) -> Option<&FunctionGroup<'source>> {
+    pub fn lookup_function(&self, identifier: &str) -> Option<&Rc<FunctionGroup<'source>>> {
         self.functions.get(identifier)
     }
 
+    /// Returns an iterator over every function group in the symbol table
+    pub fn functions(&self) -> impl Iterator<Item = (&str, &Rc<FunctionGroup<'source>>)> {
+        self.functions
+            .iter()
+            .map(|(identifier, function_group)| (identifier.as_str(), function_group))
+    }
+
     /// Assigns a new value to an already existing variable
     ///
     /// # Panics
     /// - If the variable isn't already defined
-    pub fn reassign_variable(&mut self, identifier: &str, new_value: Value) {
+    pub fn reassign_variable(&mut self, identifier: &str, new_value: Value<'source>) {
         assert!(self.lookup_variable(identifier).is_some());
 
         *self.variables.get_mut(identifier).unwrap() = new_value;
@@ -98,6 +129,7 @@ impl Clone for SymbolTable<'_> {
                 .iter()
                 .map(|(k, v)| (k.clone(), v.deep_copy()))
                 .collect(),
+            constants: self.constants.clone(),
         }
     }
 }
@@ -6,8 +6,12 @@
 
 #![cfg(test)]
 
+use std::collections::VecDeque;
+
 use rand::{Rng, SeedableRng};
 
+use crate::{interpreter::interpret, lexer::tokenize, parser::parse};
+
 pub const RAND_SEED: u64 = 123;
 pub const RAND_ITERATIONS: usize = 1000;
 
@@ -16,6 +20,34 @@ pub fn make_rng() -> impl Rng {
     rand_chacha::ChaCha8Rng::seed_from_u64(RAND_SEED)
 }
 
+/// Runs a snippet of icelang source code, returning the error message if it
+/// fails at any point. The snippet can check its own results with `assert`
+pub fn run_icelang(source_code: &str) -> Result<(), String> {
+    let tokens = tokenize(source_code, "test.ice").map_err(|err| err.to_string())?;
+    let ast = parse(tokens.iter().collect::<VecDeque<_>>()).map_err(|err| err.to_string())?;
+    interpret(&ast).map(|_| ()).map_err(|err| err.to_string())
+}
+
+/// Runs a snippet of icelang source code (like `run_icelang`) after defining
+/// `error_of(f)`, which calls `f` and returns the kind and message of the
+/// runtime error it causes (like `"type: ..."`), or null if it doesn't cause
+/// one
+pub fn run_with_error_of(source_code: &str) -> Result<(), String> {
+    run_icelang(&format!(
+        r#"
+        fn error_of(f) {{
+            try {{
+                f();
+            }} catch err {{
+                return f"{{err.kind}}: {{err.message}}";
+            }};
+            return null;
+        }};
+        {source_code}
+        "#
+    ))
+}
+
 /// Generates a pseudo-random character, designed to trigger as many weird
 /// edge-cases as possible >:)
 pub fn gen_rand_char(rng: &mut impl Rng) -> char {
@@ -19,6 +19,9 @@ pub enum Token<'source> {
     /// A keyword token
     Keyword(TokenKeyword<'source>),
 
+    /// A loop label token
+    Label(TokenLabel<'source>),
+
     /// A punctuator (separator or operator) token
     Punctuator(TokenPunctuator<'source>),
 }
@@ -29,6 +32,7 @@ impl<'source> Token<'source> {
         match self {
             Self::Ident(token) => token.pos(),
             Self::Keyword(token) => token.pos(),
+            Self::Label(token) => token.pos(),
             Self::Literal(token) => token.pos(),
             Self::FormattedStringLiteralSection(token) => token.pos(),
             Self::Punctuator(token) => token.pos(),
@@ -41,6 +45,7 @@ impl Display for Token<'_> {
         match self {
             Self::Ident(token) => write!(f, "{token}"),
             Self::Keyword(token) => write!(f, "{token}"),
+            Self::Label(token) => write!(f, "{token}"),
             Self::Literal(token) => write!(f, "{token}"),
             Self::FormattedStringLiteralSection(token) => write!(f, "{token}"),
             Self::Punctuator(token) => write!(f, "{token}"),
@@ -59,6 +64,7 @@ macro_rules! impl_from_specific_token {
 }
 impl_from_specific_token!(TokenIdent, Ident);
 impl_from_specific_token!(TokenKeyword, Keyword);
+impl_from_specific_token!(TokenLabel, Label);
 impl_from_specific_token!(TokenLiteral, Literal);
 impl_from_specific_token!(
     TokenFormattedStringLiteralSection,
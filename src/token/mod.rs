@@ -5,6 +5,7 @@ mod generic_token;
 mod token_formatted_string_literal_section;
 mod token_ident;
 mod token_keyword;
+mod token_label;
 mod token_literal;
 mod token_punctuator;
 
@@ -14,5 +15,6 @@ pub use token_formatted_string_literal_section::{
 };
 pub use token_ident::TokenIdent;
 pub use token_keyword::TokenKeyword;
+pub use token_label::TokenLabel;
 pub use token_literal::TokenLiteral;
 pub use token_punctuator::TokenPunctuator;
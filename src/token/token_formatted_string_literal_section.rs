@@ -57,6 +57,7 @@ pub struct TokenFormattedStringLiteralSection<'source> {
     raw: String,
     value: String,
     kind: FormattedStringLiteralSectionKind,
+    format_spec: Option<String>,
     pos: SourceRange<'source>,
 }
 
@@ -66,12 +67,14 @@ impl<'source> TokenFormattedStringLiteralSection<'source> {
         raw: String,
         value: String,
         kind: FormattedStringLiteralSectionKind,
+        format_spec: Option<String>,
         pos: SourceRange<'source>,
     ) -> Self {
         Self {
             raw,
             value,
             kind,
+            format_spec,
             pos,
         }
     }
@@ -92,6 +95,13 @@ impl<'source> TokenFormattedStringLiteralSection<'source> {
         self.kind
     }
 
+    /// Returns the format spec of the replacement field just before this
+    /// section (the `>8.3` in `f"{x:>8.3}"`), if it had one. Only
+    /// continuation and end sections follow a replacement field
+    pub fn format_spec(&self) -> Option<&str> {
+        self.format_spec.as_deref()
+    }
+
     /// Returns the position in the source code of this literal
     pub fn pos(&self) -> &SourceRange<'source> {
         &self.pos
@@ -135,6 +145,7 @@ mod tests {
                 raw.to_string(),
                 raw.to_string(),
                 kind,
+                None,
                 nowhere.clone(),
             );
 
@@ -142,6 +153,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_formatted_string_literal_section_format_spec() {
+        let nowhere = SourceRange::new(" ", "", 0, 0);
+
+        for format_spec in [None, Some(">8.3"), Some("?")] {
+            let tok = TokenFormattedStringLiteralSection::new(
+                format_spec.map_or("}\"".to_string(), |spec| format!(":{spec}}}\"")),
+                String::new(),
+                FormattedStringLiteralSectionKind::End,
+                format_spec.map(str::to_string),
+                nowhere.clone(),
+            );
+
+            assert_eq!(tok.format_spec(), format_spec);
+        }
+    }
+
     #[test]
     fn test_formatted_string_literal_section_display() {
         let nowhere = SourceRange::new(" ", "", 0, 0);
@@ -152,6 +180,7 @@ mod tests {
                 raw.to_string(),
                 raw.to_string(),
                 kind,
+                None,
                 nowhere.clone(),
             );
 
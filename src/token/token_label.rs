@@ -0,0 +1,65 @@
+use std::fmt::Display;
+
+use crate::source_range::SourceRange;
+
+/// A loop label token (like `'outer`)
+#[derive(Debug)]
+pub struct TokenLabel<'source> {
+    label: String,
+    pos: SourceRange<'source>,
+}
+
+impl<'source> TokenLabel<'source> {
+    /// Constructs a new TokenLabel from the name of the label (without the
+    /// leading `'`)
+    pub fn new(label: String, pos: SourceRange<'source>) -> Self {
+        Self { label, pos }
+    }
+
+    /// Returns the name of the label (without the leading `'`)
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the position in the source code of this label
+    pub fn pos(&self) -> &SourceRange<'source> {
+        &self.pos
+    }
+}
+
+impl Display for TokenLabel<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[Token] Label: '{}", self.label)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::source_range::SourceRange;
+
+    use super::*;
+
+    const LABELS: &[&str] = &["outer", "inner", "_", "loop_2", "CamelCase"];
+
+    #[test]
+    fn test_label() {
+        let nowhere = SourceRange::new(" ", "", 0, 0);
+
+        for label in LABELS {
+            let tok = TokenLabel::new(label.to_string(), nowhere.clone());
+
+            assert_eq!(tok.label(), *label);
+        }
+    }
+
+    #[test]
+    fn test_label_display() {
+        let nowhere = SourceRange::new(" ", "", 0, 0);
+
+        for label in LABELS {
+            let tok = TokenLabel::new(label.to_string(), nowhere.clone());
+
+            assert_eq!(tok.to_string(), format!("[Token] Label: '{label}"));
+        }
+    }
+}
@@ -7,7 +7,7 @@ use crate::{icelang_type::IcelangType, source_range::SourceRange, value::Value};
 pub struct TokenLiteral<'source> {
     raw: String,
     icelang_type: IcelangType,
-    value: Value,
+    value: Value<'source>,
     pos: SourceRange<'source>,
 }
 
@@ -20,7 +20,7 @@ impl<'source> TokenLiteral<'source> {
     pub fn new(
         raw: String,
         icelang_type: IcelangType,
-        value: Value,
+        value: Value<'source>,
         pos: SourceRange<'source>,
     ) -> Self {
         assert_eq!(value.icelang_type(), icelang_type);
@@ -44,7 +44,7 @@ impl<'source> TokenLiteral<'source> {
     }
 
     /// Returns the icelang value of this literal
-    pub fn value(&self) -> &Value {
+    pub fn value(&self) -> &Value<'source> {
         &self.value
     }
 
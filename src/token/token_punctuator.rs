@@ -41,7 +41,8 @@ mod tests {
     const PUNCTUATORS: &[&str] = &[
         "=", "+=", "-=", "*=", "/=", "%=", "**=", "<<=", ">>=", "&=", "^=", "|=", "&&=", "||=",
         "?", ":", "||", "&&", "==", "!=", "<", ">", "<=", ">=", "|", "^", "&", "<<", ">>", "+",
-        "-", "*", "/", "%", "**", "!", "=>", ";", ",", ".", "(", ")", "{", "}", "[", "]",
+        "-", "*", "/", "%", "**", "!", "=>", ";", ",", ".", "(", ")", "{", "}", "[", "]", "...",
+        "..", "..=", "??", "??=", "?.", "?[",
     ];
 
     #[test]
@@ -1,18 +1,29 @@
 //! Contains code related to `Value`s, which represent icelang runtime values
 
-use std::{cell::RefCell, collections::HashMap, hash::Hash, rc::Rc};
+use std::{
+    cell::RefCell,
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    ops::Deref,
+    rc::Rc,
+};
 
+use indexmap::{IndexMap, IndexSet};
 use num_bigint::BigInt;
 use ordered_float::OrderedFloat;
 
 use crate::{
+    function::FunctionValue,
+    generator::Generator,
     icelang_std_lib::{IcelangFmt, IcelangFmtArgs},
     icelang_type::IcelangType,
+    range::Range,
+    record::Record,
 };
 
 /// Represents an icelang runtime value
 #[derive(Debug)]
-pub enum Value {
+pub enum Value<'source> {
     /// An int value
     Int(BigInt),
 
@@ -29,20 +40,37 @@ pub enum Value {
     String(Rc<str>),
 
     /// A list value
-    List(Rc<RefCell<Vec<Value>>>),
+    List(Rc<RefCell<Freezable<Vec<Value<'source>>>>>),
+
+    /// A dict value. Dicts remember the order their keys were first inserted
+    /// in, which is the order they are iterated through and displayed in
+    Dict(Rc<RefCell<Freezable<IndexMap<Value<'source>, Value<'source>>>>>),
+
+    /// A set value. Like dicts, sets remember the order their elements were
+    /// first inserted in
+    Set(Rc<RefCell<Freezable<IndexSet<Value<'source>>>>>),
+
+    /// A function value
+    Function(FunctionValue<'source>),
+
+    /// A record value (an instance of a user-defined record type)
+    Record(Rc<RefCell<Freezable<Record<'source>>>>),
+
+    /// A range value
+    Range(Rc<Range>),
 
-    /// A dict value
-    Dict(Rc<RefCell<HashMap<Value, Value>>>),
+    /// A generator value
+    Generator(Rc<RefCell<Generator<'source>>>),
 
     /// A null value
     Null,
 }
 
-impl Value {
+impl<'source> Value<'source> {
     /// Performs a reference copy of the value. This means that the two values
     /// are copied by-reference, and (for the non-copy types) changes to the
     /// original will be reflected in the copy, and vice-versa
-    pub fn reference_copy(&self) -> Value {
+    pub fn reference_copy(&self) -> Value<'source> {
         match self {
             Value::Int(value) => Value::Int(value.clone()),
             Value::Byte(value) => Value::Byte(*value),
@@ -51,6 +79,11 @@ impl Value {
             Value::String(value) => Value::String(value.clone()),
             Value::List(value) => Value::List(value.clone()),
             Value::Dict(value) => Value::Dict(value.clone()),
+            Value::Set(value) => Value::Set(value.clone()),
+            Value::Function(value) => Value::Function(value.clone()),
+            Value::Record(value) => Value::Record(value.clone()),
+            Value::Range(value) => Value::Range(value.clone()),
+            Value::Generator(value) => Value::Generator(value.clone()),
             Value::Null => Value::Null,
         }
     }
@@ -60,8 +93,8 @@ impl Value {
     /// (and vice-versa). However, since this is a shallow copy, changes to any
     /// of the values *stored inside* the original will be reflected in the
     /// copy, and vice-versa. This only matters for types that contain other
-    /// values, like `list`s or `dict`s
-    pub fn shallow_copy(&self) -> Value {
+    /// values, like `list`s, `dict`s, or records
+    pub fn shallow_copy(&self) -> Value<'source> {
         match self {
             Value::Int(value) => Value::Int(value.clone()),
             Value::Byte(value) => Value::Byte(*value),
@@ -82,6 +115,24 @@ impl Value {
                     .map(|(k, v)| (k.reference_copy(), v.reference_copy()))
                     .collect(),
             ))),
+            Value::Set(value) => Value::Set(Rc::new(RefCell::new(
+                value
+                    .borrow()
+                    .iter()
+                    .map(|value| value.reference_copy())
+                    .collect(),
+            ))),
+            Value::Function(value) => Value::Function(value.clone()),
+            Value::Record(value) => Value::Record(Rc::new(RefCell::new(
+                value
+                    .borrow()
+                    .map_fields(|value| value.reference_copy())
+                    .into(),
+            ))),
+            Value::Range(value) => Value::Range(value.clone()),
+            // Generators share their progress with their copies, since a
+            // generator's stack frame can't be copied
+            Value::Generator(value) => Value::Generator(value.clone()),
             Value::Null => Value::Null,
         }
     }
@@ -91,8 +142,8 @@ impl Value {
     /// vice-versa). Additionally, since this is a deep copy, changes to any
     /// of the values stored inside the original will **not** be reflected in
     /// the copy, and vice-versa. This only matters for types that contain other
-    /// values, like `list`s or `dict`s
-    pub fn deep_copy(&self) -> Value {
+    /// values, like `list`s, `dict`s, or records
+    pub fn deep_copy(&self) -> Value<'source> {
         match self {
             Value::Int(value) => Value::Int(value.clone()),
             Value::Byte(value) => Value::Byte(*value),
@@ -113,10 +164,95 @@ impl Value {
                     .map(|(k, v)| (k.deep_copy(), v.deep_copy()))
                     .collect(),
             ))),
+            Value::Set(value) => Value::Set(Rc::new(RefCell::new(
+                value
+                    .borrow()
+                    .iter()
+                    .map(|value| value.deep_copy())
+                    .collect(),
+            ))),
+            Value::Function(value) => Value::Function(value.clone()),
+            Value::Record(value) => Value::Record(Rc::new(RefCell::new(
+                value.borrow().map_fields(|value| value.deep_copy()).into(),
+            ))),
+            Value::Range(value) => Value::Range(value.clone()),
+            Value::Generator(value) => Value::Generator(value.clone()),
             Value::Null => Value::Null,
         }
     }
 
+    /// Deeply freezes the value, so that neither it nor any of the values
+    /// stored inside it can be modified. This only matters for types that
+    /// contain other values, like `list`s, `dict`s, or records
+    pub fn freeze(&self) {
+        // The contents are copied out before being frozen, so that values
+        // which contain themselves aren't borrowed while being frozen again
+        let contents: Vec<Value> = match self {
+            Value::List(list) => {
+                if !freeze_once(list) {
+                    return;
+                }
+                list.borrow().iter().map(Value::reference_copy).collect()
+            }
+            Value::Dict(dict) => {
+                if !freeze_once(dict) {
+                    return;
+                }
+                dict.borrow()
+                    .iter()
+                    .flat_map(|(key, value)| [key.reference_copy(), value.reference_copy()])
+                    .collect()
+            }
+            Value::Set(set) => {
+                if !freeze_once(set) {
+                    return;
+                }
+                set.borrow().iter().map(Value::reference_copy).collect()
+            }
+            Value::Record(record) => {
+                if !freeze_once(record) {
+                    return;
+                }
+                record
+                    .borrow()
+                    .fields()
+                    .map(|(_, value)| value.reference_copy())
+                    .collect()
+            }
+            Value::Int(_)
+            | Value::Byte(_)
+            | Value::Float(_)
+            | Value::Bool(_)
+            | Value::String(_)
+            | Value::Function(_)
+            | Value::Range(_)
+            | Value::Generator(_)
+            | Value::Null => return,
+        };
+
+        contents.iter().for_each(Value::freeze);
+    }
+
+    /// Returns whether or not the value is frozen. Values of types which can't
+    /// be modified in the first place are never considered frozen
+    pub fn is_frozen(&self) -> bool {
+        match self {
+            Value::List(list) => list.borrow().is_frozen(),
+            Value::Dict(dict) => dict.borrow().is_frozen(),
+            Value::Set(set) => set.borrow().is_frozen(),
+            Value::Record(record) => record.borrow().is_frozen(),
+            Value::Range(_)
+            | Value::Generator(_)
+            | Value::Int(_)
+            | Value::Byte(_)
+            | Value::Float(_)
+            | Value::Bool(_)
+            | Value::String(_)
+            | Value::Function(_)
+            | Value::Null => false,
+        }
+    }
+
     /// Gets the icelang type of the value
     pub fn icelang_type(&self) -> IcelangType {
         match self {
@@ -127,6 +263,11 @@ impl Value {
             Self::String(_) => IcelangType::String,
             Self::List(_) => IcelangType::List,
             Self::Dict(_) => IcelangType::Dict,
+            Self::Set(_) => IcelangType::Set,
+            Self::Function(_) => IcelangType::Function,
+            Self::Record(_) => IcelangType::Record,
+            Self::Range(_) => IcelangType::Range,
+            Self::Generator(_) => IcelangType::Generator,
             Self::Null => IcelangType::Null,
         }
     }
@@ -152,7 +293,94 @@ impl Value {
     }
 }
 
-impl PartialEq for Value {
+/// Freezes the contents of a shared value, returning whether or not they
+/// weren't already frozen. The contents are frozen before anything stored
+/// inside them, so values which (directly or indirectly) contain themselves
+/// are only frozen once
+fn freeze_once<T>(contents: &RefCell<Freezable<T>>) -> bool {
+    let mut contents = contents.borrow_mut();
+    if contents.is_frozen() {
+        return false;
+    }
+
+    contents.freeze();
+    true
+}
+
+/// The contents of a mutable icelang value (such as the elements of a list),
+/// which may be frozen to prevent any further modification
+///
+/// The contents can always be read through `Deref`, but can only be modified
+/// through `get_mut`, which fails once the contents are frozen
+#[derive(Debug, Default)]
+pub struct Freezable<T> {
+    value: T,
+    frozen: bool,
+}
+
+impl<T> Freezable<T> {
+    /// Constructs new (unfrozen) Freezable contents
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            frozen: false,
+        }
+    }
+
+    /// Returns whether or not the contents are frozen
+    pub fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    /// Freezes the contents. This can't be undone
+    pub fn freeze(&mut self) {
+        self.frozen = true;
+    }
+
+    /// Gets a mutable reference to the contents, or None if they're frozen
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        match self.frozen {
+            true => None,
+            false => Some(&mut self.value),
+        }
+    }
+}
+
+impl<T> From<T> for Freezable<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<A, T: FromIterator<A>> FromIterator<A> for Freezable<T> {
+    fn from_iter<I: IntoIterator<Item = A>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl<T> Deref for Freezable<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T: PartialEq> PartialEq for Freezable<T> {
+    fn eq(&self, other: &Self) -> bool {
+        // Freezing contents doesn't change what they are
+        self.value == other.value
+    }
+}
+impl<T: Eq> Eq for Freezable<T> {}
+
+impl<T: Hash> Hash for Freezable<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl PartialEq for Value<'_> {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (Self::Int(lhs), Self::Int(rhs)) => lhs == rhs,
@@ -168,14 +396,19 @@ impl PartialEq for Value {
                         .iter()
                         .all(|(key, value)| rhs.borrow().get(key) == Some(value))
             }
+            (Self::Set(lhs), Self::Set(rhs)) => lhs == rhs,
+            (Self::Function(lhs), Self::Function(rhs)) => lhs == rhs,
+            (Self::Record(lhs), Self::Record(rhs)) => lhs == rhs,
+            (Self::Range(lhs), Self::Range(rhs)) => lhs == rhs,
+            (Self::Generator(lhs), Self::Generator(rhs)) => Rc::ptr_eq(lhs, rhs),
             (Self::Null, Self::Null) => true,
             (_, _) => false,
         }
     }
 }
-impl Eq for Value {}
+impl Eq for Value<'_> {}
 
-impl Hash for Value {
+impl Hash for Value<'_> {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         std::mem::discriminant(self).hash(state);
         match self {
@@ -189,13 +422,112 @@ impl Hash for Value {
             Value::String(value) => value.hash(state),
             Value::List(value) => value.borrow().hash(state),
             Value::Dict(value) => {
-                value.borrow().len().hash(state);
-                for (key, value) in value.borrow().iter() {
-                    key.hash(state);
-                    value.hash(state);
-                }
+                // Dicts which only differ in insertion order are equal, so
+                // their hashes mustn't depend on it either
+                let value = value.borrow();
+                value.len().hash(state);
+                unordered_hash(value.iter()).hash(state);
+            }
+            Value::Set(value) => {
+                // Sets which only differ in insertion order are equal, so the
+                // hashes of their elements are combined in a way which doesn't
+                // depend on it
+                let value = value.borrow();
+                value.len().hash(state);
+                unordered_hash(value.iter()).hash(state);
             }
+            Value::Function(value) => value.hash(state),
+            Value::Record(value) => value.borrow().hash(state),
+            Value::Range(value) => value.hash(state),
+            Value::Generator(value) => Rc::as_ptr(value).hash(state),
             Value::Null => {}
         };
     }
 }
+
+/// Combines the hashes of the given items in a way which doesn't depend on the
+/// order they're given in
+fn unordered_hash(items: impl Iterator<Item = impl Hash>) -> u64 {
+    items
+        .map(|item| {
+            let mut hasher = DefaultHasher::new();
+            item.hash(&mut hasher);
+            hasher.finish()
+        })
+        .fold(0u64, u64::wrapping_add)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn int(value: i64) -> Value<'static> {
+        Value::Int(BigInt::from(value))
+    }
+
+    fn hash_of(value: &Value) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn test_dict_insertion_order() {
+        let mut entries = IndexMap::new();
+        for (key, value) in [(3, 30), (1, 10), (2, 20), (1, 11)] {
+            entries.insert(int(key), int(value));
+        }
+        let dict = Value::Dict(Rc::new(RefCell::new(entries.into())));
+        assert_eq!(dict.icelang_display(), "{3: 30, 1: 11, 2: 20}");
+
+        // Removing an entry keeps the others in order
+        let Value::Dict(entries) = &dict else {
+            unreachable!()
+        };
+        entries
+            .borrow_mut()
+            .get_mut()
+            .unwrap()
+            .shift_remove(&int(3));
+        entries
+            .borrow_mut()
+            .get_mut()
+            .unwrap()
+            .insert(int(3), int(31));
+        assert_eq!(dict.icelang_display(), "{1: 11, 2: 20, 3: 31}");
+
+        // The order doesn't affect equality or hashing
+        let reordered = Value::Dict(Rc::new(RefCell::new(
+            IndexMap::from([(int(3), int(31)), (int(2), int(20)), (int(1), int(11))]).into(),
+        )));
+        assert_eq!(dict, reordered);
+        assert_eq!(hash_of(&dict), hash_of(&reordered));
+    }
+
+    #[test]
+    fn test_set_insertion_order() {
+        let set = Value::Set(Rc::new(RefCell::new(
+            IndexSet::from_iter([5, 1, 3, 1, 4].map(int)).into(),
+        )));
+        assert_eq!(set.icelang_display(), "{5, 1, 3, 4}");
+
+        // Removing an element keeps the others in order
+        let Value::Set(elements) = &set else {
+            unreachable!()
+        };
+        elements
+            .borrow_mut()
+            .get_mut()
+            .unwrap()
+            .shift_remove(&int(5));
+        elements.borrow_mut().get_mut().unwrap().insert(int(5));
+        assert_eq!(set.icelang_display(), "{1, 3, 4, 5}");
+
+        // The order doesn't affect equality or hashing
+        let reordered = Value::Set(Rc::new(RefCell::new(
+            IndexSet::from_iter([4, 5, 3, 1].map(int)).into(),
+        )));
+        assert_eq!(set, reordered);
+        assert_eq!(hash_of(&set), hash_of(&reordered));
+    }
+}